@@ -0,0 +1,67 @@
+#![feature(test)]
+
+extern crate petgraph;
+extern crate test;
+
+use test::Bencher;
+
+#[allow(dead_code)]
+mod common;
+use common::*;
+
+use petgraph::algo::{
+    betweenness_centrality, closeness_centrality, edge_betweenness_centrality,
+    eigenvector_centrality, hits, weighted_betweenness_centrality,
+};
+
+#[cfg(feature = "rayon")]
+use petgraph::algo::parallel_betweenness_centrality;
+
+#[bench]
+fn betweenness_centrality_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| betweenness_centrality::<_, f64>(&g, true));
+}
+
+#[bench]
+fn weighted_betweenness_centrality_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| weighted_betweenness_centrality::<_, _, _, f64>(&g, true, |_| 1));
+}
+
+#[bench]
+fn edge_betweenness_centrality_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| edge_betweenness_centrality::<_, f64>(&g, true));
+}
+
+#[bench]
+#[cfg(feature = "rayon")]
+fn parallel_betweenness_centrality_fan(bench: &mut Bencher) {
+    let g = directed_fan(500);
+    bench.iter(|| parallel_betweenness_centrality::<_, f64>(&g, true));
+}
+
+#[bench]
+fn betweenness_centrality_fan(bench: &mut Bencher) {
+    let g = directed_fan(500);
+    bench.iter(|| betweenness_centrality::<_, f64>(&g, true));
+}
+
+#[bench]
+fn closeness_centrality_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| closeness_centrality(&g, |_| 1.));
+}
+
+#[bench]
+fn eigenvector_centrality_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| eigenvector_centrality(&g, 100, Some(1e-9_f64)));
+}
+
+#[bench]
+fn hits_fan(bench: &mut Bencher) {
+    let g = directed_fan(500);
+    bench.iter(|| hits(&g, 100, Some(1e-9_f64)));
+}
@@ -0,0 +1,36 @@
+#![feature(test)]
+
+extern crate petgraph;
+extern crate test;
+
+use test::Bencher;
+
+#[allow(dead_code)]
+mod common;
+use common::*;
+
+use petgraph::algo::{maximal_cliques, maximum_clique};
+
+#[bench]
+fn maximal_cliques_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| maximal_cliques(&g).count());
+}
+
+#[bench]
+fn maximal_cliques_praust(bench: &mut Bencher) {
+    let g = ungraph().praust_a();
+    bench.iter(|| maximal_cliques(&g).count());
+}
+
+#[bench]
+fn maximum_clique_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| maximum_clique(&g));
+}
+
+#[bench]
+fn maximum_clique_full(bench: &mut Bencher) {
+    let g = ungraph().full_a();
+    bench.iter(|| maximum_clique(&g));
+}
@@ -0,0 +1,62 @@
+#![feature(test)]
+
+extern crate petgraph;
+extern crate test;
+
+use test::Bencher;
+
+#[allow(dead_code)]
+mod common;
+use common::*;
+
+use petgraph::algo::{
+    bipartite_edge_coloring, chromatic_number, edge_coloring, greedy_coloring, ColoringOrder,
+};
+
+#[bench]
+fn greedy_coloring_largest_first_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| greedy_coloring(&g, ColoringOrder::LargestFirst));
+}
+
+#[bench]
+fn greedy_coloring_smallest_last_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| greedy_coloring(&g, ColoringOrder::SmallestLast));
+}
+
+#[bench]
+fn greedy_coloring_dsatur_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| greedy_coloring(&g, ColoringOrder::DSatur));
+}
+
+#[bench]
+fn chromatic_number_petersen(bench: &mut Bencher) {
+    let g = ungraph().petersen_a();
+    bench.iter(|| chromatic_number(&g));
+}
+
+#[bench]
+fn chromatic_number_praust(bench: &mut Bencher) {
+    let g = ungraph().praust_a();
+    bench.iter(|| chromatic_number(&g));
+}
+
+#[bench]
+fn edge_coloring_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| edge_coloring(&g));
+}
+
+#[bench]
+fn edge_coloring_full(bench: &mut Bencher) {
+    let g = ungraph().full_a();
+    bench.iter(|| edge_coloring(&g));
+}
+
+#[bench]
+fn bipartite_edge_coloring_bipartite(bench: &mut Bencher) {
+    let g = ungraph().bipartite();
+    bench.iter(|| bipartite_edge_coloring(&g));
+}
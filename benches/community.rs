@@ -0,0 +1,37 @@
+#![feature(test)]
+
+extern crate petgraph;
+extern crate test;
+
+use test::Bencher;
+
+#[allow(dead_code)]
+mod common;
+use common::*;
+
+use petgraph::algo::{label_propagation, louvain, modularity};
+
+#[bench]
+fn louvain_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| louvain(&g, 1., |_| 1.));
+}
+
+#[bench]
+fn louvain_directed_bigger(bench: &mut Bencher) {
+    let g = digraph().bigger();
+    bench.iter(|| louvain(&g, 1., |_| 1.));
+}
+
+#[bench]
+fn label_propagation_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| label_propagation(&g, |_| 1));
+}
+
+#[bench]
+fn modularity_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    let communities = louvain(&g, 1., |_| 1.);
+    bench.iter(|| modularity(&g, &communities, 1., |_| 1.));
+}
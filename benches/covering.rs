@@ -0,0 +1,44 @@
+#![feature(test)]
+
+extern crate petgraph;
+extern crate test;
+
+use test::Bencher;
+
+#[allow(dead_code)]
+mod common;
+use common::*;
+
+use petgraph::algo::{
+    approx_vertex_cover, greedy_dominating_set, greedy_independent_set, minimum_vertex_cover,
+};
+
+#[bench]
+fn approx_vertex_cover_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| approx_vertex_cover(&g));
+}
+
+#[bench]
+fn minimum_vertex_cover_petersen(bench: &mut Bencher) {
+    let g = ungraph().petersen_a();
+    bench.iter(|| minimum_vertex_cover(&g));
+}
+
+#[bench]
+fn minimum_vertex_cover_praust(bench: &mut Bencher) {
+    let g = ungraph().praust_a();
+    bench.iter(|| minimum_vertex_cover(&g));
+}
+
+#[bench]
+fn greedy_independent_set_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| greedy_independent_set(&g));
+}
+
+#[bench]
+fn greedy_dominating_set_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| greedy_dominating_set(&g));
+}
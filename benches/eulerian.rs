@@ -0,0 +1,46 @@
+#![feature(test)]
+
+extern crate petgraph;
+extern crate test;
+
+use test::Bencher;
+
+#[allow(dead_code)]
+mod common;
+use common::*;
+
+use petgraph::algo::{eulerian_circuit, eulerian_path};
+use petgraph::prelude::*;
+
+/// The complete graph on an odd number of nodes, where every degree is even.
+fn complete_graph(n: usize) -> UnGraph<(), ()> {
+    let mut g = UnGraph::with_capacity(n, n * (n - 1) / 2);
+    for _ in 0..n {
+        g.add_node(());
+    }
+    for a in 0..n {
+        for b in a + 1..n {
+            g.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+        }
+    }
+    g
+}
+
+#[bench]
+fn eulerian_circuit_complete_101(bench: &mut Bencher) {
+    let g = complete_graph(101);
+    bench.iter(|| eulerian_circuit(&g));
+}
+
+#[bench]
+fn eulerian_path_complete_101(bench: &mut Bencher) {
+    let mut g = complete_graph(101);
+    g.remove_edge(EdgeIndex::new(0));
+    bench.iter(|| eulerian_path(&g));
+}
+
+#[bench]
+fn eulerian_path_bigger(bench: &mut Bencher) {
+    let g = digraph().bigger();
+    bench.iter(|| eulerian_path(&g));
+}
@@ -7,7 +7,7 @@ use petgraph::prelude::*;
 use std::cmp::{max, min};
 use test::Bencher;
 
-use petgraph::algo::floyd_warshall;
+use petgraph::algo::{floyd_warshall, floyd_warshall_matrix};
 
 #[bench]
 fn floyd_warshall_bench(bench: &mut Bencher) {
@@ -30,3 +30,25 @@ fn floyd_warshall_bench(bench: &mut Bencher) {
         let _scores = floyd_warshall(&g, |e| *e.weight());
     });
 }
+
+#[bench]
+fn floyd_warshall_matrix_bench(bench: &mut Bencher) {
+    static NODE_COUNT: usize = 100;
+    let mut g = Graph::new_undirected();
+    let nodes: Vec<NodeIndex<_>> = (0..NODE_COUNT).map(|i| g.add_node(i)).collect();
+    for i in 0..NODE_COUNT {
+        let n1 = nodes[i];
+        let neighbour_count = i % 8 + 3;
+        let j_from = max(0, i as i32 - neighbour_count as i32 / 2) as usize;
+        let j_to = min(NODE_COUNT, j_from + neighbour_count);
+        for j in j_from..j_to {
+            let n2 = nodes[j];
+            let distance = (i + 3) % 10;
+            g.add_edge(n1, n2, distance);
+        }
+    }
+
+    bench.iter(|| {
+        let _scores = floyd_warshall_matrix(&g, |e| *e.weight());
+    });
+}
@@ -0,0 +1,52 @@
+#![feature(test)]
+
+extern crate petgraph;
+extern crate test;
+
+use petgraph::prelude::*;
+use std::cmp::{max, min};
+use test::Bencher;
+
+use petgraph::algo::johnson;
+
+#[cfg(feature = "rayon")]
+use petgraph::algo::parallel_johnson;
+
+fn sparse_graph(node_count: usize) -> Graph<usize, i32> {
+    let mut g = Graph::new();
+    let nodes: Vec<NodeIndex<_>> = (0..node_count).map(|i| g.add_node(i)).collect();
+    for i in 0..node_count {
+        let n1 = nodes[i];
+        let neighbour_count = i % 8 + 3;
+        let j_from = max(0, i as i32 - neighbour_count as i32 / 2) as usize;
+        let j_to = min(node_count, j_from + neighbour_count);
+        for j in j_from..j_to {
+            let n2 = nodes[j];
+            // A few negative edges, but no negative cycles.
+            let distance = if j > i && i % 5 == 0 {
+                -1
+            } else {
+                ((i + 3) % 10) as i32 + 1
+            };
+            g.add_edge(n1, n2, distance);
+        }
+    }
+    g
+}
+
+#[bench]
+fn johnson_bench(bench: &mut Bencher) {
+    let g = sparse_graph(100);
+    bench.iter(|| {
+        let _scores = johnson(&g, |e| *e.weight());
+    });
+}
+
+#[bench]
+#[cfg(feature = "rayon")]
+fn parallel_johnson_bench(bench: &mut Bencher) {
+    let g = sparse_graph(100);
+    bench.iter(|| {
+        let _scores = parallel_johnson(&g, |e| *e.weight());
+    });
+}
@@ -9,8 +9,12 @@ use test::Bencher;
 mod common;
 use common::*;
 
-use petgraph::algo::{greedy_matching, maximum_matching};
-use petgraph::graph::UnGraph;
+use petgraph::algo::{
+    bipartite_maximum_matching, bipartition, greedy_matching, max_weight_assignment,
+    maximum_matching, maximum_weight_matching, min_cost_assignment,
+};
+use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::visit::EdgeRef;
 
 fn huge() -> UnGraph<(), ()> {
     static NODE_COUNT: u32 = 1_000;
@@ -76,3 +80,63 @@ fn maximum_matching_huge(bench: &mut Bencher) {
     let g = huge();
     bench.iter(|| maximum_matching(&g));
 }
+
+/// A complete bipartite graph with pseudo-random weights between the even and
+/// odd nodes.
+fn weighted_bipartite(node_count: u32) -> UnGraph<(), u32> {
+    let mut edges = Vec::new();
+    for i in (0..node_count).step_by(2) {
+        for j in (1..node_count).step_by(2) {
+            edges.push((i, j, (i * 31 + j * 17) % 97));
+        }
+    }
+    UnGraph::from_edges(&edges)
+}
+
+#[bench]
+fn min_cost_assignment_bipartite(bench: &mut Bencher) {
+    let g = weighted_bipartite(200);
+    let left = (0..200).step_by(2).map(NodeIndex::new);
+    let right = (1..200).step_by(2).map(NodeIndex::new);
+    bench.iter(|| min_cost_assignment(&g, left.clone(), right.clone(), |e| *e.weight()));
+}
+
+#[bench]
+fn max_weight_assignment_bipartite(bench: &mut Bencher) {
+    let g = weighted_bipartite(200);
+    let left = (0..200).step_by(2).map(NodeIndex::new);
+    let right = (1..200).step_by(2).map(NodeIndex::new);
+    bench.iter(|| max_weight_assignment(&g, left.clone(), right.clone(), |e| *e.weight()));
+}
+
+#[bench]
+fn maximum_weight_matching_bigger(bench: &mut Bencher) {
+    let g = ungraph().bigger();
+    bench.iter(|| maximum_weight_matching(&g, false, |e| e.source().index() % 7 + 1));
+}
+
+#[bench]
+fn maximum_weight_matching_bipartite(bench: &mut Bencher) {
+    let g = weighted_bipartite(200);
+    bench.iter(|| maximum_weight_matching(&g, false, |e| *e.weight()));
+}
+
+#[bench]
+fn bipartite_maximum_matching_bipartite(bench: &mut Bencher) {
+    let g = ungraph().bipartite();
+    let (left, right) = bipartition(&g).unwrap();
+    bench.iter(|| bipartite_maximum_matching(&g, left.iter().cloned(), right.iter().cloned()));
+}
+
+#[bench]
+fn bipartite_maximum_matching_large(bench: &mut Bencher) {
+    let g = weighted_bipartite(2_000);
+    let (left, right) = bipartition(&g).unwrap();
+    bench.iter(|| bipartite_maximum_matching(&g, left.iter().cloned(), right.iter().cloned()));
+}
+
+#[bench]
+fn maximum_matching_large_bipartite(bench: &mut Bencher) {
+    let g = weighted_bipartite(2_000);
+    bench.iter(|| maximum_matching(&g));
+}
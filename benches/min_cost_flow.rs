@@ -0,0 +1,29 @@
+#![feature(test)]
+extern crate petgraph;
+extern crate test;
+
+use petgraph::algo::min_cost_flow;
+use petgraph::prelude::{Graph, NodeIndex};
+use test::Bencher;
+
+#[bench]
+fn min_cost_flow_bench(bench: &mut Bencher) {
+    static NODE_COUNT: usize = 1_000;
+    let mut g: Graph<usize, (f64, f64)> = Graph::new();
+    let nodes: Vec<NodeIndex<_>> = (0..NODE_COUNT).map(|i| g.add_node(i)).collect();
+    for i in 0..NODE_COUNT - 1 {
+        g.add_edge(nodes[i], nodes[i + 1], (10., (i % 7) as f64));
+        if i + 5 < NODE_COUNT {
+            g.add_edge(nodes[i], nodes[i + 5], (3., (i % 11) as f64));
+        }
+    }
+    bench.iter(|| {
+        let _flow = min_cost_flow(
+            &g,
+            NodeIndex::from(0),
+            NodeIndex::from(g.node_count() as u32 - 1),
+            |e| e.weight().0,
+            |e| e.weight().1,
+        );
+    });
+}
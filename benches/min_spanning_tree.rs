@@ -9,7 +9,12 @@ use test::Bencher;
 mod common;
 use common::{digraph, ungraph};
 
-use petgraph::algo::min_spanning_tree;
+use petgraph::algo::{
+    min_spanning_arborescence, min_spanning_tree, min_spanning_tree_boruvka, min_spanning_tree_prim,
+};
+use petgraph::graph::DiGraph;
+use petgraph::matrix_graph::UnMatrix;
+use petgraph::visit::IntoNodeIdentifiers;
 
 #[bench]
 fn min_spanning_tree_praust_undir_bench(bench: &mut Bencher) {
@@ -58,3 +63,62 @@ fn min_spanning_tree_petersen_dir_bench(bench: &mut Bencher) {
 
     bench.iter(|| (min_spanning_tree(&a), min_spanning_tree(&b)));
 }
+
+/// A complete graph with pseudo-random weights, stored densely.
+fn dense_matrix(node_count: usize) -> UnMatrix<(), u32> {
+    let mut g = UnMatrix::with_capacity(node_count);
+    let nodes: Vec<_> = (0..node_count).map(|_| g.add_node(())).collect();
+    for i in 0..node_count {
+        for j in i + 1..node_count {
+            g.add_edge(nodes[i], nodes[j], ((i * 31 + j * 17) % 97) as u32);
+        }
+    }
+    g
+}
+
+#[bench]
+fn min_spanning_tree_dense_matrix_bench(bench: &mut Bencher) {
+    let g = dense_matrix(300);
+    bench.iter(|| min_spanning_tree(&g).count());
+}
+
+#[bench]
+fn min_spanning_tree_prim_dense_matrix_bench(bench: &mut Bencher) {
+    let g = dense_matrix(300);
+    let root = g.node_identifiers().next().unwrap();
+    bench.iter(|| min_spanning_tree_prim(&g, root).count());
+}
+
+#[bench]
+fn min_spanning_tree_boruvka_dense_matrix_bench(bench: &mut Bencher) {
+    let g = dense_matrix(300);
+    bench.iter(|| min_spanning_tree_boruvka(&g).count());
+}
+
+#[cfg(feature = "rayon")]
+#[bench]
+fn parallel_min_spanning_tree_boruvka_dense_matrix_bench(bench: &mut Bencher) {
+    use petgraph::algo::parallel_min_spanning_tree_boruvka;
+
+    let g = dense_matrix(300);
+    bench.iter(|| parallel_min_spanning_tree_boruvka(&g).count());
+}
+
+#[bench]
+fn min_spanning_arborescence_dense_bench(bench: &mut Bencher) {
+    let node_count = 100;
+    let mut g = DiGraph::<(), u32>::with_capacity(node_count, node_count * node_count);
+    for _ in 0..node_count {
+        g.add_node(());
+    }
+    for i in 0..node_count {
+        for j in 0..node_count {
+            if i != j {
+                let weight = (i * 7919 + j * 104_729) % 1000;
+                g.add_edge((i as u32).into(), (j as u32).into(), weight as u32);
+            }
+        }
+    }
+    let root = g.node_indices().next();
+    bench.iter(|| min_spanning_arborescence(&g, root).unwrap().count());
+}
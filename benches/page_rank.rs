@@ -4,7 +4,7 @@ extern crate test;
 
 use test::Bencher;
 
-use petgraph::algo::page_rank;
+use petgraph::algo::{page_rank, page_rank_with_options, PageRankOptions};
 
 #[allow(dead_code)]
 mod common;
@@ -14,6 +14,8 @@ use common::directed_fan;
 #[cfg(feature = "rayon")]
 use petgraph::algo::page_rank::parallel_page_rank;
 #[cfg(feature = "rayon")]
+use petgraph::algo::parallel_page_rank_with_options;
+#[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
 #[bench]
@@ -34,3 +36,24 @@ fn par_page_rank_bench(bench: &mut Bencher) {
         let _ranks = parallel_page_rank(&g, 0.6_f64, 100, None);
     });
 }
+
+#[bench]
+fn page_rank_with_options_bench(bench: &mut Bencher) {
+    static NODE_COUNT: usize = 2_000;
+    let g = directed_fan(NODE_COUNT);
+    let options = PageRankOptions::new().damping_factor(0.6_f64);
+    bench.iter(|| {
+        let _scores = page_rank_with_options(&g, &options);
+    });
+}
+
+#[bench]
+#[cfg(feature = "rayon")]
+fn par_page_rank_with_options_bench(bench: &mut Bencher) {
+    static NODE_COUNT: usize = 2_000;
+    let g = directed_fan(NODE_COUNT);
+    let options = PageRankOptions::new().damping_factor(0.6_f64);
+    bench.iter(|| {
+        let _scores = parallel_page_rank_with_options(&g, &options);
+    });
+}
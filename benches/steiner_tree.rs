@@ -0,0 +1,34 @@
+#![feature(test)]
+
+extern crate petgraph;
+extern crate test;
+
+use petgraph::prelude::*;
+use test::Bencher;
+
+use petgraph::algo::steiner_tree;
+
+/// A square grid graph with pseudo-random edge weights.
+fn grid(side: usize) -> UnGraph<(), u32> {
+    let mut g = UnGraph::with_capacity(side * side, 2 * side * side);
+    for _ in 0..side * side {
+        g.add_node(());
+    }
+    for i in 0..side * side {
+        let weight = (i * 7919 % 100) as u32 + 1;
+        if i % side + 1 < side {
+            g.add_edge(NodeIndex::new(i), NodeIndex::new(i + 1), weight);
+        }
+        if i + side < side * side {
+            g.add_edge(NodeIndex::new(i), NodeIndex::new(i + side), weight + 3);
+        }
+    }
+    g
+}
+
+#[bench]
+fn steiner_tree_grid_bench(bench: &mut Bencher) {
+    let g = grid(100);
+    let terminals: Vec<_> = g.node_indices().step_by(97).collect();
+    bench.iter(|| steiner_tree(&g, terminals.iter().cloned(), |e| *e.weight()));
+}
@@ -0,0 +1,72 @@
+#![feature(test)]
+
+extern crate petgraph;
+extern crate test;
+
+use test::Bencher;
+
+#[allow(dead_code)]
+mod common;
+use common::*;
+
+use petgraph::algo::{
+    christofides, hamiltonian_path, held_karp, nearest_neighbor_tour, or_opt, two_opt,
+};
+use petgraph::prelude::*;
+
+/// The complete graph on `n` points scattered on a grid, weighted by their
+/// distance.
+fn euclidean(n: usize) -> UnGraph<(), f64> {
+    let points: Vec<(f64, f64)> = (0..n)
+        .map(|i| ((i * 37 % 101) as f64, (i * 53 % 97) as f64))
+        .collect();
+    let mut g = UnGraph::with_capacity(n, n * (n - 1) / 2);
+    for _ in 0..n {
+        g.add_node(());
+    }
+    for (i, &(xa, ya)) in points.iter().enumerate() {
+        for (j, &(xb, yb)) in points.iter().enumerate().skip(i + 1) {
+            let distance = ((xa - xb).powi(2) + (ya - yb).powi(2)).sqrt();
+            g.add_edge(NodeIndex::new(i), NodeIndex::new(j), distance);
+        }
+    }
+    g
+}
+
+#[bench]
+fn nearest_neighbor_tour_100(bench: &mut Bencher) {
+    let g = euclidean(100);
+    bench.iter(|| nearest_neighbor_tour(&g, NodeIndex::new(0), |e| *e.weight()));
+}
+
+#[bench]
+fn two_opt_100(bench: &mut Bencher) {
+    let g = euclidean(100);
+    let (_, tour) = nearest_neighbor_tour(&g, NodeIndex::new(0), |e| *e.weight()).unwrap();
+    bench.iter(|| two_opt(&g, &mut tour.clone(), |e| *e.weight()));
+}
+
+#[bench]
+fn or_opt_100(bench: &mut Bencher) {
+    let g = euclidean(100);
+    let (_, tour) = nearest_neighbor_tour(&g, NodeIndex::new(0), |e| *e.weight()).unwrap();
+    bench.iter(|| or_opt(&g, &mut tour.clone(), |e| *e.weight()));
+}
+
+#[bench]
+fn christofides_100(bench: &mut Bencher) {
+    let g = euclidean(100);
+    bench.iter(|| christofides(&g, |e| *e.weight()));
+}
+
+#[bench]
+fn held_karp_12(bench: &mut Bencher) {
+    let g = euclidean(12);
+    bench.iter(|| held_karp(&g, |e| *e.weight()));
+}
+
+#[bench]
+fn hamiltonian_path_praust(bench: &mut Bencher) {
+    let g = ungraph().praust_a();
+    bench.iter(|| hamiltonian_path(&g));
+}
@@ -0,0 +1,103 @@
+//! Undirected adjacency lists by node index, for the algorithms that look at
+//! the neighbors of nodes many times.
+
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// The nodes of a graph by index, with the neighbors of each node in both
+/// directions, sorted and without duplicates or self loops.
+pub struct Adjacency {
+    /// The nodes that are in the graph, in the order of `node_identifiers`.
+    pub nodes: Vec<usize>,
+    pub neighbors: Vec<Vec<usize>>,
+    /// Whether each node has a self loop, if built with `with_loops`, and
+    /// empty otherwise.
+    pub loops: Vec<bool>,
+}
+
+impl Adjacency {
+    pub fn new<G>(graph: G) -> Self
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    {
+        Self::build(graph, false)
+    }
+
+    /// Like `new`, and record which nodes have a self loop.
+    pub fn with_loops<G>(graph: G) -> Self
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    {
+        Self::build(graph, true)
+    }
+
+    fn build<G>(graph: G, record_loops: bool) -> Self
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    {
+        let ix = |i| graph.to_index(i);
+        let nodes: Vec<_> = graph.node_identifiers().map(ix).collect();
+        let mut neighbors = vec![vec![]; graph.node_bound()];
+        let mut loops = if record_loops {
+            vec![false; graph.node_bound()]
+        } else {
+            vec![]
+        };
+        for a in graph.node_identifiers() {
+            for b in graph.neighbors(a) {
+                if a != b {
+                    neighbors[ix(a)].push(ix(b));
+                    neighbors[ix(b)].push(ix(a));
+                } else if record_loops {
+                    loops[ix(a)] = true;
+                }
+            }
+        }
+        for list in &mut neighbors {
+            list.sort_unstable();
+            list.dedup();
+        }
+        Adjacency {
+            nodes,
+            neighbors,
+            loops,
+        }
+    }
+
+    /// The nodes in the order in which they are removed, when a node of the
+    /// smallest degree is removed from the graph each time.
+    pub fn degeneracy_order(&self) -> Vec<usize> {
+        let mut degrees: Vec<_> = self.neighbors.iter().map(|list| list.len()).collect();
+        // The nodes by degree, where a node may still be in the bucket of a
+        // greater degree after it has been removed.
+        let mut buckets = vec![vec![]; self.nodes.len()];
+        for &n in &self.nodes {
+            buckets[degrees[n]].push(n);
+        }
+        let mut removed = vec![false; self.neighbors.len()];
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut degree = 0;
+        while order.len() < self.nodes.len() {
+            let n = match buckets[degree].pop() {
+                Some(n) => n,
+                None => {
+                    degree += 1;
+                    continue;
+                }
+            };
+            if removed[n] || degrees[n] != degree {
+                continue;
+            }
+            removed[n] = true;
+            order.push(n);
+            for &m in &self.neighbors[n] {
+                if !removed[m] {
+                    degrees[m] -= 1;
+                    buckets[degrees[m]].push(m);
+                }
+            }
+            // Removing a node lowers the degrees of its neighbors by one.
+            degree = degree.saturating_sub(1);
+        }
+        order
+    }
+}
@@ -1,17 +1,95 @@
 //! Bellman-Ford algorithms.
 
+use std::cmp::Ordering;
+
 use crate::prelude::*;
 
 use crate::visit::{IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable, VisitMap, Visitable};
 
 use super::{FloatMeasure, NegativeCycle};
 
+/// Shortest paths from a single source node.
+///
+/// Both vectors are indexed by the graph's node indices. Nodes that are not
+/// reachable from the source have no predecessor. Their distance is infinite
+/// when computed by [`bellman_ford`], and `None` when computed by
+/// [`spfa`][spfa] or [`dijkstra_paths`][dijkstra_paths], whose measures need
+/// not have an infinity.
+///
+/// [spfa]: ../spfa/fn.spfa.html
+/// [dijkstra_paths]: ../dijkstra/fn.dijkstra_paths.html
 #[derive(Debug, Clone)]
 pub struct Paths<NodeId, EdgeWeight> {
     pub distances: Vec<EdgeWeight>,
     pub predecessors: Vec<Option<NodeId>>,
 }
 
+impl<NodeId, EdgeWeight> Paths<NodeId, EdgeWeight>
+where
+    NodeId: Copy + PartialEq,
+{
+    /// Return the nodes of the shortest path from `source` to `target`,
+    /// both included, or `None` if `target` was not reached.
+    ///
+    /// `graph` and `source` must be the graph and the source the paths were
+    /// computed with.
+    pub fn path_to<G>(&self, graph: G, source: NodeId, target: NodeId) -> Option<Vec<NodeId>>
+    where
+        G: NodeIndexable<NodeId = NodeId>,
+    {
+        let ix = |i| graph.to_index(i);
+        if target != source && self.predecessors[ix(target)].is_none() {
+            // Not reached from the source.
+            return None;
+        }
+
+        let mut path = vec![target];
+        let mut node = target;
+        while let Some(pred) = self.predecessors[ix(node)] {
+            path.push(pred);
+            node = pred;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Return the edges of the shortest path from `source` to `target`, or
+    /// `None` if `target` was not reached.
+    ///
+    /// The function `edge_cost` should be the one the paths were computed
+    /// with; among parallel edges, the one with the least cost is chosen.
+    ///
+    /// `graph` and `source` must be the graph and the source the paths were
+    /// computed with.
+    pub fn edges_to<G, F, K>(
+        &self,
+        graph: G,
+        source: NodeId,
+        target: NodeId,
+        mut edge_cost: F,
+    ) -> Option<Vec<G::EdgeRef>>
+    where
+        G: IntoEdges + NodeIndexable<NodeId = NodeId>,
+        F: FnMut(G::EdgeRef) -> K,
+        K: PartialOrd,
+    {
+        let path = self.path_to(graph, source, target)?;
+        let edges = path
+            .windows(2)
+            .map(|pair| {
+                graph
+                    .edges(pair[0])
+                    .filter(|edge| edge.target() == pair[1])
+                    .map(|edge| (edge_cost(edge), edge))
+                    .min_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+                    .map(|(_, edge)| edge)
+                    .expect("predecessor should be connected to its successor")
+            })
+            .collect();
+        Some(edges)
+    }
+}
+
 /// \[Generic\] Compute shortest paths from node `source` to all other.
 ///
 /// Using the [Bellman–Ford algorithm][bf]; negative edge costs are
@@ -0,0 +1,414 @@
+//! Bridges, articulation points and biconnected components of undirected graphs.
+
+use crate::graph::{Graph, NodeIndex};
+use crate::visit::{
+    EdgeIndexable, EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeIndexable, VisitMap, Visitable,
+};
+use crate::Undirected;
+
+/// An event of the lowlink depth first search.
+enum LowlinkEvent<N, E> {
+    /// The edge leads to a node that is discovered for the first time.
+    TreeEdge(E),
+    /// The edge leads to an ancestor of the current node in the DFS tree.
+    BackEdge(E),
+    /// The DFS subtree reached through the tree edge has been explored.
+    ///
+    /// `low` is the lowest discovery time reachable from the subtree, `disc`
+    /// is the discovery time of the edge's source.
+    Finish { edge: E, disc: usize, low: usize },
+    /// A DFS tree has been explored; contains the root and its number of children.
+    RootFinish(N, usize),
+}
+
+/// Run Tarjan's lowlink depth first search over every node of `g`, reporting
+/// each explored subtree to `visitor`.
+///
+/// The tree edge is skipped when it is seen again from the child, so that
+/// parallel edges are handled as back edges. Self-loops are ignored.
+///
+/// The implementation is iterative.
+fn lowlink_dfs<G, F>(g: G, mut visitor: F)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+    F: FnMut(LowlinkEvent<G::NodeId, G::EdgeRef>),
+{
+    struct Frame<N, E, I> {
+        node: N,
+        tree_edge: Option<E>,
+        edges: I,
+        skipped_parent: bool,
+        // An edge back to the parent with a different id than the tree edge:
+        // either the tree edge seen from this end or a parallel edge.
+        pending_parent: Option<E>,
+    }
+
+    let ix = |n| g.to_index(n);
+    let mut discovered = g.visit_map();
+    let mut disc = vec![0; g.node_bound()];
+    let mut low = vec![0; g.node_bound()];
+    let mut time = 0;
+    let mut stack: Vec<Frame<_, G::EdgeRef, _>> = Vec::new();
+
+    for root in g.node_identifiers() {
+        if !discovered.visit(root) {
+            continue;
+        }
+        disc[ix(root)] = time;
+        low[ix(root)] = time;
+        time += 1;
+        let mut root_children = 0;
+        stack.push(Frame {
+            node: root,
+            tree_edge: None,
+            edges: g.edges(root),
+            skipped_parent: false,
+            pending_parent: None,
+        });
+
+        while let Some(frame) = stack.last_mut() {
+            let u = frame.node;
+            if let Some(mut edge) = frame.edges.next() {
+                let v = edge.target();
+                if v == u {
+                    continue;
+                }
+                if let Some(tree_edge) = frame.tree_edge {
+                    if v == tree_edge.source() && !frame.skipped_parent {
+                        // Graphs that give both ends of an edge the same id let us
+                        // recognize the tree edge; otherwise, the first edge back
+                        // to the parent is taken to be the tree edge.
+                        if edge.id() == tree_edge.id() {
+                            frame.skipped_parent = true;
+                            match frame.pending_parent.take() {
+                                Some(pending) => edge = pending,
+                                None => continue,
+                            }
+                        } else if frame.pending_parent.is_none() {
+                            frame.pending_parent = Some(edge);
+                            continue;
+                        }
+                    }
+                }
+                if discovered.visit(v) {
+                    disc[ix(v)] = time;
+                    low[ix(v)] = time;
+                    time += 1;
+                    if u == root {
+                        root_children += 1;
+                    }
+                    visitor(LowlinkEvent::TreeEdge(edge));
+                    stack.push(Frame {
+                        node: v,
+                        tree_edge: Some(edge),
+                        edges: g.edges(v),
+                        skipped_parent: false,
+                        pending_parent: None,
+                    });
+                } else if disc[ix(v)] < disc[ix(u)] {
+                    low[ix(u)] = low[ix(u)].min(disc[ix(v)]);
+                    visitor(LowlinkEvent::BackEdge(edge));
+                }
+            } else {
+                let tree_edge = frame.tree_edge;
+                stack.pop();
+                if let Some(edge) = tree_edge {
+                    let parent = edge.source();
+                    low[ix(parent)] = low[ix(parent)].min(low[ix(u)]);
+                    visitor(LowlinkEvent::Finish {
+                        edge,
+                        disc: disc[ix(parent)],
+                        low: low[ix(u)],
+                    });
+                }
+            }
+        }
+        visitor(LowlinkEvent::RootFinish(root, root_children));
+    }
+}
+
+/// \[Generic\] Find the *bridges* (cut edges) of an undirected graph.
+///
+/// A bridge is an edge whose removal increases the number of connected
+/// components of the graph.
+///
+/// The graph is traversed through `edges`, so it should be undirected (for a
+/// directed graph only the outgoing edges of each node are seen). Parallel
+/// edges are never bridges, and self-loops are ignored.
+///
+/// Uses Tarjan's lowlink depth first search, with runtime **O(|V| + |E|)**.
+/// The implementation is iterative.
+///
+/// Returns a vector with a reference to each bridge, in the direction it was
+/// traversed.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::bridges;
+/// use petgraph::prelude::*;
+///
+/// // a --- b --- c
+/// //       |     |
+/// //       d --- e --- f
+/// let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let e = graph.add_node(());
+/// let f = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b), (b, c), (b, d), (c, e), (d, e), (e, f)]);
+///
+/// let mut cut_edges: Vec<_> = bridges(&graph)
+///     .into_iter()
+///     .map(|edge| graph.edge_endpoints(edge.id()).unwrap())
+///     .collect();
+/// cut_edges.sort();
+/// assert_eq!(cut_edges, vec![(a, b), (e, f)]);
+/// ```
+pub fn bridges<G>(g: G) -> Vec<G::EdgeRef>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    let mut bridges = Vec::new();
+    lowlink_dfs(g, |event| {
+        if let LowlinkEvent::Finish { edge, disc, low } = event {
+            if low > disc {
+                bridges.push(edge);
+            }
+        }
+    });
+    bridges
+}
+
+/// \[Generic\] Find the *articulation points* (cut vertices) of an undirected graph.
+///
+/// An articulation point is a node whose removal, together with its incident
+/// edges, increases the number of connected components of the graph.
+///
+/// The graph is traversed through `edges`, so it should be undirected (for a
+/// directed graph only the outgoing edges of each node are seen). Self-loops
+/// are ignored.
+///
+/// Uses Tarjan's lowlink depth first search, with runtime **O(|V| + |E|)**.
+/// The implementation is iterative.
+///
+/// Returns a vector of the articulation points, in the order of
+/// `node_identifiers`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::articulation_points;
+/// use petgraph::prelude::*;
+///
+/// // a --- b --- c
+/// //       |     |
+/// //       d --- e --- f
+/// let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let e = graph.add_node(());
+/// let f = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b), (b, c), (b, d), (c, e), (d, e), (e, f)]);
+///
+/// assert_eq!(articulation_points(&graph), vec![b, e]);
+/// ```
+pub fn articulation_points<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    let mut is_cut = vec![false; g.node_bound()];
+    lowlink_dfs(g, |event| match event {
+        // The root is handled separately, its discovery time is lowest in its tree.
+        LowlinkEvent::Finish { edge, disc, low } if low >= disc => {
+            is_cut[g.to_index(edge.source())] = true;
+        }
+        LowlinkEvent::RootFinish(root, children) => {
+            is_cut[g.to_index(root)] = children > 1;
+        }
+        _ => {}
+    });
+    g.node_identifiers()
+        .filter(|&n| is_cut[g.to_index(n)])
+        .collect()
+}
+
+/// Run the lowlink depth first search and call `f` with the edges of each
+/// biconnected component, in the order the components are completed.
+fn for_each_biconnected_component<G, F>(g: G, mut f: F)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+    F: FnMut(&[G::EdgeRef]),
+{
+    let mut edge_stack = Vec::new();
+    // Position of each open tree edge on the edge stack.
+    let mut starts = Vec::new();
+    lowlink_dfs(g, |event| match event {
+        LowlinkEvent::TreeEdge(edge) => {
+            starts.push(edge_stack.len());
+            edge_stack.push(edge);
+        }
+        LowlinkEvent::BackEdge(edge) => edge_stack.push(edge),
+        LowlinkEvent::Finish { disc, low, .. } => {
+            let start = starts.pop().unwrap();
+            if low >= disc {
+                // The source of the tree edge separates its subtree: every edge
+                // pushed since the tree edge forms one component.
+                f(&edge_stack[start..]);
+                edge_stack.truncate(start);
+            }
+        }
+        LowlinkEvent::RootFinish(..) => debug_assert!(edge_stack.is_empty()),
+    });
+}
+
+/// \[Generic\] Compute the *biconnected components* of an undirected graph.
+///
+/// A biconnected component (or *block*) is a maximal set of edges such that
+/// any two edges of the set lie on a common simple cycle. Bridges form
+/// components of their own. Every node that belongs to more than one component
+/// is an [articulation point][ap].
+///
+/// The graph is traversed through `edges`, so it should be undirected (for a
+/// directed graph only the outgoing edges of each node are seen).
+///
+/// Uses Tarjan's lowlink depth first search, with runtime **O(|V| + |E|)**.
+/// The implementation is iterative.
+///
+/// Returns a vector indexed by the graph's edge indices, holding the component
+/// id of each edge. Component ids are numbered from `0` in the order the
+/// components are completed. Self-loops and vacant edge indices are `None`.
+///
+/// Use [`block_cut_tree`][bct] to get the tree linking the components and the
+/// articulation points.
+///
+/// [ap]: fn.articulation_points.html
+/// [bct]: fn.block_cut_tree.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::biconnected_components;
+/// use petgraph::prelude::*;
+///
+/// // a --- b --- d
+/// //  \   /     / \
+/// //   \ /     /   \
+/// //    c     e --- f
+/// let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let e = graph.add_node(());
+/// let f = graph.add_node(());
+/// let ab = graph.add_edge(a, b, ());
+/// let bc = graph.add_edge(b, c, ());
+/// let ca = graph.add_edge(c, a, ());
+/// let bd = graph.add_edge(b, d, ());
+/// let de = graph.add_edge(d, e, ());
+/// let ef = graph.add_edge(e, f, ());
+/// let fd = graph.add_edge(f, d, ());
+///
+/// let components = biconnected_components(&graph);
+/// // Three components: the two triangles and the bridge between them.
+/// assert_eq!(components[ab.index()], components[bc.index()]);
+/// assert_eq!(components[ab.index()], components[ca.index()]);
+/// assert_eq!(components[de.index()], components[ef.index()]);
+/// assert_eq!(components[de.index()], components[fd.index()]);
+/// assert_ne!(components[bd.index()], components[ab.index()]);
+/// assert_ne!(components[bd.index()], components[de.index()]);
+/// ```
+pub fn biconnected_components<G>(g: G) -> Vec<Option<usize>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + EdgeIndexable + Visitable,
+{
+    let mut labels = vec![None; g.edge_bound()];
+    let mut component = 0;
+    for_each_biconnected_component(g, |edges| {
+        for edge in edges {
+            labels[EdgeIndexable::to_index(&g, edge.id())] = Some(component);
+        }
+        component += 1;
+    });
+    labels
+}
+
+/// A node of the [block-cut tree][bct] of a graph.
+///
+/// [bct]: fn.block_cut_tree.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlockCutNode<N> {
+    /// A biconnected component, holding all of its nodes.
+    Block(Vec<N>),
+    /// An articulation point.
+    Cut(N),
+}
+
+/// \[Generic\] Build the *block-cut tree* of an undirected graph.
+///
+/// The block-cut tree has a node for each [biconnected component][bc] (a
+/// block) and one for each articulation point, and an edge between an
+/// articulation point and every block that contains it. It is a forest, with
+/// one tree for each connected component of the input graph that has edges.
+///
+/// Block nodes come first, and the index of each block node is the component
+/// id that [`biconnected_components`][bc] assigns to its edges. Articulation
+/// point nodes follow, in the order of `node_identifiers`. Nodes without
+/// incident edges (other than self-loops) are not part of any block.
+///
+/// The graph is traversed through `edges`, so it should be undirected (for a
+/// directed graph only the outgoing edges of each node are seen).
+///
+/// [bc]: fn.biconnected_components.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{block_cut_tree, BlockCutNode};
+/// use petgraph::prelude::*;
+///
+/// // a --- b --- c
+/// let graph: UnGraph<(), ()> = UnGraph::from_edges(&[(0, 1), (1, 2)]);
+///
+/// let tree = block_cut_tree(&graph);
+/// // Two blocks, one for each edge, and one articulation point.
+/// assert_eq!(tree.node_count(), 3);
+/// assert_eq!(tree.edge_count(), 2);
+/// let cut = NodeIndex::new(2);
+/// assert_eq!(tree[cut], BlockCutNode::Cut(NodeIndex::new(1)));
+/// assert_eq!(tree.neighbors(cut).count(), 2);
+/// ```
+pub fn block_cut_tree<G>(g: G) -> Graph<BlockCutNode<G::NodeId>, (), Undirected>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    let mut tree = Graph::new_undirected();
+    // The blocks that contain each node.
+    let mut node_blocks = vec![Vec::new(); g.node_bound()];
+    for_each_biconnected_component(g, |edges| {
+        let block = tree.add_node(BlockCutNode::Block(Vec::new()));
+        let mut nodes = Vec::new();
+        for edge in edges {
+            for n in [edge.source(), edge.target()] {
+                let blocks = &mut node_blocks[g.to_index(n)];
+                if blocks.last() != Some(&block) {
+                    blocks.push(block);
+                    nodes.push(n);
+                }
+            }
+        }
+        tree[block] = BlockCutNode::Block(nodes);
+    });
+
+    for n in g.node_identifiers() {
+        let blocks = &node_blocks[g.to_index(n)];
+        if blocks.len() > 1 {
+            let cut: NodeIndex = tree.add_node(BlockCutNode::Cut(n));
+            for &block in blocks {
+                tree.add_edge(cut, block, ());
+            }
+        }
+    }
+    tree
+}
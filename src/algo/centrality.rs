@@ -0,0 +1,879 @@
+//! Centrality measures.
+
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::algo::{dijkstra, iterate_until_converged, Measure, UnitMeasure};
+use crate::scored::MinScored;
+use crate::visit::{
+    EdgeIndexable, EdgeRef, GraphProp, IntoEdges, IntoNodeIdentifiers, NodeIndexable, Visitable,
+};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// \[Generic\] Betweenness centrality of the nodes, with [Brandes'
+/// algorithm][brandes].
+///
+/// The betweenness of a node is the sum, over all pairs of other nodes
+/// **s** and **t**, of the fraction of the shortest paths from **s** to
+/// **t** that go through it. Paths follow the edges as `graph.edges` does,
+/// so in a directed graph the pairs are ordered. In an undirected graph
+/// each pair of nodes is counted once.
+///
+/// The length of a path is its number of edges: see
+/// [`weighted_betweenness_centrality`][wbc] for edge costs. If `normalized`
+/// is true, the scores are divided by the number of pairs of other nodes,
+/// **(|V| - 1)(|V| - 2)** for directed graphs and half of it for undirected
+/// graphs, so they are between 0 and 1.
+///
+/// Returns a `Vec` mapping each node index to its score, where indices that
+/// are not nodes of the graph have a score of zero.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|·|E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// [brandes]: https://doi.org/10.1080/0022250X.2001.9990249
+/// [wbc]: fn.weighted_betweenness_centrality.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::betweenness_centrality;
+/// use petgraph::prelude::*;
+///
+/// // A star with the center 0, and the path 3 - 4.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (3, 4)]);
+///
+/// // All the paths between 1, 2, 3 and 4 go through 0, and the paths from
+/// // 0, 1 and 2 to 4 through 3.
+/// let scores: Vec<f64> = betweenness_centrality(&g, false);
+/// assert_eq!(scores, vec![5., 0., 0., 3., 0.]);
+///
+/// // There are 6 pairs of other nodes.
+/// let scores: Vec<f64> = betweenness_centrality(&g, true);
+/// assert!((scores[0] - 5. / 6.).abs() < 1e-12);
+/// assert_eq!(scores[3], 0.5);
+/// ```
+pub fn betweenness_centrality<G, D>(graph: G, normalized: bool) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    D: UnitMeasure + Copy,
+{
+    let mut brandes = Brandes::new(graph.node_bound());
+    let mut scores = vec![D::zero(); graph.node_bound()];
+    for source in graph.node_identifiers() {
+        brandes.breadth_first(graph, source, |_| 0);
+        brandes.accumulate(&mut scores, None);
+    }
+    rescale(&mut scores, node_scale(graph, normalized));
+    scores
+}
+
+/// \[Generic\] Betweenness centrality of the nodes, with path lengths
+/// given by `edge_cost`.
+///
+/// This is [`betweenness_centrality`][bc], where the shortest paths are
+/// searched with Dijkstra's algorithm. The edge costs must be positive, and
+/// paths of the same length are counted as equally short.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|·(|V| + |E|)·log(|V|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// [bc]: fn.betweenness_centrality.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::weighted_betweenness_centrality;
+/// use petgraph::prelude::*;
+///
+/// // The direct edge 0 -> 2 is longer than the path through 1.
+/// let g = Graph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (0, 2, 5)]);
+///
+/// let scores: Vec<f32> = weighted_betweenness_centrality(&g, false, |e| *e.weight());
+/// assert_eq!(scores, vec![0., 1., 0.]);
+/// ```
+pub fn weighted_betweenness_centrality<G, F, K, D>(
+    graph: G,
+    normalized: bool,
+    mut edge_cost: F,
+) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+    D: UnitMeasure + Copy,
+{
+    let mut brandes = Brandes::new(graph.node_bound());
+    let mut scores = vec![D::zero(); graph.node_bound()];
+    for source in graph.node_identifiers() {
+        brandes.dijkstra(graph, source, &mut edge_cost, |_| 0);
+        brandes.accumulate(&mut scores, None);
+    }
+    rescale(&mut scores, node_scale(graph, normalized));
+    scores
+}
+
+/// \[Generic\] Parallel betweenness centrality of the nodes.
+///
+/// The shortest paths from each node are searched in parallel.
+/// See [`betweenness_centrality`][bc].
+///
+/// [bc]: fn.betweenness_centrality.html
+#[cfg(feature = "rayon")]
+pub fn parallel_betweenness_centrality<G, D>(graph: G, normalized: bool) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp + Sync,
+    G::NodeId: Send,
+    D: UnitMeasure + Copy + Send + Sync,
+{
+    let sources: Vec<_> = graph.node_identifiers().collect();
+    let mut scores = sources
+        .into_par_iter()
+        .fold(
+            || {
+                let scores = vec![D::zero(); graph.node_bound()];
+                (Brandes::new(graph.node_bound()), scores)
+            },
+            |(mut brandes, mut scores), source| {
+                brandes.breadth_first(graph, source, |_| 0);
+                brandes.accumulate(&mut scores, None);
+                (brandes, scores)
+            },
+        )
+        .map(|(_, scores)| scores)
+        .reduce(|| vec![D::zero(); graph.node_bound()], add_scores);
+    rescale(&mut scores, node_scale(graph, normalized));
+    scores
+}
+
+/// \[Generic\] Parallel betweenness centrality of the nodes, with path
+/// lengths given by `edge_cost`.
+///
+/// The shortest paths from each node are searched in parallel.
+/// See [`weighted_betweenness_centrality`][wbc].
+///
+/// [wbc]: fn.weighted_betweenness_centrality.html
+#[cfg(feature = "rayon")]
+pub fn parallel_weighted_betweenness_centrality<G, F, K, D>(
+    graph: G,
+    normalized: bool,
+    edge_cost: F,
+) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp + Sync,
+    G::NodeId: Send,
+    F: Fn(G::EdgeRef) -> K + Sync,
+    K: Measure + Copy + Send,
+    D: UnitMeasure + Copy + Send + Sync,
+{
+    let sources: Vec<_> = graph.node_identifiers().collect();
+    let mut scores = sources
+        .into_par_iter()
+        .fold(
+            || {
+                let scores = vec![D::zero(); graph.node_bound()];
+                (Brandes::new(graph.node_bound()), scores)
+            },
+            |(mut brandes, mut scores), source| {
+                brandes.dijkstra(graph, source, &edge_cost, |_| 0);
+                brandes.accumulate(&mut scores, None);
+                (brandes, scores)
+            },
+        )
+        .map(|(_, scores)| scores)
+        .reduce(|| vec![D::zero(); graph.node_bound()], add_scores);
+    rescale(&mut scores, node_scale(graph, normalized));
+    scores
+}
+
+/// \[Generic\] Betweenness centrality of the edges, with [Brandes'
+/// algorithm][brandes].
+///
+/// The betweenness of an edge is the sum, over all pairs of nodes **s** and
+/// **t**, of the fraction of the shortest paths from **s** to **t** that go
+/// through it. Paths follow the edges as `graph.edges` does, so in a
+/// directed graph the pairs are ordered. In an undirected graph each pair
+/// of nodes is counted once.
+///
+/// The length of a path is its number of edges: see
+/// [`weighted_edge_betweenness_centrality`][webc] for edge costs. If
+/// `normalized` is true, the scores are divided by the number of pairs of
+/// nodes, **|V|(|V| - 1)** for directed graphs and half of it for
+/// undirected graphs, so they are between 0 and 1.
+///
+/// Returns a `Vec` mapping each edge index to its score, where indices that
+/// are not edges of the graph have a score of zero.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|·|E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// [brandes]: https://doi.org/10.1080/0022250X.2001.9990249
+/// [webc]: fn.weighted_edge_betweenness_centrality.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::edge_betweenness_centrality;
+/// use petgraph::prelude::*;
+///
+/// // Two triangles 0, 1, 2 and 3, 4, 5 linked by the bridge 2 - 3.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// g.extend_with_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+/// let bridge = g.add_edge(2.into(), 3.into(), ());
+///
+/// // The bridge is on the paths between the 3 nodes of each triangle.
+/// let scores: Vec<f64> = edge_betweenness_centrality(&g, false);
+/// assert_eq!(scores[bridge.index()], 9.);
+/// assert_eq!(scores[0], 1.);
+/// ```
+pub fn edge_betweenness_centrality<G, D>(graph: G, normalized: bool) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + EdgeIndexable + GraphProp,
+    D: UnitMeasure + Copy,
+{
+    let mut brandes = Brandes::new(graph.node_bound());
+    let mut node_scores = vec![D::zero(); graph.node_bound()];
+    let mut scores = vec![D::zero(); graph.edge_bound()];
+    for source in graph.node_identifiers() {
+        brandes.breadth_first(graph, source, |e| EdgeIndexable::to_index(&graph, e));
+        brandes.accumulate(&mut node_scores, Some(&mut scores[..]));
+    }
+    rescale(&mut scores, edge_scale(graph, normalized));
+    scores
+}
+
+/// \[Generic\] Betweenness centrality of the edges, with path lengths
+/// given by `edge_cost`.
+///
+/// This is [`edge_betweenness_centrality`][ebc], where the shortest paths
+/// are searched with Dijkstra's algorithm. The edge costs must be positive,
+/// and paths of the same length are counted as equally short.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|·(|V| + |E|)·log(|V|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// [ebc]: fn.edge_betweenness_centrality.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::weighted_edge_betweenness_centrality;
+/// use petgraph::prelude::*;
+///
+/// // The direct edge 0 -> 2 is longer than the path through 1.
+/// let g = Graph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (0, 2, 5)]);
+///
+/// let scores: Vec<f32> = weighted_edge_betweenness_centrality(&g, false, |e| *e.weight());
+/// assert_eq!(scores, vec![2., 2., 0.]);
+/// ```
+pub fn weighted_edge_betweenness_centrality<G, F, K, D>(
+    graph: G,
+    normalized: bool,
+    mut edge_cost: F,
+) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + EdgeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+    D: UnitMeasure + Copy,
+{
+    let mut brandes = Brandes::new(graph.node_bound());
+    let mut node_scores = vec![D::zero(); graph.node_bound()];
+    let mut scores = vec![D::zero(); graph.edge_bound()];
+    for source in graph.node_identifiers() {
+        brandes.dijkstra(graph, source, &mut edge_cost, |e| {
+            EdgeIndexable::to_index(&graph, e)
+        });
+        brandes.accumulate(&mut node_scores, Some(&mut scores[..]));
+    }
+    rescale(&mut scores, edge_scale(graph, normalized));
+    scores
+}
+
+/// \[Generic\] Closeness centrality of the nodes.
+///
+/// The closeness of a node is the inverse of the average length of the
+/// shortest paths from it to the nodes it can reach, with path lengths
+/// given by `edge_cost`. In a graph that is not strongly connected, this is
+/// scaled by the fraction of the other nodes that can be reached (Wasserman
+/// and Faust), so that nodes that reach few others have small scores. A node
+/// that can not reach any other node has a score of zero.
+///
+/// Paths follow the edges as `graph.edges` does: use a [`Reversed`][rev]
+/// graph for the lengths of the paths to each node instead.
+///
+/// The edge costs must be non-negative.
+///
+/// Returns a `Vec` mapping each node index to its score, where indices that
+/// are not nodes of the graph have a score of zero.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|·(|V| + |E|)·log(|V|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// [rev]: ../visit/struct.Reversed.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::closeness_centrality;
+/// use petgraph::prelude::*;
+///
+/// // The path 0 - 1 - 2 - 3.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+///
+/// // The node 1 is at a distance of 1, 1 and 2 of the others.
+/// let scores = closeness_centrality(&g, |_| 1.);
+/// assert_eq!(scores, vec![0.5, 0.75, 0.75, 0.5]);
+/// ```
+pub fn closeness_centrality<G, F, D>(graph: G, mut edge_cost: F) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let node_count = graph.node_identifiers().count();
+    let mut scores = vec![D::zero(); graph.node_bound()];
+    for source in graph.node_identifiers() {
+        let distances = dijkstra(graph, source, None, &mut edge_cost);
+        scores[graph.to_index(source)] = closeness(&distances, node_count);
+    }
+    scores
+}
+
+/// \[Generic\] Parallel closeness centrality of the nodes.
+///
+/// The shortest paths from each node are searched in parallel.
+/// See [`closeness_centrality`][cc].
+///
+/// [cc]: fn.closeness_centrality.html
+#[cfg(feature = "rayon")]
+pub fn parallel_closeness_centrality<G, F, D>(graph: G, edge_cost: F) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable + Sync,
+    G::NodeId: Eq + Hash + Send,
+    F: Fn(G::EdgeRef) -> D + Sync,
+    D: UnitMeasure + Copy + Send,
+{
+    let sources: Vec<_> = graph.node_identifiers().collect();
+    let node_count = sources.len();
+    let closeness: Vec<_> = sources
+        .into_par_iter()
+        .map(|source| {
+            let distances = dijkstra(graph, source, None, &edge_cost);
+            (graph.to_index(source), closeness(&distances, node_count))
+        })
+        .collect();
+    let mut scores = vec![D::zero(); graph.node_bound()];
+    for (i, score) in closeness {
+        scores[i] = score;
+    }
+    scores
+}
+
+/// \[Generic\] Harmonic centrality of the nodes.
+///
+/// The harmonic centrality of a node is the sum of the inverses of the
+/// lengths of the shortest paths from it to the other nodes, with path
+/// lengths given by `edge_cost`. Unlike [`closeness_centrality`][cc], it
+/// needs no scaling for graphs that are not strongly connected: the nodes
+/// that can not be reached add nothing.
+///
+/// Paths follow the edges as `graph.edges` does: use a [`Reversed`][rev]
+/// graph for the lengths of the paths to each node instead.
+///
+/// The edge costs must be positive.
+///
+/// Returns a `Vec` mapping each node index to its score, where indices that
+/// are not nodes of the graph have a score of zero.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|·(|V| + |E|)·log(|V|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// [cc]: fn.closeness_centrality.html
+/// [rev]: ../visit/struct.Reversed.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::harmonic_centrality;
+/// use petgraph::prelude::*;
+///
+/// // The path 0 -> 1 -> 2.
+/// let g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+///
+/// let scores = harmonic_centrality(&g, |_| 1.);
+/// assert_eq!(scores, vec![1.5, 1., 0.]);
+/// ```
+pub fn harmonic_centrality<G, F, D>(graph: G, mut edge_cost: F) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let mut scores = vec![D::zero(); graph.node_bound()];
+    for source in graph.node_identifiers() {
+        let distances = dijkstra(graph, source, None, &mut edge_cost);
+        scores[graph.to_index(source)] = harmonic(source, &distances);
+    }
+    scores
+}
+
+/// \[Generic\] Parallel harmonic centrality of the nodes.
+///
+/// The shortest paths from each node are searched in parallel.
+/// See [`harmonic_centrality`][hc].
+///
+/// [hc]: fn.harmonic_centrality.html
+#[cfg(feature = "rayon")]
+pub fn parallel_harmonic_centrality<G, F, D>(graph: G, edge_cost: F) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable + Sync,
+    G::NodeId: Eq + Hash + Send,
+    F: Fn(G::EdgeRef) -> D + Sync,
+    D: UnitMeasure + Copy + Send,
+{
+    let sources: Vec<_> = graph.node_identifiers().collect();
+    let harmonic: Vec<_> = sources
+        .into_par_iter()
+        .map(|source| {
+            let distances = dijkstra(graph, source, None, &edge_cost);
+            (graph.to_index(source), harmonic(source, &distances))
+        })
+        .collect();
+    let mut scores = vec![D::zero(); graph.node_bound()];
+    for (i, score) in harmonic {
+        scores[i] = score;
+    }
+    scores
+}
+
+/// \[Generic\] Eigenvector centrality of the nodes, by power iteration.
+///
+/// The eigenvector centrality of a node is proportional to the sum of the
+/// centralities of the nodes with an edge to it: the scores are the
+/// eigenvector of the largest eigenvalue of the transposed adjacency
+/// matrix, where parallel edges count separately. Each iteration adds the
+/// previous scores to the sums, which does not change the eigenvector but
+/// makes the iteration converge on bipartite graphs too.
+///
+/// The iteration stops after `nb_iter` iterations, or when the squared
+/// distance between the scores of two iterations is at most `tol`, which is
+/// `1e-6` by default. The scores sum to one.
+///
+/// Returns a `Vec` mapping each node index to its score, where indices that
+/// are not nodes of the graph have a score of zero.
+///
+/// # Complexity
+/// * Time complexity: **O(N·(|V| + |E|))**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **N** is the number of iterations.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::eigenvector_centrality;
+/// use petgraph::prelude::*;
+///
+/// // A star with the center 0.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+///
+/// let scores = eigenvector_centrality(&g, 100, Some(1e-12_f64));
+/// // The center has twice the score of a leaf: its eigenvalue is 2.
+/// assert!((scores[0] - 1. / 3.).abs() < 1e-5);
+/// assert!((scores[1] - 1. / 6.).abs() < 1e-5);
+/// ```
+pub fn eigenvector_centrality<G, D>(graph: G, nb_iter: usize, tol: Option<D>) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    D: UnitMeasure + Copy,
+{
+    let tolerance = tol.unwrap_or_else(D::default_tol);
+    let node_count = graph.node_identifiers().count();
+    let mut scores = vec![D::zero(); graph.node_bound()];
+    for n in graph.node_identifiers() {
+        scores[graph.to_index(n)] = D::one() / D::from_usize(node_count);
+    }
+    iterate_until_converged(scores, nb_iter, tolerance, |scores| {
+        let mut next = scores.to_vec();
+        add_in_sums(graph, scores, &mut next);
+        normalize(&mut next);
+        next
+    })
+    .0
+}
+
+/// \[Generic\] Katz centrality of the nodes, by power iteration.
+///
+/// The Katz centrality of a node is `beta` plus `alpha` times the sum of
+/// the centralities of the nodes with an edge to it, where parallel edges
+/// count separately. This counts the walks that end at the node, where a
+/// walk of length **k** weighs **alpha^k**. The iteration converges only if
+/// `alpha` is less than the inverse of the largest eigenvalue of the
+/// adjacency matrix, which is at most the inverse of the largest degree.
+///
+/// The iteration stops after `nb_iter` iterations, or when the squared
+/// distance between the scores of two iterations is at most `tol`, which is
+/// `1e-6` by default. The scores are not normalized.
+///
+/// Returns a `Vec` mapping each node index to its score, where indices that
+/// are not nodes of the graph have a score of zero.
+///
+/// # Complexity
+/// * Time complexity: **O(N·(|V| + |E|))**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **N** is the number of iterations.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::katz_centrality;
+/// use petgraph::prelude::*;
+///
+/// // The path 0 -> 1 -> 2.
+/// let g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+///
+/// let scores = katz_centrality(&g, 0.5_f64, 1., 100, None);
+/// assert_eq!(scores, vec![1., 1.5, 1.75]);
+/// ```
+pub fn katz_centrality<G, D>(graph: G, alpha: D, beta: D, nb_iter: usize, tol: Option<D>) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    D: UnitMeasure + Copy,
+{
+    let tolerance = tol.unwrap_or_else(D::default_tol);
+    let scores = vec![D::zero(); graph.node_bound()];
+    iterate_until_converged(scores, nb_iter, tolerance, |scores| {
+        let mut sums = vec![D::zero(); graph.node_bound()];
+        add_in_sums(graph, scores, &mut sums);
+        let mut next = vec![D::zero(); graph.node_bound()];
+        for n in graph.node_identifiers() {
+            let i = graph.to_index(n);
+            next[i] = alpha * sums[i] + beta;
+        }
+        next
+    })
+    .0
+}
+
+/// \[Generic\] Hub and authority scores of the nodes, with the [HITS
+/// algorithm][hits].
+///
+/// The authority score of a node is proportional to the sum of the hub
+/// scores of the nodes with an edge to it, and the hub score of a node to
+/// the sum of the authority scores of the nodes it has an edge to. Both are
+/// computed by power iteration, where parallel edges count separately.
+///
+/// The iteration stops after `nb_iter` iterations, or when the squared
+/// distance between the hub scores of two iterations is at most `tol`,
+/// which is `1e-6` by default. The hub scores sum to one, and so do the
+/// authority scores, unless the graph has no edges.
+///
+/// Returns a `Vec` of the hub scores and one of the authority scores, each
+/// mapping the node indices to their scores, where indices that are not
+/// nodes of the graph have a score of zero.
+///
+/// # Complexity
+/// * Time complexity: **O(N·(|V| + |E|))**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **N** is the number of iterations.
+///
+/// [hits]: https://en.wikipedia.org/wiki/HITS_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::hits;
+/// use petgraph::prelude::*;
+///
+/// // The nodes 0 and 1 link to 2 and 3, and 0 also to 1.
+/// let g = Graph::<(), ()>::from_edges(&[(0, 2), (0, 3), (1, 2), (1, 3), (0, 1)]);
+///
+/// let (hubs, authorities) = hits(&g, 100, Some(1e-12_f64));
+/// assert!(hubs[0] > hubs[1] && hubs[1] > hubs[2]);
+/// assert_eq!(hubs[2], 0.);
+/// assert!(authorities[2] > authorities[1] && authorities[1] > authorities[0]);
+/// assert_eq!(authorities[2], authorities[3]);
+/// ```
+pub fn hits<G, D>(graph: G, nb_iter: usize, tol: Option<D>) -> (Vec<D>, Vec<D>)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    D: UnitMeasure + Copy,
+{
+    let tolerance = tol.unwrap_or_else(D::default_tol);
+    let node_count = graph.node_identifiers().count();
+    let mut hubs = vec![D::zero(); graph.node_bound()];
+    for n in graph.node_identifiers() {
+        hubs[graph.to_index(n)] = D::one() / D::from_usize(node_count);
+    }
+    let mut authorities = vec![D::zero(); graph.node_bound()];
+    let (hubs, _, _) = iterate_until_converged(hubs, nb_iter, tolerance, |hubs| {
+        authorities = vec![D::zero(); graph.node_bound()];
+        add_in_sums(graph, hubs, &mut authorities);
+        normalize(&mut authorities);
+        let mut next = vec![D::zero(); graph.node_bound()];
+        for n in graph.node_identifiers() {
+            let i = graph.to_index(n);
+            next[i] = graph
+                .edges(n)
+                .map(|edge| authorities[graph.to_index(edge.target())])
+                .sum();
+        }
+        normalize(&mut next);
+        next
+    });
+    (hubs, authorities)
+}
+
+/// The shortest paths from one node, and the dependencies of the source on
+/// the other nodes.
+struct Brandes<K, D> {
+    /// The nodes that can be reached, from the nearest to the furthest.
+    order: Vec<usize>,
+    distances: Vec<Option<K>>,
+    /// The number of shortest paths to each node.
+    paths: Vec<D>,
+    /// The previous node and the edge index of the last edge of each
+    /// shortest path to each node.
+    predecessors: Vec<Vec<(usize, usize)>>,
+    dependencies: Vec<D>,
+}
+
+impl<K, D> Brandes<K, D>
+where
+    K: Measure + Copy,
+    D: UnitMeasure + Copy,
+{
+    fn new(node_bound: usize) -> Self {
+        Brandes {
+            order: Vec::with_capacity(node_bound),
+            distances: vec![None; node_bound],
+            paths: vec![D::zero(); node_bound],
+            predecessors: vec![vec![]; node_bound],
+            dependencies: vec![D::zero(); node_bound],
+        }
+    }
+
+    fn clear(&mut self) {
+        for &n in &self.order {
+            self.distances[n] = None;
+            self.paths[n] = D::zero();
+            self.predecessors[n].clear();
+            self.dependencies[n] = D::zero();
+        }
+        self.order.clear();
+    }
+
+    fn dijkstra<G, F, E>(&mut self, graph: G, source: G::NodeId, mut edge_cost: F, edge_index: E)
+    where
+        G: IntoEdges + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> K,
+        E: Fn(G::EdgeId) -> usize,
+    {
+        self.clear();
+        let s = graph.to_index(source);
+        self.distances[s] = Some(K::default());
+        self.paths[s] = D::one();
+        let mut visit_next = BinaryHeap::new();
+        visit_next.push(MinScored(K::default(), s));
+        let mut visited = vec![false; self.distances.len()];
+        while let Some(MinScored(distance, v)) = visit_next.pop() {
+            if visited[v] {
+                continue;
+            }
+            visited[v] = true;
+            self.order.push(v);
+            for edge in graph.edges(graph.from_index(v)) {
+                let w = graph.to_index(edge.target());
+                if w == v {
+                    continue;
+                }
+                let e = edge_index(edge.id());
+                let next = distance + edge_cost(edge);
+                match self.distances[w] {
+                    Some(d) if next > d => {}
+                    Some(d) if next == d => {
+                        self.paths[w] = self.paths[w] + self.paths[v];
+                        self.predecessors[w].push((v, e));
+                    }
+                    _ => {
+                        self.distances[w] = Some(next);
+                        self.paths[w] = self.paths[v];
+                        self.predecessors[w].clear();
+                        self.predecessors[w].push((v, e));
+                        visit_next.push(MinScored(next, w));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Add the dependencies of the source to `node_scores`, and to
+    /// `edge_scores` by edge index.
+    fn accumulate(&mut self, node_scores: &mut [D], mut edge_scores: Option<&mut [D]>) {
+        for (i, &w) in self.order.iter().enumerate().rev() {
+            let coefficient = (D::one() + self.dependencies[w]) / self.paths[w];
+            for &(v, e) in &self.predecessors[w] {
+                let dependency = self.paths[v] * coefficient;
+                self.dependencies[v] = self.dependencies[v] + dependency;
+                if let Some(edge_scores) = edge_scores.as_mut() {
+                    edge_scores[e] = edge_scores[e] + dependency;
+                }
+            }
+            // The source is first.
+            if i > 0 {
+                node_scores[w] = node_scores[w] + self.dependencies[w];
+            }
+        }
+    }
+}
+
+impl<D> Brandes<usize, D>
+where
+    D: UnitMeasure + Copy,
+{
+    fn breadth_first<G, E>(&mut self, graph: G, source: G::NodeId, edge_index: E)
+    where
+        G: IntoEdges + NodeIndexable,
+        E: Fn(G::EdgeId) -> usize,
+    {
+        self.clear();
+        let s = graph.to_index(source);
+        self.distances[s] = Some(0);
+        self.paths[s] = D::one();
+        let mut visit_next = VecDeque::new();
+        visit_next.push_back(s);
+        while let Some(v) = visit_next.pop_front() {
+            self.order.push(v);
+            let next = self.distances[v].unwrap() + 1;
+            for edge in graph.edges(graph.from_index(v)) {
+                let w = graph.to_index(edge.target());
+                match self.distances[w] {
+                    Some(d) if d != next => continue,
+                    Some(_) => {}
+                    None => {
+                        self.distances[w] = Some(next);
+                        visit_next.push_back(w);
+                    }
+                }
+                self.paths[w] = self.paths[w] + self.paths[v];
+                self.predecessors[w].push((v, edge_index(edge.id())));
+            }
+        }
+    }
+}
+
+/// The factor of the node betweenness scores, which count each pair of
+/// nodes in both directions.
+fn node_scale<G, D>(graph: G, normalized: bool) -> D
+where
+    G: IntoNodeIdentifiers + GraphProp,
+    D: UnitMeasure,
+{
+    let node_count = graph.node_identifiers().count();
+    if normalized && node_count > 2 {
+        D::one() / D::from_usize((node_count - 1) * (node_count - 2))
+    } else if !normalized && !graph.is_directed() {
+        D::one() / D::from_usize(2)
+    } else {
+        D::one()
+    }
+}
+
+/// The factor of the edge betweenness scores, which count each pair of
+/// nodes in both directions.
+fn edge_scale<G, D>(graph: G, normalized: bool) -> D
+where
+    G: IntoNodeIdentifiers + GraphProp,
+    D: UnitMeasure,
+{
+    let node_count = graph.node_identifiers().count();
+    if normalized && node_count > 1 {
+        D::one() / D::from_usize(node_count * (node_count - 1))
+    } else if !normalized && !graph.is_directed() {
+        D::one() / D::from_usize(2)
+    } else {
+        D::one()
+    }
+}
+
+fn rescale<D>(scores: &mut [D], scale: D)
+where
+    D: UnitMeasure + Copy,
+{
+    for score in scores {
+        *score = *score * scale;
+    }
+}
+
+#[cfg(feature = "rayon")]
+fn add_scores<D>(mut a: Vec<D>, b: Vec<D>) -> Vec<D>
+where
+    D: UnitMeasure + Copy,
+{
+    for (a, b) in a.iter_mut().zip(b) {
+        *a = *a + b;
+    }
+    a
+}
+
+/// The closeness of a node, from the distances to the nodes it can reach.
+fn closeness<N, D>(distances: &HashMap<N, D>, node_count: usize) -> D
+where
+    D: UnitMeasure + Copy,
+{
+    let total: D = distances.values().cloned().sum();
+    // The source is at a distance of zero.
+    let reached = distances.len() - 1;
+    if reached == 0 || total == D::zero() {
+        return D::zero();
+    }
+    let reached = D::from_usize(reached);
+    reached / total * reached / D::from_usize(node_count - 1)
+}
+
+/// The harmonic centrality of a node, from the distances to the nodes it
+/// can reach.
+fn harmonic<N, D>(source: N, distances: &HashMap<N, D>) -> D
+where
+    N: Eq + Hash,
+    D: UnitMeasure + Copy,
+{
+    distances
+        .iter()
+        .filter(|&(n, _)| *n != source)
+        .map(|(_, &d)| D::one() / d)
+        .sum()
+}
+
+/// Add to `sums` the sum of the `scores` of the nodes with an edge to each
+/// node.
+fn add_in_sums<G, D>(graph: G, scores: &[D], sums: &mut [D])
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    D: UnitMeasure + Copy,
+{
+    for n in graph.node_identifiers() {
+        let score = scores[graph.to_index(n)];
+        for edge in graph.edges(n) {
+            let i = graph.to_index(edge.target());
+            sums[i] = sums[i] + score;
+        }
+    }
+}
+
+/// Divide the scores by their sum, unless it is zero.
+fn normalize<D>(scores: &mut [D])
+where
+    D: UnitMeasure + Copy,
+{
+    let sum: D = scores.iter().cloned().sum();
+    if sum != D::zero() {
+        for score in scores {
+            *score = *score / sum;
+        }
+    }
+}
@@ -0,0 +1,338 @@
+//! Clique algorithms.
+
+use std::cmp::Reverse;
+
+use crate::algo::adjacency::Adjacency;
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Enumerate the *maximal cliques* of a graph.
+///
+/// A clique is a set of nodes that are all neighbors of each other, and a
+/// maximal clique is one that is not contained in a larger clique. Each
+/// maximal clique is yielded once, as a vector of nodes.
+///
+/// Uses the [Bron–Kerbosch algorithm][bk] with pivoting, where the cliques
+/// are searched from each node in a degeneracy ordering (Eppstein, Löffler
+/// and Strash). A graph with **n** nodes and degeneracy **d** has at most
+/// **(n - d)·3^(d/3)** maximal cliques, which are all found in
+/// **O(d·n·3^(d/3))** time.
+///
+/// The graph is treated as if undirected: two nodes are neighbors if there
+/// is an edge between them in either direction. Self loops are ignored, so
+/// a node without other neighbors is a maximal clique by itself.
+///
+/// [bk]: https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::maximal_cliques;
+/// use petgraph::prelude::*;
+///
+/// // Two triangles 0, 1, 2 and 1, 2, 3 with the common edge 1 - 2, and
+/// // the edge 3 - 4.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+///
+/// let mut cliques: Vec<Vec<_>> = maximal_cliques(&g)
+///     .map(|mut clique| {
+///         clique.sort();
+///         clique.into_iter().map(|n| n.index()).collect()
+///     })
+///     .collect();
+/// cliques.sort();
+/// assert_eq!(cliques, vec![vec![0, 1, 2], vec![1, 2, 3], vec![3, 4]]);
+/// ```
+pub fn maximal_cliques<G>(graph: G) -> MaximalCliques<G>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let adjacency = Adjacency::new(graph);
+    let order = adjacency.degeneracy_order();
+    let neighbors = adjacency.neighbors;
+    let mut rank = vec![0; neighbors.len()];
+    for (i, &n) in order.iter().enumerate() {
+        rank[n] = i;
+    }
+    MaximalCliques {
+        graph,
+        neighbors,
+        order,
+        rank,
+        next_node: 0,
+        stack: Vec::new(),
+    }
+}
+
+/// An iterator over the maximal cliques of a graph.
+///
+/// Created with [`maximal_cliques`][mc].
+///
+/// [mc]: fn.maximal_cliques.html
+#[derive(Debug, Clone)]
+pub struct MaximalCliques<G> {
+    graph: G,
+    neighbors: Vec<Vec<usize>>,
+    order: Vec<usize>,
+    /// The position of each node in `order`.
+    rank: Vec<usize>,
+    /// The position in `order` of the next node to search the cliques from.
+    next_node: usize,
+    stack: Vec<CliqueSearch>,
+}
+
+/// A step of the Bron–Kerbosch algorithm: the cliques that contain all of
+/// `clique`, some of `candidates`, and none of `excluded`. The nodes of
+/// `branches` are the candidates to add next.
+#[derive(Debug, Clone)]
+struct CliqueSearch {
+    clique: Vec<usize>,
+    candidates: Vec<usize>,
+    excluded: Vec<usize>,
+    branches: Vec<usize>,
+}
+
+impl CliqueSearch {
+    fn new(
+        clique: Vec<usize>,
+        candidates: Vec<usize>,
+        excluded: Vec<usize>,
+        neighbors: &[Vec<usize>],
+    ) -> Self {
+        // The pivot is the node with the most neighbors among the
+        // candidates: the cliques with none of them contain the pivot or
+        // one of its other neighbors.
+        let pivot = candidates
+            .iter()
+            .chain(&excluded)
+            .max_by_key(|&&u| count_common(&candidates, &neighbors[u]));
+        let branches = match pivot {
+            Some(&u) => candidates
+                .iter()
+                .cloned()
+                .filter(|v| neighbors[u].binary_search(v).is_err())
+                .collect(),
+            None => vec![],
+        };
+        CliqueSearch {
+            clique,
+            candidates,
+            excluded,
+            branches,
+        }
+    }
+}
+
+impl<G> Iterator for MaximalCliques<G>
+where
+    G: NodeIndexable,
+{
+    type Item = Vec<G::NodeId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let search = match self.stack.last_mut() {
+                Some(search) => search,
+                None => {
+                    // The cliques where the next node comes first in `order`.
+                    let &v = self.order.get(self.next_node)?;
+                    self.next_node += 1;
+                    let rank = &self.rank;
+                    let (later, earlier): (Vec<_>, Vec<_>) = self.neighbors[v]
+                        .iter()
+                        .cloned()
+                        .partition(|&u| rank[u] > rank[v]);
+                    if later.is_empty() && earlier.is_empty() {
+                        return Some(vec![self.graph.from_index(v)]);
+                    }
+                    self.stack
+                        .push(CliqueSearch::new(vec![v], later, earlier, &self.neighbors));
+                    continue;
+                }
+            };
+            let v = match search.branches.pop() {
+                Some(v) => v,
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+
+            let candidates = intersection(&search.candidates, &self.neighbors[v]);
+            let excluded = intersection(&search.excluded, &self.neighbors[v]);
+            let mut clique = search.clique.clone();
+            clique.push(v);
+            // The next cliques of this step do not contain `v`.
+            if let Ok(i) = search.candidates.binary_search(&v) {
+                search.candidates.remove(i);
+            }
+            if let Err(i) = search.excluded.binary_search(&v) {
+                search.excluded.insert(i, v);
+            }
+
+            if candidates.is_empty() {
+                if excluded.is_empty() {
+                    return Some(
+                        clique
+                            .into_iter()
+                            .map(|n| self.graph.from_index(n))
+                            .collect(),
+                    );
+                }
+            } else {
+                self.stack.push(CliqueSearch::new(
+                    clique,
+                    candidates,
+                    excluded,
+                    &self.neighbors,
+                ));
+            }
+        }
+    }
+}
+
+/// \[Generic\] Find a *maximum clique* of a graph.
+///
+/// A clique is a set of nodes that are all neighbors of each other. Finding
+/// one with the most nodes is NP-hard: this uses a branch and bound search,
+/// where the size of a clique that can still be found is bounded by a greedy
+/// coloring of the candidate nodes (Tomita and Seki). This is much faster
+/// than enumerating the [`maximal_cliques`][mc] on dense graphs.
+///
+/// The graph is treated as if undirected: two nodes are neighbors if there
+/// is an edge between them in either direction. Self loops are ignored.
+///
+/// Returns the nodes of a maximum clique, which is empty only if the graph
+/// has no nodes.
+///
+/// [mc]: fn.maximal_cliques.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::maximum_clique;
+/// use petgraph::prelude::*;
+///
+/// // A wheel: a cycle 0 -> 1 -> 2 -> 3 -> 0 around the node 4, with the
+/// // diagonal 0 - 2.
+/// let g = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0),
+///     (0, 4), (1, 4), (2, 4), (3, 4),
+///     (0, 2),
+/// ]);
+///
+/// let mut clique = maximum_clique(&g);
+/// clique.sort();
+/// assert_eq!(clique.len(), 4);
+/// assert!(clique == vec![0.into(), 1.into(), 2.into(), 4.into()]
+///     || clique == vec![0.into(), 2.into(), 3.into(), 4.into()]);
+/// ```
+pub fn maximum_clique<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let Adjacency {
+        mut nodes,
+        neighbors,
+        ..
+    } = Adjacency::new(graph);
+    // Nodes of large degree are more likely in a large clique, and are
+    // searched first.
+    nodes.sort_by_key(|&n| Reverse(neighbors[n].len()));
+
+    let mut search = MaximumClique {
+        neighbors: &neighbors,
+        clique: vec![],
+        best: vec![],
+    };
+    search.expand(nodes);
+    search
+        .best
+        .into_iter()
+        .map(|n| graph.from_index(n))
+        .collect()
+}
+
+struct MaximumClique<'a> {
+    neighbors: &'a [Vec<usize>],
+    clique: Vec<usize>,
+    best: Vec<usize>,
+}
+
+impl MaximumClique<'_> {
+    /// Search the cliques with all nodes of `clique` and some of `candidates`,
+    /// which are all neighbors of the nodes of `clique`.
+    fn expand(&mut self, candidates: Vec<usize>) {
+        if candidates.is_empty() {
+            if self.clique.len() > self.best.len() {
+                self.best.clone_from(&self.clique);
+            }
+            return;
+        }
+
+        // Color the candidates greedily: a clique has at most one node of
+        // each color.
+        let mut classes: Vec<Vec<usize>> = vec![];
+        for &v in &candidates {
+            let neighbors = &self.neighbors[v];
+            match classes
+                .iter_mut()
+                .find(|class| class.iter().all(|u| neighbors.binary_search(u).is_err()))
+            {
+                Some(class) => class.push(v),
+                None => classes.push(vec![v]),
+            }
+        }
+
+        // The nodes of the most colors first, which can make the largest
+        // cliques.
+        let mut remaining: Vec<usize> = classes.iter().flatten().cloned().collect();
+        for (color_count, class) in classes.iter().enumerate().rev() {
+            for _ in 0..class.len() {
+                if self.clique.len() + color_count < self.best.len() {
+                    return;
+                }
+                let v = remaining.pop().unwrap();
+                let next = remaining
+                    .iter()
+                    .cloned()
+                    .filter(|u| self.neighbors[v].binary_search(u).is_ok())
+                    .collect();
+                self.clique.push(v);
+                self.expand(next);
+                self.clique.pop();
+            }
+        }
+    }
+}
+
+/// The common elements of two sorted lists.
+fn intersection(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let mut common = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] < b[j] {
+            i += 1;
+        } else if a[i] > b[j] {
+            j += 1;
+        } else {
+            common.push(a[i]);
+            i += 1;
+            j += 1;
+        }
+    }
+    common
+}
+
+fn count_common(a: &[usize], b: &[usize]) -> usize {
+    let (mut count, mut i, mut j) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] < b[j] {
+            i += 1;
+        } else if a[i] > b[j] {
+            j += 1;
+        } else {
+            count += 1;
+            i += 1;
+            j += 1;
+        }
+    }
+    count
+}
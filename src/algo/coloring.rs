@@ -0,0 +1,619 @@
+//! Vertex and edge coloring algorithms.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::algo::adjacency::Adjacency;
+use crate::algo::bipartition;
+use crate::visit::{
+    EdgeRef, IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers, NodeCompactIndexable,
+    NodeIndexable,
+};
+
+/// The order in which [`greedy_coloring`][gc] colors the nodes.
+///
+/// [gc]: fn.greedy_coloring.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColoringOrder {
+    /// By decreasing degree (Welsh–Powell).
+    LargestFirst,
+    /// The reverse of the order in which nodes of the smallest degree are
+    /// removed from the graph one by one (Matula–Beck). It uses at most
+    /// **d + 1** colors, where **d** is the degeneracy of the graph, so
+    /// planar graphs get at most six colors.
+    SmallestLast,
+    /// The node with the most distinct colors among its neighbors first,
+    /// then the one with the most uncolored neighbors ([Brélaz][dsatur]).
+    /// It is optimal on bipartite graphs.
+    ///
+    /// [dsatur]: https://en.wikipedia.org/wiki/DSatur
+    DSatur,
+}
+
+/// \[Generic\] Color the nodes of a graph so that no two neighbors share a
+/// color.
+///
+/// Each node gets the smallest color that is not used by its neighbors, in
+/// the given `order` (see [`ColoringOrder`][order]). This [greedy coloring][gc]
+/// may use more colors than needed, [`chromatic_number`][cn] finds the
+/// least number of colors.
+///
+/// The graph is treated as if undirected, and self loops are ignored.
+///
+/// Returns the color of each node; the colors are numbered from `0`. Use
+/// [`greedy_coloring_dense`][dense] to get them in a vector instead.
+///
+/// Computes in **O((|V| + |E|)·log(|V|))** time.
+///
+/// [order]: enum.ColoringOrder.html
+/// [gc]: https://en.wikipedia.org/wiki/Greedy_coloring
+/// [cn]: fn.chromatic_number.html
+/// [dense]: fn.greedy_coloring_dense.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{greedy_coloring, ColoringOrder};
+/// use petgraph::prelude::*;
+///
+/// // A cycle of length 6, where 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 0.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+///
+/// let coloring = greedy_coloring(&g, ColoringOrder::DSatur);
+/// assert_eq!(coloring.values().max(), Some(&1));
+/// for edge in g.edge_references() {
+///     assert_ne!(coloring[&edge.source()], coloring[&edge.target()]);
+/// }
+/// ```
+pub fn greedy_coloring<G>(graph: G, order: ColoringOrder) -> HashMap<G::NodeId, usize>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency = Adjacency::new(graph);
+    let colors = adjacency.greedy(order);
+    graph
+        .node_identifiers()
+        .map(|n| (n, colors[graph.to_index(n)]))
+        .collect()
+}
+
+/// \[Generic\] Color the nodes of a graph so that no two neighbors share a
+/// color.
+///
+/// Like [`greedy_coloring`][gc], but the colors are in a vector indexed by
+/// the graph's node indices.
+///
+/// [gc]: fn.greedy_coloring.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{greedy_coloring_dense, ColoringOrder};
+/// use petgraph::prelude::*;
+///
+/// // A star, with a center node 0.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+///
+/// let colors = greedy_coloring_dense(&g, ColoringOrder::LargestFirst);
+/// assert_eq!(colors, vec![0, 1, 1, 1]);
+/// ```
+pub fn greedy_coloring_dense<G>(graph: G, order: ColoringOrder) -> Vec<usize>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeCompactIndexable,
+{
+    Adjacency::new(graph).greedy(order)
+}
+
+/// \[Generic\] Compute the chromatic number of a graph, and a coloring that
+/// achieves it.
+///
+/// The [chromatic number][cn] is the least number of colors that are needed
+/// so that no two neighbors share a color. Computing it is NP-hard: this uses
+/// a backtracking search in the `DSatur` order, with branch and bound, which
+/// is only suited to small graphs (up to about a hundred nodes, depending on
+/// the density).
+///
+/// The graph is treated as if undirected, and self loops are ignored.
+///
+/// Returns the number of colors and the color of each node; the colors are
+/// numbered from `0`. Use [`chromatic_number_dense`][dense] to get them in a
+/// vector instead.
+///
+/// [cn]: https://en.wikipedia.org/wiki/Graph_coloring#Chromatic_number
+/// [dense]: fn.chromatic_number_dense.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::chromatic_number;
+/// use petgraph::prelude::*;
+///
+/// // The Petersen graph: an outer and an inner cycle of length 5.
+/// let mut edges = vec![];
+/// for i in 0..5 {
+///     edges.push((i, (i + 1) % 5));
+///     edges.push((i, i + 5));
+///     edges.push((i + 5, (i + 2) % 5 + 5));
+/// }
+/// let g = UnGraph::<(), ()>::from_edges(&edges);
+///
+/// let (count, coloring) = chromatic_number(&g);
+/// assert_eq!(count, 3);
+/// for edge in g.edge_references() {
+///     assert_ne!(coloring[&edge.source()], coloring[&edge.target()]);
+/// }
+/// ```
+pub fn chromatic_number<G>(graph: G) -> (usize, HashMap<G::NodeId, usize>)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency = Adjacency::new(graph);
+    let (count, colors) = adjacency.exact();
+    let coloring = graph
+        .node_identifiers()
+        .map(|n| (n, colors[graph.to_index(n)]))
+        .collect();
+    (count, coloring)
+}
+
+/// \[Generic\] Compute the chromatic number of a graph, and a coloring that
+/// achieves it.
+///
+/// Like [`chromatic_number`][cn], but the colors are in a vector indexed by
+/// the graph's node indices.
+///
+/// [cn]: fn.chromatic_number.html
+pub fn chromatic_number_dense<G>(graph: G) -> (usize, Vec<usize>)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeCompactIndexable,
+{
+    Adjacency::new(graph).exact()
+}
+
+/// \[Generic\] Color the edges of a graph so that no two edges with a common
+/// endpoint share a color.
+///
+/// Uses the [Misra–Gries algorithm][mg], which colors a graph without
+/// parallel edges with at most **Δ + 1** colors, where **Δ** is the maximum
+/// degree of a node. At least **Δ** colors are always needed, and
+/// [`bipartite_edge_coloring`][bipartite] only uses **Δ** colors on bipartite
+/// graphs.
+///
+/// On multigraphs, the edges parallel to an already colored one are colored
+/// last, each with the smallest color that is free at both endpoints. This
+/// uses at most **2Δ - 1** colors.
+///
+/// The graph is treated as if undirected. Self loops can not be colored and
+/// are left out.
+///
+/// Returns the color of each edge; the colors are numbered from `0`.
+///
+/// Computes in **O(|V|·|E|)** time.
+///
+/// [mg]: https://en.wikipedia.org/wiki/Misra_%26_Gries_edge_coloring_algorithm
+/// [bipartite]: fn.bipartite_edge_coloring.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::edge_coloring;
+/// use petgraph::prelude::*;
+///
+/// // Each pair of 4 players meets once, and each player is in one meeting
+/// // per round.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+///
+/// let rounds = edge_coloring(&g);
+/// assert_eq!(rounds.len(), 6);
+/// assert!(rounds.values().all(|&round| round < 4));
+/// for a in g.edge_references() {
+///     for b in g.edges(a.source()).chain(g.edges(a.target())) {
+///         assert!(a.id() == b.id() || rounds[&a.id()] != rounds[&b.id()]);
+///     }
+/// }
+/// ```
+pub fn edge_coloring<G>(graph: G) -> HashMap<G::EdgeId, usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    G::EdgeId: Eq + Hash,
+{
+    let (ids, mut edges) = EdgeColors::new(graph);
+
+    // One edge between each pair of nodes first, then the parallel ones.
+    let mut pairs = HashSet::new();
+    let mut parallel = vec![];
+    for e in 0..ids.len() {
+        let (a, b) = edges.ends[e];
+        if pairs.insert((a.min(b), a.max(b))) {
+            edges.misra_gries(e);
+        } else {
+            parallel.push(e);
+        }
+    }
+    for e in parallel {
+        let (a, b) = edges.ends[e];
+        let color = (0..)
+            .find(|&c| edges.is_free(a, c) && edges.is_free(b, c))
+            .unwrap();
+        edges.set(e, color);
+    }
+
+    ids.into_iter().zip(edges.colors).collect()
+}
+
+/// \[Generic\] Color the edges of a bipartite graph with the least number of
+/// colors, so that no two edges with a common endpoint share a color.
+///
+/// By [Kőnig's theorem][konig], a bipartite graph, even with parallel edges,
+/// can be colored with **Δ** colors, where **Δ** is the maximum degree of a
+/// node. Each edge is colored in turn, after swapping the two colors of an
+/// alternating path if needed.
+///
+/// The graph is treated as if undirected. Returns the color of each edge,
+/// numbered from `0`, or `None` if the graph is not bipartite.
+///
+/// Computes in **O(|V|·|E|)** time.
+///
+/// [konig]: https://en.wikipedia.org/wiki/K%C5%91nig%27s_theorem_(graph_theory)
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::bipartite_edge_coloring;
+/// use petgraph::prelude::*;
+///
+/// // Teachers 0 and 1 give lessons to classes 2, 3 and 4, twice to class 2.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 2), (0, 2), (0, 3), (1, 2), (1, 4)]);
+///
+/// let hours = bipartite_edge_coloring(&g).unwrap();
+/// assert!(hours.values().all(|&hour| hour < 3));
+///
+/// // An odd cycle is not bipartite.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// assert_eq!(bipartite_edge_coloring(&g), None);
+/// ```
+pub fn bipartite_edge_coloring<G>(graph: G) -> Option<HashMap<G::EdgeId, usize>>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    G::EdgeId: Eq + Hash,
+{
+    bipartition(graph)?;
+    let (ids, mut edges) = EdgeColors::new(graph);
+
+    for e in 0..ids.len() {
+        let (a, b) = edges.ends[e];
+        let free_a = edges.free(a);
+        let free_b = edges.free(b);
+        if !edges.is_free(b, free_a) {
+            // The path of colors `free_a` and `free_b` from `b` can not end
+            // at `a`, because the graph is bipartite.
+            edges.swap_path(b, free_a, free_b);
+        }
+        edges.set(e, free_a);
+    }
+
+    Some(ids.into_iter().zip(edges.colors).collect())
+}
+
+impl Adjacency {
+    fn greedy(&self, order: ColoringOrder) -> Vec<usize> {
+        match order {
+            ColoringOrder::LargestFirst => {
+                let mut nodes = self.nodes.clone();
+                nodes.sort_by_key(|&n| Reverse(self.neighbors[n].len()));
+                self.color_in_order(&nodes)
+            }
+            ColoringOrder::SmallestLast => {
+                let mut nodes = self.degeneracy_order();
+                nodes.reverse();
+                self.color_in_order(&nodes)
+            }
+            ColoringOrder::DSatur => self.dsatur(),
+        }
+    }
+
+    /// Give each node the smallest color not used by its neighbors.
+    fn color_in_order(&self, nodes: &[usize]) -> Vec<usize> {
+        let mut colors = vec![std::usize::MAX; self.neighbors.len()];
+        // `used[c] == n` if color `c` is used by a neighbor of `n`.
+        let mut used = vec![std::usize::MAX; self.nodes.len() + 1];
+        for &n in nodes {
+            for &m in &self.neighbors[n] {
+                if colors[m] != std::usize::MAX {
+                    used[colors[m]] = n;
+                }
+            }
+            colors[n] = (0..).find(|&c| used[c] != n).unwrap();
+        }
+        colors
+    }
+
+    fn dsatur(&self) -> Vec<usize> {
+        let mut colors = vec![std::usize::MAX; self.neighbors.len()];
+        // The distinct colors of the neighbors of each node, and the number
+        // of its uncolored neighbors.
+        let mut neighbor_colors = vec![HashSet::new(); self.neighbors.len()];
+        let mut degrees: Vec<_> = self.neighbors.iter().map(|list| list.len()).collect();
+        let mut heap: BinaryHeap<_> = self
+            .nodes
+            .iter()
+            .map(|&n| (0, degrees[n], Reverse(n)))
+            .collect();
+        while let Some((saturation, degree, Reverse(n))) = heap.pop() {
+            if colors[n] != std::usize::MAX
+                || saturation != neighbor_colors[n].len()
+                || degree != degrees[n]
+            {
+                continue;
+            }
+            let color = (0..).find(|c| !neighbor_colors[n].contains(c)).unwrap();
+            colors[n] = color;
+            for &m in &self.neighbors[n] {
+                if colors[m] == std::usize::MAX {
+                    neighbor_colors[m].insert(color);
+                    degrees[m] -= 1;
+                    heap.push((neighbor_colors[m].len(), degrees[m], Reverse(m)));
+                }
+            }
+        }
+        colors
+    }
+
+    /// The least number of colors, and the colors of the nodes.
+    fn exact(&self) -> (usize, Vec<usize>) {
+        let best = self.dsatur();
+        let count = best
+            .iter()
+            .filter(|&&c| c != std::usize::MAX)
+            .max()
+            .map_or(0, |&c| c + 1);
+        let lower_bound = self.clique_size();
+        if count <= lower_bound {
+            return (count, best);
+        }
+        let mut search = ExactColoring {
+            adjacency: self,
+            colors: vec![std::usize::MAX; self.neighbors.len()],
+            // `conflicts[n][c]` is the number of neighbors of `n` with color `c`.
+            conflicts: vec![vec![0; count]; self.neighbors.len()],
+            best,
+            count,
+            lower_bound,
+        };
+        search.color(0, 0);
+        (search.count, search.best)
+    }
+
+    /// The size of a clique found greedily, which is a lower bound of the
+    /// chromatic number.
+    fn clique_size(&self) -> usize {
+        let mut best = 0;
+        for &n in &self.nodes {
+            let mut clique = vec![n];
+            let mut candidates = self.neighbors[n].clone();
+            candidates.sort_by_key(|&m| Reverse(self.neighbors[m].len()));
+            for m in candidates {
+                if clique
+                    .iter()
+                    .all(|c| self.neighbors[m].binary_search(c).is_ok())
+                {
+                    clique.push(m);
+                }
+            }
+            best = best.max(clique.len());
+        }
+        best
+    }
+}
+
+struct ExactColoring<'a> {
+    adjacency: &'a Adjacency,
+    colors: Vec<usize>,
+    conflicts: Vec<Vec<usize>>,
+    /// The best coloring found so far, and its number of colors.
+    best: Vec<usize>,
+    count: usize,
+    lower_bound: usize,
+}
+
+impl ExactColoring<'_> {
+    /// Color the remaining nodes, when `colored` nodes use `used` colors.
+    /// Returns `true` when the lower bound is reached.
+    fn color(&mut self, colored: usize, used: usize) -> bool {
+        if used >= self.count {
+            return false;
+        }
+        if colored == self.adjacency.nodes.len() {
+            self.best.clone_from(&self.colors);
+            self.count = used;
+            return self.count <= self.lower_bound;
+        }
+
+        // The uncolored node with the most distinct colors among its
+        // neighbors, then with the most neighbors.
+        let saturation = |n: usize| self.conflicts[n][..used].iter().filter(|&&k| k > 0).count();
+        let n = self
+            .adjacency
+            .nodes
+            .iter()
+            .cloned()
+            .filter(|&n| self.colors[n] == std::usize::MAX)
+            .max_by_key(|&n| (saturation(n), self.adjacency.neighbors[n].len(), Reverse(n)))
+            .unwrap();
+
+        // A new color is only tried if it can make a better coloring.
+        for color in 0..used + 1 {
+            if self.conflicts[n][color] > 0 {
+                continue;
+            }
+            self.set(n, color);
+            let done = self.color(colored + 1, used.max(color + 1));
+            self.unset(n, color);
+            if done {
+                return true;
+            }
+            if self.count <= used.max(color + 1) {
+                // Only colorings with fewer colors are searched now.
+                break;
+            }
+        }
+        false
+    }
+
+    fn set(&mut self, n: usize, color: usize) {
+        self.colors[n] = color;
+        for &m in &self.adjacency.neighbors[n] {
+            self.conflicts[m][color] += 1;
+        }
+    }
+
+    fn unset(&mut self, n: usize, color: usize) {
+        self.colors[n] = std::usize::MAX;
+        for &m in &self.adjacency.neighbors[n] {
+            self.conflicts[m][color] -= 1;
+        }
+    }
+}
+
+/// The edges of a graph by index, with their colors.
+struct EdgeColors {
+    ends: Vec<(usize, usize)>,
+    colors: Vec<usize>,
+    /// The edges of each node, and the edge of each color at each node.
+    incident: Vec<Vec<usize>>,
+    at: Vec<HashMap<usize, usize>>,
+}
+
+impl EdgeColors {
+    /// Return the identifiers of the edges that are not self loops, and the
+    /// uncolored edges with the same indices.
+    fn new<G>(graph: G) -> (Vec<G::EdgeId>, Self)
+    where
+        G: IntoEdgeReferences + NodeIndexable,
+    {
+        let mut ids = vec![];
+        let mut ends = vec![];
+        let mut incident = vec![vec![]; graph.node_bound()];
+        for edge in graph.edge_references() {
+            let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+            if a != b {
+                incident[a].push(ids.len());
+                incident[b].push(ids.len());
+                ids.push(edge.id());
+                ends.push((a, b));
+            }
+        }
+        let edges = EdgeColors {
+            colors: vec![std::usize::MAX; ends.len()],
+            ends,
+            at: vec![HashMap::new(); incident.len()],
+            incident,
+        };
+        (ids, edges)
+    }
+
+    fn other(&self, e: usize, node: usize) -> usize {
+        let (a, b) = self.ends[e];
+        if a == node {
+            b
+        } else {
+            a
+        }
+    }
+
+    fn is_free(&self, node: usize, color: usize) -> bool {
+        !self.at[node].contains_key(&color)
+    }
+
+    /// The smallest color that is free at `node`.
+    fn free(&self, node: usize) -> usize {
+        (0..).find(|&c| self.is_free(node, c)).unwrap()
+    }
+
+    fn set(&mut self, e: usize, color: usize) {
+        let (a, b) = self.ends[e];
+        self.colors[e] = color;
+        self.at[a].insert(color, e);
+        self.at[b].insert(color, e);
+    }
+
+    fn unset(&mut self, e: usize) {
+        let (a, b) = self.ends[e];
+        let color = std::mem::replace(&mut self.colors[e], std::usize::MAX);
+        self.at[a].remove(&color);
+        self.at[b].remove(&color);
+    }
+
+    /// Swap the colors `c` and `d` on the path from `start` whose edges have
+    /// these colors in turn, starting with `c`.
+    fn swap_path(&mut self, start: usize, c: usize, d: usize) {
+        let mut path = vec![];
+        let (mut node, mut color) = (start, c);
+        while let Some(&e) = self.at[node].get(&color) {
+            path.push(e);
+            node = self.other(e, node);
+            color = if color == c { d } else { c };
+        }
+        for &e in &path {
+            self.unset(e);
+        }
+        for (i, &e) in path.iter().enumerate() {
+            self.set(e, if i % 2 == 0 { d } else { c });
+        }
+    }
+
+    /// Color the edge `e` with the colors of the other edges at most one
+    /// higher than the maximum degree, recoloring some of them.
+    fn misra_gries(&mut self, e: usize) {
+        let x = self.ends[e].0;
+
+        // A maximal fan of `x`: its edges to distinct nodes, starting with
+        // `e`, where the color of each edge is free at the previous node.
+        let mut fan = vec![(self.ends[e].1, e)];
+        let mut in_fan: HashSet<_> = Some(self.ends[e].1).into_iter().collect();
+        loop {
+            let last = fan[fan.len() - 1].0;
+            let next = self.incident[x].iter().cloned().find(|&f| {
+                self.colors[f] != std::usize::MAX
+                    && self.is_free(last, self.colors[f])
+                    && !in_fan.contains(&self.other(f, x))
+            });
+            match next {
+                Some(f) => {
+                    let node = self.other(f, x);
+                    fan.push((node, f));
+                    in_fan.insert(node);
+                }
+                None => break,
+            }
+        }
+
+        // Free a color `d` at `x` that is also free at the end of the fan.
+        let c = self.free(x);
+        let d = self.free(fan[fan.len() - 1].0);
+        if !self.is_free(x, d) {
+            self.swap_path(x, d, c);
+        }
+
+        // The first node of the fan where `d` is free, such that the fan up
+        // to it is still one.
+        let mut end = 0;
+        for i in 0..fan.len() {
+            if i > 0 && !self.is_free(fan[i - 1].0, self.colors[fan[i].1]) {
+                break;
+            }
+            if self.is_free(fan[i].0, d) {
+                end = i;
+                break;
+            }
+        }
+
+        // Rotate the colors of the fan, and color its last edge with `d`.
+        let shifted: Vec<_> = fan[1..=end].iter().map(|&(_, f)| self.colors[f]).collect();
+        for &(_, f) in &fan[1..=end] {
+            self.unset(f);
+        }
+        for (&(_, f), &color) in fan.iter().zip(&shifted) {
+            self.set(f, color);
+        }
+        self.set(fan[end].1, d);
+    }
+}
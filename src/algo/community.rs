@@ -0,0 +1,535 @@
+//! Community detection.
+
+use std::collections::HashMap;
+
+use crate::algo::{Measure, UnitMeasure};
+use crate::graph::{EdgeIndex, Graph, NodeIndex};
+use crate::visit::{EdgeRef, GraphProp, IntoEdges, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Communities of the graph that maximize its modularity, with
+/// the [Louvain method][louvain].
+///
+/// Each node starts in its own community, and is moved to the community of
+/// a neighbor as long as it increases the [`modularity`][modularity]. The
+/// communities are then merged into single nodes and the search starts
+/// again on this smaller graph, until no move improves the modularity.
+/// As in the [Leiden algorithm][leiden], each community is split into its
+/// connected parts before being merged, so the communities found are always
+/// connected.
+///
+/// The weights given by `edge_weight` must be nonnegative, and the
+/// directions of the edges are ignored. A `resolution` higher than one
+/// favors smaller communities, and a lower one bigger communities. The
+/// nodes are visited in the order of `graph.node_identifiers()`, so the
+/// result is deterministic.
+///
+/// Returns a `Vec` mapping each node index to its community, numbered from
+/// zero in the order of `graph.node_identifiers()`. Indices that are not
+/// nodes of the graph have the community `usize::MAX`.
+///
+/// # Complexity
+/// * Time complexity: **O(|E|)** per pass over the nodes.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// [louvain]: https://doi.org/10.1088/1742-5468/2008/10/P10008
+/// [leiden]: https://doi.org/10.1038/s41598-019-41695-z
+/// [modularity]: fn.modularity.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::louvain;
+/// use petgraph::prelude::*;
+///
+/// // Two triangles joined by the edge 2 - 3.
+/// let g = UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.), (1, 2, 1.), (2, 0, 1.),
+///     (3, 4, 1.), (4, 5, 1.), (5, 3, 1.),
+///     (2, 3, 1.),
+/// ]);
+///
+/// let communities = louvain(&g, 1., |e| *e.weight());
+/// assert_eq!(communities, vec![0, 0, 0, 1, 1, 1]);
+/// ```
+pub fn louvain<G, F, D>(graph: G, resolution: D, edge_weight: F) -> Vec<usize>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let (positions, mut level) = Level::new(graph, edge_weight);
+    let two_m = level.degrees().into_iter().sum::<D>();
+    // The node of the current level that contains each node of the graph.
+    let mut membership: Vec<usize> = (0..level.len()).collect();
+    if two_m > D::zero() {
+        loop {
+            let communities = level.move_nodes(resolution, two_m);
+            let (communities, count) = level.split(&communities);
+            if count == level.len() {
+                break;
+            }
+            for node in &mut membership {
+                *node = communities[*node];
+            }
+            level = level.aggregate(&communities, count);
+        }
+    }
+    number_by_position(&positions, &membership)
+}
+
+/// \[Generic\] Communities of the graph found by asynchronous label
+/// propagation.
+///
+/// Each node starts with its own label, then the nodes are visited in the
+/// order of `graph.node_identifiers()`, and each one takes the label with
+/// the highest total weight among its neighbors. A node keeps its label
+/// unless another one is strictly heavier, and other ties are broken towards
+/// the label that started at the latest node, so the search is deterministic
+/// and stops once every node agrees with its neighborhood. The nodes with the same label form a
+/// community.
+///
+/// The weights given by `edge_weight` must be nonnegative, the directions
+/// of the edges are ignored and self loops have no effect.
+///
+/// Returns a `Vec` mapping each node index to its community, numbered from
+/// zero in the order of `graph.node_identifiers()`. Indices that are not
+/// nodes of the graph have the community `usize::MAX`.
+///
+/// # Complexity
+/// * Time complexity: **O(|E|)** per pass over the nodes.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::label_propagation;
+/// use petgraph::prelude::*;
+///
+/// // Two squares with their diagonals, joined by the edge 3 - 4.
+/// let g = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (1, 3),
+///     (4, 5), (5, 6), (6, 7), (7, 4), (4, 6), (5, 7),
+///     (3, 4),
+/// ]);
+///
+/// let communities = label_propagation(&g, |_| 1.);
+/// assert_eq!(communities, vec![0, 0, 0, 0, 1, 1, 1, 1]);
+/// ```
+pub fn label_propagation<G, F, D>(graph: G, edge_weight: F) -> Vec<usize>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> D,
+    D: Measure + Copy,
+{
+    let (positions, level) = Level::new(graph, edge_weight);
+    let mut labels: Vec<usize> = (0..level.len()).collect();
+    let mut label_weights = vec![D::default(); level.len()];
+    let mut touched = vec![false; level.len()];
+    let mut neighbor_labels = Vec::new();
+    // Every change strictly increases the total weight of the edges whose
+    // ends have the same label, so the search stops.
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for node in 0..level.len() {
+            for &(neighbor, weight) in &level.neighbors[node] {
+                let label = labels[neighbor];
+                if !touched[label] {
+                    touched[label] = true;
+                    neighbor_labels.push(label);
+                }
+                label_weights[label] = label_weights[label] + weight;
+            }
+            let current = labels[node];
+            let mut best = current;
+            let mut best_weight = if touched[current] {
+                label_weights[current]
+            } else {
+                D::default()
+            };
+            for &label in &neighbor_labels {
+                let weight = label_weights[label];
+                if weight > best_weight
+                    || (weight == best_weight && best != current && label > best)
+                {
+                    best = label;
+                    best_weight = weight;
+                }
+            }
+            for label in neighbor_labels.drain(..) {
+                touched[label] = false;
+                label_weights[label] = D::default();
+            }
+            if best != current {
+                labels[node] = best;
+                changed = true;
+            }
+        }
+    }
+    number_by_position(&positions, &labels)
+}
+
+/// \[Generic\] Modularity of a partition of the graph into communities.
+///
+/// The modularity compares the weight of the edges inside the communities
+/// with the weight expected if the edges were placed at random, keeping the
+/// weighted degrees of the nodes:
+///
+/// **Q = Σ<sub>c</sub> (L<sub>c</sub> / m - γ·(d<sub>c</sub> / 2m)²)**
+///
+/// where **m** is the total weight of the edges, **L<sub>c</sub>** the
+/// weight of the edges inside the community **c**, **d<sub>c</sub>** the sum
+/// of the weighted degrees of its nodes and **γ** the `resolution`. Self
+/// loops count twice in the degrees, and the directions of the edges are
+/// ignored.
+///
+/// `communities` maps each node index to its community, as returned by
+/// [`louvain`][louvain], and must have a length of at least
+/// `graph.node_bound()`. A graph without weight has a modularity of zero.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// [louvain]: fn.louvain.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::modularity;
+/// use petgraph::prelude::*;
+///
+/// // Two triangles joined by the edge 2 - 3.
+/// let g = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0),
+///     (3, 4), (4, 5), (5, 3),
+///     (2, 3),
+/// ]);
+///
+/// let q: f64 = modularity(&g, &[0, 0, 0, 1, 1, 1], 1., |_| 1.);
+/// assert!((q - 5. / 14.).abs() < 1e-12);
+///
+/// let q: f64 = modularity(&g, &[0; 6], 1., |_| 1.);
+/// assert_eq!(q, 0.);
+/// ```
+pub fn modularity<G, F, D>(graph: G, communities: &[usize], resolution: D, mut edge_weight: F) -> D
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> D,
+    D: UnitMeasure + Copy,
+{
+    let mut count = 0;
+    for node in graph.node_identifiers() {
+        count = count.max(communities[graph.to_index(node)] + 1);
+    }
+    let mut internal = vec![D::zero(); count];
+    let mut degrees = vec![D::zero(); count];
+    let mut m = D::zero();
+    for edge in graph.edge_references() {
+        let a = communities[graph.to_index(edge.source())];
+        let b = communities[graph.to_index(edge.target())];
+        let weight = edge_weight(edge);
+        m = m + weight;
+        degrees[a] = degrees[a] + weight;
+        degrees[b] = degrees[b] + weight;
+        if a == b {
+            internal[a] = internal[a] + weight;
+        }
+    }
+    if m <= D::zero() {
+        return D::zero();
+    }
+    let two_m = m + m;
+    internal
+        .into_iter()
+        .zip(degrees)
+        .map(|(internal, degree)| internal / m - resolution * (degree / two_m) * (degree / two_m))
+        .sum()
+}
+
+/// \[Generic\] Quotient graph of a partition of the graph into communities.
+///
+/// Like [`condensation`][condensation] does for strongly connected
+/// components, each community becomes a single node, with the `Vec` of its
+/// nodes in the order of `graph.node_identifiers()` as weight. The edges
+/// between two communities are merged into one edge, weighted by the sum of
+/// their `edge_weight`, and the edges inside a community into a self loop.
+/// The quotient graph keeps the direction of the edges.
+///
+/// `communities` maps each node index to its community, as returned by
+/// [`louvain`][louvain], and must have a length of at least
+/// `graph.node_bound()`. The node indices of the quotient graph are the
+/// communities.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// [condensation]: fn.condensation.html
+/// [louvain]: fn.louvain.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::quotient_graph;
+/// use petgraph::prelude::*;
+///
+/// // Two triangles joined by the edge 2 - 3.
+/// let g = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (2, 0, 1),
+///     (3, 4, 2), (4, 5, 2), (5, 3, 2),
+///     (2, 3, 5),
+/// ]);
+///
+/// let q = quotient_graph(&g, &[0, 0, 0, 1, 1, 1], |e| *e.weight());
+/// assert_eq!(q.node_count(), 2);
+/// assert_eq!(q[NodeIndex::new(1)], vec![NodeIndex::new(3), NodeIndex::new(4), NodeIndex::new(5)]);
+///
+/// let between = q.find_edge(NodeIndex::new(0), NodeIndex::new(1)).unwrap();
+/// assert_eq!(q[between], 5);
+/// let inside = q.find_edge(NodeIndex::new(1), NodeIndex::new(1)).unwrap();
+/// assert_eq!(q[inside], 6);
+/// ```
+pub fn quotient_graph<G, F, D>(
+    graph: G,
+    communities: &[usize],
+    mut edge_weight: F,
+) -> Graph<Vec<G::NodeId>, D, G::EdgeType>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> D,
+    D: std::ops::Add<D, Output = D> + Copy,
+{
+    let mut members: Vec<Vec<G::NodeId>> = Vec::new();
+    for node in graph.node_identifiers() {
+        let community = communities[graph.to_index(node)];
+        if community >= members.len() {
+            members.resize(community + 1, Vec::new());
+        }
+        members[community].push(node);
+    }
+    let mut quotient = Graph::with_capacity(members.len(), 0);
+    for nodes in members {
+        quotient.add_node(nodes);
+    }
+    let mut edges: HashMap<(usize, usize), EdgeIndex> = HashMap::new();
+    for edge in graph.edge_references() {
+        let mut a = communities[graph.to_index(edge.source())];
+        let mut b = communities[graph.to_index(edge.target())];
+        if !graph.is_directed() && a > b {
+            std::mem::swap(&mut a, &mut b);
+        }
+        let weight = edge_weight(edge);
+        match edges.get(&(a, b)) {
+            Some(&e) => quotient[e] = quotient[e] + weight,
+            None => {
+                let e = quotient.add_edge(NodeIndex::new(a), NodeIndex::new(b), weight);
+                edges.insert((a, b), e);
+            }
+        }
+    }
+    quotient
+}
+
+/// Numbers the communities of the nodes, given by their position, in the
+/// order of the positions.
+fn number_by_position(positions: &[usize], communities: &[usize]) -> Vec<usize> {
+    let mut numbers = vec![std::usize::MAX; communities.len()];
+    let mut count = 0;
+    positions
+        .iter()
+        .map(|&position| {
+            if position == std::usize::MAX {
+                return std::usize::MAX;
+            }
+            let community = communities[position];
+            if numbers[community] == std::usize::MAX {
+                numbers[community] = count;
+                count += 1;
+            }
+            numbers[community]
+        })
+        .collect()
+}
+
+/// An undirected weighted graph on the positions `0..len`, whose nodes may
+/// stand for groups of nodes of the original graph.
+struct Level<D> {
+    /// The neighbors of each node with the total weight of the edges to
+    /// them, without self loops.
+    neighbors: Vec<Vec<(usize, D)>>,
+    /// The total weight of the edges inside each node.
+    loops: Vec<D>,
+}
+
+impl<D> Level<D>
+where
+    D: Measure + Copy,
+{
+    /// Builds the first level of `graph`, and returns with it the position
+    /// of each node index, `usize::MAX` for the indices that are not nodes.
+    fn new<G, F>(graph: G, mut edge_weight: F) -> (Vec<usize>, Self)
+    where
+        G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> D,
+    {
+        let mut positions = vec![std::usize::MAX; graph.node_bound()];
+        let mut len = 0;
+        for node in graph.node_identifiers() {
+            positions[graph.to_index(node)] = len;
+            len += 1;
+        }
+        let mut level = Level {
+            neighbors: vec![Vec::new(); len],
+            loops: vec![D::default(); len],
+        };
+        for edge in graph.edge_references() {
+            let a = positions[graph.to_index(edge.source())];
+            let b = positions[graph.to_index(edge.target())];
+            let weight = edge_weight(edge);
+            if a == b {
+                level.loops[a] = level.loops[a] + weight;
+            } else {
+                level.neighbors[a].push((b, weight));
+                level.neighbors[b].push((a, weight));
+            }
+        }
+        level.merge_parallel_edges();
+        (positions, level)
+    }
+
+    fn len(&self) -> usize {
+        self.loops.len()
+    }
+
+    /// The weighted degree of each node, where self loops count twice.
+    fn degrees(&self) -> Vec<D> {
+        self.neighbors
+            .iter()
+            .zip(&self.loops)
+            .map(|(neighbors, &loops)| {
+                neighbors
+                    .iter()
+                    .fold(loops + loops, |degree, &(_, weight)| degree + weight)
+            })
+            .collect()
+    }
+
+    fn merge_parallel_edges(&mut self) {
+        for neighbors in &mut self.neighbors {
+            neighbors.sort_by_key(|&(neighbor, _)| neighbor);
+            neighbors.dedup_by(|&mut (b, weight), &mut (a, ref mut total)| {
+                if a == b {
+                    *total = *total + weight;
+                }
+                a == b
+            });
+        }
+    }
+
+    /// Splits the communities into their connected parts, and returns the
+    /// part of each node, numbered from zero, with the number of parts.
+    fn split(&self, communities: &[usize]) -> (Vec<usize>, usize) {
+        let mut parts = vec![std::usize::MAX; self.len()];
+        let mut count = 0;
+        let mut stack = Vec::new();
+        for start in 0..self.len() {
+            if parts[start] != std::usize::MAX {
+                continue;
+            }
+            parts[start] = count;
+            stack.push(start);
+            while let Some(node) = stack.pop() {
+                for &(neighbor, _) in &self.neighbors[node] {
+                    if parts[neighbor] == std::usize::MAX
+                        && communities[neighbor] == communities[start]
+                    {
+                        parts[neighbor] = count;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+            count += 1;
+        }
+        (parts, count)
+    }
+
+    /// Merges the nodes of each community into a single node.
+    fn aggregate(&self, communities: &[usize], count: usize) -> Self {
+        let mut level = Level {
+            neighbors: vec![Vec::new(); count],
+            loops: vec![D::default(); count],
+        };
+        for node in 0..self.len() {
+            let a = communities[node];
+            level.loops[a] = level.loops[a] + self.loops[node];
+            for &(neighbor, weight) in &self.neighbors[node] {
+                let b = communities[neighbor];
+                if a != b {
+                    level.neighbors[a].push((b, weight));
+                } else if node < neighbor {
+                    level.loops[a] = level.loops[a] + weight;
+                }
+            }
+        }
+        level.merge_parallel_edges();
+        level
+    }
+}
+
+impl<D> Level<D>
+where
+    D: UnitMeasure + Copy,
+{
+    /// Moves each node to the neighboring community that increases the
+    /// modularity the most, until no move increases it, and returns the
+    /// community of each node.
+    fn move_nodes(&self, resolution: D, two_m: D) -> Vec<usize> {
+        let degrees = self.degrees();
+        let mut communities: Vec<usize> = (0..self.len()).collect();
+        let mut totals = degrees.clone();
+        let mut community_weights = vec![D::zero(); self.len()];
+        let mut touched = vec![false; self.len()];
+        let mut neighbor_communities = Vec::new();
+        let mut moved = true;
+        while moved {
+            moved = false;
+            for node in 0..self.len() {
+                for &(neighbor, weight) in &self.neighbors[node] {
+                    let community = communities[neighbor];
+                    if !touched[community] {
+                        touched[community] = true;
+                        neighbor_communities.push(community);
+                    }
+                    community_weights[community] = community_weights[community] + weight;
+                }
+                let current = communities[node];
+                let degree = degrees[node];
+                totals[current] = totals[current] - degree;
+                // The change of modularity when the node joins a community,
+                // up to a positive factor.
+                let gain = |community: usize| {
+                    let weight = if touched[community] {
+                        community_weights[community]
+                    } else {
+                        D::zero()
+                    };
+                    weight - resolution * totals[community] * degree / two_m
+                };
+                let mut best = current;
+                let mut best_gain = gain(current);
+                for &community in &neighbor_communities {
+                    let community_gain = gain(community);
+                    if community_gain > best_gain {
+                        best = community;
+                        best_gain = community_gain;
+                    }
+                }
+                totals[best] = totals[best] + degree;
+                communities[node] = best;
+                moved |= best != current;
+                for community in neighbor_communities.drain(..) {
+                    touched[community] = false;
+                    community_weights[community] = D::zero();
+                }
+            }
+        }
+        communities
+    }
+}
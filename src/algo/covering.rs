@@ -0,0 +1,497 @@
+//! Vertex cover, independent set and dominating set algorithms.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::hash::Hash;
+
+use crate::algo::adjacency::Adjacency;
+use crate::visit::{
+    EdgeRef, IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable,
+};
+
+/// \[Generic\] Compute a *vertex cover* of at most twice the minimum size.
+///
+/// A vertex cover is a set of nodes that contains at least one endpoint of
+/// every edge. The nodes with a self loop are always in the cover. The other
+/// nodes are covered with both endpoints of a maximal matching of the rest
+/// of the graph, which any cover needs one endpoint of each of.
+///
+/// The graph is treated as if undirected.
+///
+/// Computes in **O(|V| + |E|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{approx_vertex_cover, is_vertex_cover};
+/// use petgraph::prelude::*;
+///
+/// // A star with the center 0.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+///
+/// let cover = approx_vertex_cover(&g);
+/// assert!(is_vertex_cover(&g, &cover));
+/// assert!(cover.len() <= 2);
+/// ```
+pub fn approx_vertex_cover<G>(graph: G) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency = Adjacency::with_loops(graph);
+    adjacency.to_nodes(graph, &adjacency.approx_cover())
+}
+
+/// \[Generic\] Compute a *minimum vertex cover*.
+///
+/// A vertex cover is a set of nodes that contains at least one endpoint of
+/// every edge, so the nodes with a self loop are in every cover. Finding the
+/// smallest cover is NP-hard: this uses a bounded search tree, where a node
+/// of degree one puts its neighbor in the cover and a node of degree at
+/// least three branches into a cover with the node or with all of its
+/// neighbors. With **k** the size of the cover, the search tree has
+/// **O(1.47^k)** nodes, so this is practical for graphs with small covers,
+/// even if they have many nodes. A bipartite graph has a faster
+/// [`bipartite_minimum_vertex_cover`][bmvc].
+///
+/// The graph is treated as if undirected.
+///
+/// [bmvc]: fn.bipartite_minimum_vertex_cover.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{is_vertex_cover, minimum_vertex_cover};
+/// use petgraph::prelude::*;
+///
+/// // A wheel: the cycle 0 - 1 - 2 - 3 - 4 - 0 around the node 5.
+/// let g = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+///     (0, 5), (1, 5), (2, 5), (3, 5), (4, 5),
+/// ]);
+///
+/// let cover = minimum_vertex_cover(&g);
+/// assert!(is_vertex_cover(&g, &cover));
+/// assert_eq!(cover.len(), 4);
+/// ```
+pub fn minimum_vertex_cover<G>(graph: G) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency = Adjacency::with_loops(graph);
+    adjacency.to_nodes(graph, &adjacency.minimum_cover())
+}
+
+/// \[Generic\] Compute a *maximal independent set* greedily.
+///
+/// An independent set is a set of nodes where no two are neighbors. The
+/// node with the fewest neighbors that are not yet in the set or next to it
+/// is added each time, until no more nodes can be added. A node with a self
+/// loop is its own neighbor, and is never in an independent set.
+///
+/// The graph is treated as if undirected.
+///
+/// Computes in **O((|V| + |E|)·log|V|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{greedy_independent_set, is_independent_set};
+/// use petgraph::prelude::*;
+///
+/// // A star with the center 0.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+///
+/// let set = greedy_independent_set(&g);
+/// assert!(is_independent_set(&g, &set));
+/// assert_eq!(set.len(), 4);
+/// ```
+pub fn greedy_independent_set<G>(graph: G) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency = Adjacency::with_loops(graph);
+    adjacency.to_nodes(graph, &adjacency.greedy_independent_set())
+}
+
+/// \[Generic\] Compute a *maximum independent set*.
+///
+/// An independent set is a set of nodes where no two are neighbors, and a
+/// node with a self loop is its own neighbor. The nodes that are not in a
+/// minimum vertex cover form a maximum independent set, so this is the
+/// complement of [`minimum_vertex_cover`][mvc] and takes as long.
+///
+/// The graph is treated as if undirected.
+///
+/// [mvc]: fn.minimum_vertex_cover.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{is_independent_set, maximum_independent_set};
+/// use petgraph::prelude::*;
+///
+/// // A wheel: the cycle 0 - 1 - 2 - 3 - 4 - 0 around the node 5.
+/// let g = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+///     (0, 5), (1, 5), (2, 5), (3, 5), (4, 5),
+/// ]);
+///
+/// let set = maximum_independent_set(&g);
+/// assert!(is_independent_set(&g, &set));
+/// assert_eq!(set.len(), 2);
+/// ```
+pub fn maximum_independent_set<G>(graph: G) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency = Adjacency::with_loops(graph);
+    let mut in_cover = vec![false; adjacency.neighbors.len()];
+    for n in adjacency.minimum_cover() {
+        in_cover[n] = true;
+    }
+    let set: Vec<_> = adjacency
+        .nodes
+        .iter()
+        .cloned()
+        .filter(|&n| !in_cover[n])
+        .collect();
+    adjacency.to_nodes(graph, &set)
+}
+
+/// \[Generic\] Compute a *dominating set* greedily.
+///
+/// A dominating set is a set of nodes where every node of the graph is
+/// either in the set or a neighbor of a node in it. The node that dominates
+/// the most nodes that are not yet dominated is added each time, which
+/// gives a set at most **1 + ln(Δ + 1)** times the minimum size, where
+/// **Δ** is the largest degree.
+///
+/// The graph is treated as if undirected, and self loops are ignored.
+///
+/// Computes in **O((|V| + |E|)·log|V|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{greedy_dominating_set, is_dominating_set};
+/// use petgraph::prelude::*;
+///
+/// // Two stars with the centers 0 and 4, linked by the path 0 - 3 - 4.
+/// let g = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3),
+///     (3, 4), (4, 5), (4, 6),
+/// ]);
+///
+/// let set = greedy_dominating_set(&g);
+/// assert!(is_dominating_set(&g, &set));
+/// assert_eq!(set, vec![0.into(), 4.into()].into_iter().collect());
+/// ```
+pub fn greedy_dominating_set<G>(graph: G) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency = Adjacency::with_loops(graph);
+    adjacency.to_nodes(graph, &adjacency.greedy_dominating_set())
+}
+
+/// \[Generic\] Check if `set` is a *vertex cover* of the graph: if every
+/// edge has at least one endpoint in `set`.
+///
+/// Returns `false` if `set` has nodes that are not in the graph.
+pub fn is_vertex_cover<G>(graph: G, set: &HashSet<G::NodeId>) -> bool
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    in_graph(graph, set)
+        && graph
+            .edge_references()
+            .all(|edge| set.contains(&edge.source()) || set.contains(&edge.target()))
+}
+
+/// \[Generic\] Check if `set` is an *independent set* of the graph: if no
+/// edge has both endpoints in `set`. A node with a self loop can not be in
+/// an independent set.
+///
+/// Returns `false` if `set` has nodes that are not in the graph.
+pub fn is_independent_set<G>(graph: G, set: &HashSet<G::NodeId>) -> bool
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    in_graph(graph, set)
+        && graph
+            .edge_references()
+            .all(|edge| !set.contains(&edge.source()) || !set.contains(&edge.target()))
+}
+
+/// \[Generic\] Check if `set` is a *dominating set* of the graph: if every
+/// node is in `set` or linked by an edge, in either direction, to a node in
+/// `set`.
+///
+/// Returns `false` if `set` has nodes that are not in the graph.
+pub fn is_dominating_set<G>(graph: G, set: &HashSet<G::NodeId>) -> bool
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    if !in_graph(graph, set) {
+        return false;
+    }
+    let mut dominated = set.clone();
+    for edge in graph.edge_references() {
+        if set.contains(&edge.source()) {
+            dominated.insert(edge.target());
+        }
+        if set.contains(&edge.target()) {
+            dominated.insert(edge.source());
+        }
+    }
+    graph.node_identifiers().all(|n| dominated.contains(&n))
+}
+
+/// Check that all the nodes of `set` are in the graph.
+fn in_graph<G>(graph: G, set: &HashSet<G::NodeId>) -> bool
+where
+    G: IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    graph.node_identifiers().filter(|n| set.contains(n)).count() == set.len()
+}
+
+impl Adjacency {
+    fn to_nodes<G>(&self, graph: G, set: &[usize]) -> HashSet<G::NodeId>
+    where
+        G: NodeIndexable,
+        G::NodeId: Eq + Hash,
+    {
+        set.iter().map(|&n| graph.from_index(n)).collect()
+    }
+
+    /// The nodes with a self loop, and both ends of a maximal matching of
+    /// the other nodes.
+    fn approx_cover(&self) -> Vec<usize> {
+        let mut in_cover = self.loops.clone();
+        let mut cover: Vec<_> = self
+            .nodes
+            .iter()
+            .cloned()
+            .filter(|&n| in_cover[n])
+            .collect();
+        for &a in &self.nodes {
+            if in_cover[a] {
+                continue;
+            }
+            if let Some(&b) = self.neighbors[a].iter().find(|&&b| !in_cover[b]) {
+                in_cover[a] = true;
+                in_cover[b] = true;
+                cover.push(a);
+                cover.push(b);
+            }
+        }
+        cover
+    }
+
+    fn minimum_cover(&self) -> Vec<usize> {
+        let mut search = VertexCover {
+            neighbors: &self.neighbors,
+            nodes: &self.nodes,
+            in_cover: vec![false; self.neighbors.len()],
+            degrees: self.neighbors.iter().map(|list| list.len()).collect(),
+            edge_count: self.neighbors.iter().map(|list| list.len()).sum::<usize>() / 2,
+            cover: vec![],
+            best: self.approx_cover(),
+        };
+        // The nodes with a self loop are in every cover.
+        for &n in &self.nodes {
+            if self.loops[n] {
+                search.take(n);
+            }
+        }
+        search.search();
+        search.best
+    }
+
+    fn greedy_independent_set(&self) -> Vec<usize> {
+        // Whether each node is in the set or next to a node in it.
+        let mut removed = self.loops.clone();
+        let mut degrees: Vec<_> = self.neighbors.iter().map(|list| list.len()).collect();
+        for &n in &self.nodes {
+            if removed[n] {
+                for &m in &self.neighbors[n] {
+                    degrees[m] -= 1;
+                }
+            }
+        }
+        let mut heap: BinaryHeap<_> = self
+            .nodes
+            .iter()
+            .filter(|&&n| !removed[n])
+            .map(|&n| Reverse((degrees[n], n)))
+            .collect();
+        let mut set = vec![];
+        while let Some(Reverse((degree, n))) = heap.pop() {
+            if removed[n] || degree != degrees[n] {
+                continue;
+            }
+            set.push(n);
+            removed[n] = true;
+            for &m in &self.neighbors[n] {
+                if removed[m] {
+                    continue;
+                }
+                removed[m] = true;
+                for &k in &self.neighbors[m] {
+                    if !removed[k] {
+                        degrees[k] -= 1;
+                        heap.push(Reverse((degrees[k], k)));
+                    }
+                }
+            }
+        }
+        set
+    }
+
+    fn greedy_dominating_set(&self) -> Vec<usize> {
+        let mut dominated = vec![false; self.neighbors.len()];
+        // The number of nodes that each node would dominate: itself and its
+        // neighbors, less the ones that are already dominated.
+        let mut gains: Vec<_> = self.neighbors.iter().map(|list| list.len() + 1).collect();
+        let mut heap: BinaryHeap<_> = self.nodes.iter().map(|&n| (gains[n], Reverse(n))).collect();
+        let mut set = vec![];
+        while let Some((gain, Reverse(n))) = heap.pop() {
+            if gain != gains[n] {
+                continue;
+            }
+            set.push(n);
+            for m in Some(n).into_iter().chain(self.neighbors[n].iter().cloned()) {
+                if dominated[m] {
+                    continue;
+                }
+                dominated[m] = true;
+                for k in Some(m).into_iter().chain(self.neighbors[m].iter().cloned()) {
+                    gains[k] -= 1;
+                    if gains[k] > 0 {
+                        heap.push((gains[k], Reverse(k)));
+                    }
+                }
+            }
+        }
+        set
+    }
+}
+
+/// The state of the search for a minimum vertex cover: the nodes in
+/// `cover` are taken out of the graph, and `degrees` and `edge_count` are
+/// for the rest of it.
+struct VertexCover<'a> {
+    neighbors: &'a [Vec<usize>],
+    nodes: &'a [usize],
+    in_cover: Vec<bool>,
+    degrees: Vec<usize>,
+    edge_count: usize,
+    cover: Vec<usize>,
+    /// The smallest cover found so far.
+    best: Vec<usize>,
+}
+
+impl VertexCover<'_> {
+    fn take(&mut self, n: usize) {
+        self.in_cover[n] = true;
+        self.cover.push(n);
+        self.edge_count -= self.degrees[n];
+        for &m in &self.neighbors[n] {
+            if !self.in_cover[m] {
+                self.degrees[m] -= 1;
+            }
+        }
+    }
+
+    fn untake(&mut self) {
+        let n = self.cover.pop().unwrap();
+        self.in_cover[n] = false;
+        self.edge_count += self.degrees[n];
+        for &m in &self.neighbors[n] {
+            if !self.in_cover[m] {
+                self.degrees[m] += 1;
+            }
+        }
+    }
+
+    /// Update `best` if there is a smaller cover that contains `cover`.
+    fn search(&mut self) {
+        let start = self.cover.len();
+        loop {
+            if self.edge_count == 0 {
+                if self.cover.len() < self.best.len() {
+                    self.best = self.cover.clone();
+                }
+                break;
+            }
+            if self.cover.len() + 1 >= self.best.len() {
+                break;
+            }
+            let mut leaf = None;
+            let mut max = self.nodes[0];
+            for &n in self.nodes {
+                if self.in_cover[n] {
+                    continue;
+                }
+                if self.degrees[n] == 1 {
+                    leaf = Some(n);
+                    break;
+                }
+                if self.degrees[n] > self.degrees[max] || self.in_cover[max] {
+                    max = n;
+                }
+            }
+
+            // A node of degree one is covered by its neighbor at no loss.
+            if let Some(n) = leaf {
+                let m = self.neighbors[n]
+                    .iter()
+                    .cloned()
+                    .find(|&m| !self.in_cover[m])
+                    .unwrap();
+                self.take(m);
+                continue;
+            }
+            // The rest of the graph is cycles, where any node can be taken.
+            let degree = self.degrees[max];
+            if degree <= 2 {
+                self.take(max);
+                continue;
+            }
+            // Each node covers at most `degree` more edges.
+            let budget = self.best.len() - 1 - self.cover.len();
+            if self.edge_count > budget * degree {
+                break;
+            }
+            // Without `max`, the cover needs all of its neighbors.
+            if degree > budget {
+                self.take(max);
+                continue;
+            }
+
+            self.take(max);
+            self.search();
+            self.untake();
+
+            let before = self.cover.len();
+            for i in 0..self.neighbors[max].len() {
+                let m = self.neighbors[max][i];
+                if !self.in_cover[m] {
+                    self.take(m);
+                }
+            }
+            self.search();
+            while self.cover.len() > before {
+                self.untake();
+            }
+            break;
+        }
+        while self.cover.len() > start {
+            self.untake();
+        }
+    }
+}
@@ -3,9 +3,11 @@ use std::collections::{BinaryHeap, HashMap};
 
 use std::hash::Hash;
 
+use crate::algo::bellman_ford::Paths;
 use crate::algo::Measure;
 use crate::scored::MinScored;
-use crate::visit::{EdgeRef, IntoEdges, VisitMap, Visitable};
+use crate::visit::{EdgeRef, IntoEdges, IntoEdgesDirected, NodeIndexable, VisitMap, Visitable};
+use crate::Direction::{Incoming, Outgoing};
 
 /// \[Generic\] Dijkstra's shortest path algorithm.
 ///
@@ -73,21 +75,71 @@ pub fn dijkstra<G, F, K>(
     graph: G,
     start: G::NodeId,
     goal: Option<G::NodeId>,
+    edge_cost: F,
+) -> HashMap<G::NodeId, K>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    multi_source_dijkstra(graph, Some((start, K::default())), goal, edge_cost)
+}
+
+/// \[Generic\] Dijkstra's shortest path algorithm from several start nodes.
+///
+/// Compute the length of the shortest path from the nearest of the `starts`
+/// to every reachable node. Each start node comes with the initial cost of a
+/// path beginning there; a node given several times keeps its least cost.
+///
+/// The graph should be `Visitable` and implement `IntoEdges`. The function
+/// `edge_cost` should return the cost for a particular edge, which is used
+/// to compute path costs. Edge costs must be non-negative.
+///
+/// If `goal` is not `None`, then the algorithm terminates once the `goal` node's
+/// cost is calculated.
+///
+/// Returns a `HashMap` that maps `NodeId` to path cost.
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::multi_source_dijkstra;
+/// use petgraph::prelude::*;
+/// use std::collections::HashMap;
+///
+/// let mut graph: Graph<(), u32> = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (b, c, 1), (d, c, 5)]);
+/// // a --1--> b --1--> c <--5-- d
+///
+/// // The path from d starts with a cost of 1.
+/// let res = multi_source_dijkstra(&graph, vec![(a, 3), (d, 1)], None, |e| *e.weight());
+/// let expected_res: HashMap<NodeIndex, u32> =
+///     [(a, 3), (b, 4), (c, 5), (d, 1)].iter().cloned().collect();
+/// assert_eq!(res, expected_res);
+/// ```
+pub fn multi_source_dijkstra<G, I, F, K>(
+    graph: G,
+    starts: I,
+    goal: Option<G::NodeId>,
     mut edge_cost: F,
 ) -> HashMap<G::NodeId, K>
 where
     G: IntoEdges + Visitable,
     G::NodeId: Eq + Hash,
+    I: IntoIterator<Item = (G::NodeId, K)>,
     F: FnMut(G::EdgeRef) -> K,
     K: Measure + Copy,
 {
     let mut visited = graph.visit_map();
     let mut scores = HashMap::new();
-    //let mut predecessor = HashMap::new();
     let mut visit_next = BinaryHeap::new();
-    let zero_score = K::default();
-    scores.insert(start, zero_score);
-    visit_next.push(MinScored(zero_score, start));
+    for (start, start_score) in starts {
+        push_if_less(&mut scores, &mut visit_next, start, start_score);
+    }
     while let Some(MinScored(node_score, node)) = visit_next.pop() {
         if visited.is_visited(&node) {
             continue;
@@ -101,22 +153,350 @@ where
                 continue;
             }
             let next_score = node_score + edge_cost(edge);
-            match scores.entry(next) {
-                Occupied(ent) => {
-                    if next_score < *ent.get() {
-                        *ent.into_mut() = next_score;
-                        visit_next.push(MinScored(next_score, next));
-                        //predecessor.insert(next.clone(), node.clone());
+            push_if_less(&mut scores, &mut visit_next, next, next_score);
+        }
+        visited.visit(node);
+    }
+    scores
+}
+
+/// \[Generic\] Dijkstra's shortest path algorithm from several start nodes to
+/// a single goal.
+///
+/// Like [`multi_source_dijkstra`], but the search stops as soon as `goal` is
+/// reached, and the path to it is returned.
+///
+/// Returns the total cost + the path of nodes from the nearest start node
+/// (first) to `goal` (last), or `None` if `goal` is not reachable.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::multi_source_dijkstra_path;
+///
+/// let mut graph: Graph<(), u32> = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (b, c, 1), (d, c, 5)]);
+/// // a --1--> b --1--> c <--5-- d
+///
+/// let res = multi_source_dijkstra_path(&graph, vec![(a, 0), (d, 0)], c, |e| *e.weight());
+/// assert_eq!(res, Some((2, vec![a, b, c])));
+///
+/// let res = multi_source_dijkstra_path(&graph, vec![(a, 4), (d, 0)], c, |e| *e.weight());
+/// assert_eq!(res, Some((5, vec![d, c])));
+///
+/// assert_eq!(multi_source_dijkstra_path(&graph, vec![(c, 0)], a, |e| *e.weight()), None);
+/// ```
+pub fn multi_source_dijkstra_path<G, I, F, K>(
+    graph: G,
+    starts: I,
+    goal: G::NodeId,
+    mut edge_cost: F,
+) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    I: IntoIterator<Item = (G::NodeId, K)>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut visited = graph.visit_map();
+    let mut scores = HashMap::new();
+    let mut predecessors = HashMap::new();
+    let mut visit_next = BinaryHeap::new();
+    for (start, start_score) in starts {
+        push_if_less(&mut scores, &mut visit_next, start, start_score);
+    }
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if visited.is_visited(&node) {
+            continue;
+        }
+        if node == goal {
+            return Some((node_score, follow_predecessors(&predecessors, goal)));
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next) {
+                continue;
+            }
+            let next_score = node_score + edge_cost(edge);
+            if push_if_less(&mut scores, &mut visit_next, next, next_score) {
+                predecessors.insert(next, node);
+            }
+        }
+        visited.visit(node);
+    }
+    None
+}
+
+/// \[Generic\] Bidirectional Dijkstra's shortest path algorithm.
+///
+/// Compute the shortest path from `start` to `goal`, by running Dijkstra's
+/// algorithm forwards from `start` and backwards from `goal` at the same
+/// time. The searches stop once the best path through a node reached by both
+/// of them can no longer be improved, which usually explores far fewer nodes
+/// than a single search from `start`.
+///
+/// The graph should be `Visitable` and implement `IntoEdgesDirected`; the
+/// backward search follows the incoming edges of each node. The function
+/// `edge_cost` should return the cost for a particular edge, which is used
+/// to compute path costs. Edge costs must be non-negative.
+///
+/// Returns the total cost, the node where the two searches met, and the path
+/// of nodes from `start` (first) to `goal` (last), or `None` if `goal` is not
+/// reachable from `start`. The meeting node is on the path.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::bidirectional_dijkstra;
+///
+/// let mut graph: Graph<(), u32> = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let e = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 2), (b, c, 2), (c, d, 2), (a, e, 1), (e, d, 7)]);
+/// // a --2--> b --2--> c --2--> d
+/// // |                          ^
+/// // +---1--> e -------7--------+
+///
+/// let (cost, meeting, path) = bidirectional_dijkstra(&graph, a, d, |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 6);
+/// assert_eq!(path, vec![a, b, c, d]);
+/// assert!(path.contains(&meeting));
+///
+/// assert_eq!(bidirectional_dijkstra(&graph, d, a, |e| *e.weight()), None);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn bidirectional_dijkstra<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    mut edge_cost: F,
+) -> Option<(K, G::NodeId, Vec<G::NodeId>)>
+where
+    G: IntoEdgesDirected + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let zero_score = K::default();
+    // Index 0 is the forward search from `start`, index 1 the backward search
+    // from `goal`.
+    let mut visited = [graph.visit_map(), graph.visit_map()];
+    let mut scores = [HashMap::new(), HashMap::new()];
+    let mut predecessors = [HashMap::new(), HashMap::new()];
+    let mut visit_next = [BinaryHeap::new(), BinaryHeap::new()];
+    for (side, &node) in [start, goal].iter().enumerate() {
+        scores[side].insert(node, zero_score);
+        visit_next[side].push(MinScored(zero_score, node));
+    }
+    // The best path found so far: its cost and meeting node.
+    let mut best = if start == goal {
+        Some((zero_score, start))
+    } else {
+        None
+    };
+
+    while let (Some(forward), Some(backward)) = (
+        visit_next[0].peek().map(|s| s.0),
+        visit_next[1].peek().map(|s| s.0),
+    ) {
+        // Any path through an unvisited node costs at least as much as the
+        // sum of the smallest scores of the two searches.
+        if let Some((best_score, _)) = best {
+            if forward + backward >= best_score {
+                break;
+            }
+        }
+        let side = if forward <= backward { 0 } else { 1 };
+        let MinScored(node_score, node) = visit_next[side].pop().unwrap();
+        if visited[side].is_visited(&node) {
+            continue;
+        }
+        let direction = if side == 0 { Outgoing } else { Incoming };
+        for edge in graph.edges_directed(node, direction) {
+            let next = if side == 0 {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            if visited[side].is_visited(&next) {
+                continue;
+            }
+            let next_score = node_score + edge_cost(edge);
+            if push_if_less(&mut scores[side], &mut visit_next[side], next, next_score) {
+                predecessors[side].insert(next, node);
+                if let Some(&other_score) = scores[1 - side].get(&next) {
+                    let score = next_score + other_score;
+                    if best.map_or(true, |(best_score, _)| score < best_score) {
+                        best = Some((score, next));
                     }
                 }
-                Vacant(ent) => {
-                    ent.insert(next_score);
-                    visit_next.push(MinScored(next_score, next));
-                    //predecessor.insert(next.clone(), node.clone());
-                }
+            }
+        }
+        visited[side].visit(node);
+    }
+
+    best.map(|(score, meeting)| {
+        let mut path = follow_predecessors(&predecessors[0], meeting);
+        let mut node = meeting;
+        while let Some(&next) = predecessors[1].get(&node) {
+            path.push(next);
+            node = next;
+        }
+        (score, meeting, path)
+    })
+}
+
+/// Record `score` for `node` and queue it, unless the node already has a
+/// score that is not greater. Return whether the score was recorded.
+fn push_if_less<N, K>(
+    scores: &mut HashMap<N, K>,
+    visit_next: &mut BinaryHeap<MinScored<K, N>>,
+    node: N,
+    score: K,
+) -> bool
+where
+    N: Copy + Eq + Hash,
+    K: Measure + Copy,
+{
+    match scores.entry(node) {
+        Occupied(ent) => {
+            if score < *ent.get() {
+                *ent.into_mut() = score;
+            } else {
+                return false;
+            }
+        }
+        Vacant(ent) => {
+            ent.insert(score);
+        }
+    }
+    visit_next.push(MinScored(score, node));
+    true
+}
+
+/// Return the path from the node without a predecessor to `node`.
+fn follow_predecessors<N>(predecessors: &HashMap<N, N>, mut node: N) -> Vec<N>
+where
+    N: Copy + Eq + Hash,
+{
+    let mut path = vec![node];
+    while let Some(&pred) = predecessors.get(&node) {
+        path.push(pred);
+        node = pred;
+    }
+    path.reverse();
+    path
+}
+
+/// \[Generic\] Dijkstra's shortest path algorithm, with predecessors.
+///
+/// Compute the length of the shortest path from `start` to every reachable
+/// node, and the predecessor of each node along its shortest path.
+///
+/// The graph should be `Visitable` and implement `IntoEdges`. The function
+/// `edge_cost` should return the cost for a particular edge, which is used
+/// to compute path costs. Edge costs must be non-negative.
+///
+/// If `goal` is not `None`, then the algorithm terminates once the `goal` node's
+/// cost is calculated. The costs of the nodes that were not reached before may
+/// then not be minimal.
+///
+/// Returns the same [`Paths`][paths] as [`bellman_ford`][bf]: one vec with
+/// path costs, and another one which points out the predecessor of a node
+/// along a shortest path. The vectors are indexed by the graph's node indices.
+/// Unreachable nodes have a cost of `None` and no predecessor. Use
+/// [`Paths::path_to`][path_to] and [`Paths::edges_to`][edges_to] to get the
+/// shortest path to a node.
+///
+/// [paths]: ../bellman_ford/struct.Paths.html
+/// [bf]: ../bellman_ford/fn.bellman_ford.html
+/// [path_to]: ../bellman_ford/struct.Paths.html#method.path_to
+/// [edges_to]: ../bellman_ford/struct.Paths.html#method.edges_to
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::dijkstra_paths;
+/// use petgraph::prelude::*;
+///
+/// let mut graph: Graph<(), u32> = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let z = graph.add_node(());
+/// let ab = graph.add_edge(a, b, 1);
+/// let bd = graph.add_edge(b, d, 5);
+/// graph.extend_with_edges(&[(a, c, 2), (c, d, 2), (b, d, 7)]);
+/// // a --1--> b ==5,7=> d
+/// // |                  ^
+/// // 2                  |
+/// // +------> c ---2----+
+///
+/// let paths = dijkstra_paths(&graph, a, None, |e| *e.weight());
+/// assert_eq!(paths.distances, vec![Some(0), Some(1), Some(2), Some(4), None]);
+/// assert_eq!(paths.predecessors, vec![None, Some(a), Some(a), Some(c), None]);
+///
+/// assert_eq!(paths.path_to(&graph, a, d), Some(vec![a, c, d]));
+/// assert_eq!(paths.path_to(&graph, a, z), None);
+///
+/// // Among the parallel edges b -> d, the cheapest one is taken.
+/// graph.add_edge(c, d, 5);
+/// let paths = dijkstra_paths(&graph, b, None, |e| *e.weight());
+/// let edges = paths.edges_to(&graph, b, d, |e| *e.weight()).unwrap();
+/// assert_eq!(edges.iter().map(|e| e.id()).collect::<Vec<_>>(), vec![bd]);
+/// # let _ = ab;
+/// ```
+pub fn dijkstra_paths<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: Option<G::NodeId>,
+    mut edge_cost: F,
+) -> Paths<G::NodeId, Option<K>>
+where
+    G: IntoEdges + Visitable + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let ix = |i| graph.to_index(i);
+    let mut visited = graph.visit_map();
+    let mut distances = vec![None; graph.node_bound()];
+    let mut predecessors = vec![None; graph.node_bound()];
+    let mut visit_next = BinaryHeap::new();
+    let zero_score = K::default();
+    distances[ix(start)] = Some(zero_score);
+    visit_next.push(MinScored(zero_score, start));
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if visited.is_visited(&node) {
+            continue;
+        }
+        if goal.as_ref() == Some(&node) {
+            break;
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next) {
+                continue;
+            }
+            let next_score = node_score + edge_cost(edge);
+            if distances[ix(next)].map_or(true, |d| next_score < d) {
+                distances[ix(next)] = Some(next_score);
+                predecessors[ix(next)] = Some(node);
+                visit_next.push(MinScored(next_score, next));
             }
         }
         visited.visit(node);
     }
-    scores
+    Paths {
+        distances,
+        predecessors,
+    }
 }
@@ -0,0 +1,172 @@
+//! Dinic's maximum flow algorithm.
+
+use std::{collections::VecDeque, ops::Sub};
+
+use crate::{
+    data::DataMap,
+    visit::{EdgeCount, EdgeIndexable, IntoEdgesDirected, NodeCount, NodeIndexable, Visitable},
+};
+
+use super::ford_fulkerson::ResidualNetwork;
+use super::{EdgeRef, PositiveMeasure};
+
+/// \[Generic\] Dinic's algorithm.
+///
+/// Computes the [maximum flow][dinic] of a weighted directed graph, where
+/// edge weights are capacities, from `source` to `destination`.
+///
+/// Each phase finds a blocking flow in the level graph of the residual
+/// network, so the flow is augmented along all shortest paths at once. This
+/// takes **O(|V|²·|E|)** time, which is much faster than
+/// [`ford_fulkerson`][ff] on most networks, and **O(|E|·√|V|)** on unit
+/// capacity networks.
+///
+/// Returns the maximum flow and the computed edge flows, indexed by edge
+/// index, like [`ford_fulkerson`][ff]. Use [`min_cut`][min_cut] to get a
+/// minimum cut from them.
+///
+/// [dinic]: https://en.wikipedia.org/wiki/Dinic%27s_algorithm
+/// [ff]: ../ford_fulkerson/fn.ford_fulkerson.html
+/// [min_cut]: ../ford_fulkerson/fn.min_cut.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::dinic;
+/// // Example from CLRS book
+/// let mut graph = Graph::<u8, u8>::new();
+/// let source = graph.add_node(0);
+/// let _ = graph.add_node(1);
+/// let _ = graph.add_node(2);
+/// let _ = graph.add_node(3);
+/// let _ = graph.add_node(4);
+/// let destination = graph.add_node(5);
+/// graph.extend_with_edges(&[
+///    (0, 1, 16),
+///    (0, 2, 13),
+///    (1, 2, 10),
+///    (1, 3, 12),
+///    (2, 1, 4),
+///    (2, 4, 14),
+///    (3, 2, 9),
+///    (3, 5, 20),
+///    (4, 3, 7),
+///    (4, 5, 4),
+/// ]);
+/// let (max_flow, flows) = dinic(&graph, source, destination);
+/// assert_eq!(23, max_flow);
+/// // The flows respect the capacities.
+/// assert!(graph.edge_indices().all(|e| flows[e.index()] <= graph[e]));
+/// ```
+pub fn dinic<N>(
+    network: N,
+    source: N::NodeId,
+    destination: N::NodeId,
+) -> (N::EdgeWeight, Vec<N::EdgeWeight>)
+where
+    N: NodeCount
+        + EdgeCount
+        + IntoEdgesDirected
+        + EdgeIndexable
+        + NodeIndexable
+        + DataMap
+        + Visitable,
+    N::EdgeWeight: Sub<Output = N::EdgeWeight> + PositiveMeasure,
+{
+    let ix = |i| NodeIndexable::to_index(&network, i);
+    let eix = |e: N::EdgeRef| EdgeIndexable::to_index(&network, e.id());
+    let zero = N::EdgeWeight::zero();
+
+    let mut flows = vec![zero; network.edge_bound()];
+    let mut max_flow = zero;
+    if source == destination {
+        return (max_flow, flows);
+    }
+
+    let residual = ResidualNetwork::new(network);
+
+    let mut level = vec![None; network.node_bound()];
+    let mut next_arc = vec![0; network.node_bound()];
+    let mut queue = VecDeque::new();
+    loop {
+        // Build the level graph: the distances from the source in the
+        // residual network.
+        level.iter_mut().for_each(|l| *l = None);
+        level[ix(source)] = Some(0);
+        queue.push_back(source);
+        while let Some(vertex) = queue.pop_front() {
+            for &arc in residual.arcs(ix(vertex)) {
+                let next = residual.head(arc);
+                if level[ix(next)].is_none() && residual.capacity(&flows, arc) > zero {
+                    level[ix(next)] = level[ix(vertex)].map(|l| l + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        if level[ix(destination)].is_none() {
+            break;
+        }
+
+        // Find a blocking flow with depth-first searches from the source,
+        // following level graph arcs that were not exhausted yet.
+        next_arc.iter_mut().for_each(|a| *a = 0);
+        let mut path = Vec::new();
+        let mut vertex = source;
+        loop {
+            if vertex == destination {
+                // Push the bottleneck capacity along the path, and retreat
+                // to the tail of the (first) saturated arc.
+                let (bottleneck, path_flow) = path
+                    .iter()
+                    .map(|&(_, arc)| residual.capacity(&flows, arc))
+                    .enumerate()
+                    .fold((0, N::EdgeWeight::max()), |(i, min), (j, r)| {
+                        if r < min {
+                            (j, r)
+                        } else {
+                            (i, min)
+                        }
+                    });
+                for &(_, (edge, forward)) in &path {
+                    let flow = &mut flows[eix(edge)];
+                    *flow = if forward {
+                        *flow + path_flow
+                    } else {
+                        *flow - path_flow
+                    };
+                }
+                max_flow = max_flow + path_flow;
+                vertex = path[bottleneck].0;
+                next_arc[ix(vertex)] += 1;
+                path.truncate(bottleneck);
+                continue;
+            }
+
+            let v = ix(vertex);
+            let mut advanced = false;
+            while let Some(&arc) = residual.arcs(v).get(next_arc[v]) {
+                let next = residual.head(arc);
+                if level[ix(next)] == level[v].map(|l| l + 1)
+                    && residual.capacity(&flows, arc) > zero
+                {
+                    path.push((vertex, arc));
+                    vertex = next;
+                    advanced = true;
+                    break;
+                }
+                next_arc[v] += 1;
+            }
+            if !advanced {
+                // Dead end: no flow can go through this node anymore.
+                match path.pop() {
+                    Some((previous, _)) => {
+                        vertex = previous;
+                        next_arc[ix(vertex)] += 1;
+                    }
+                    None => break,
+                }
+            }
+        }
+    }
+    (max_flow, flows)
+}
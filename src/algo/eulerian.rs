@@ -0,0 +1,338 @@
+//! Eulerian paths and circuits.
+
+use crate::algo::NotEulerian;
+use crate::visit::{
+    EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable,
+};
+
+type WalkResult<G> = Result<
+    EulerianWalk<<G as GraphBase>::NodeId, <G as IntoEdgeReferences>::EdgeRef>,
+    NotEulerian<<G as GraphBase>::NodeId>,
+>;
+
+/// \[Generic\] Return `true` if the graph has an Eulerian circuit.
+///
+/// An Eulerian circuit is a closed walk that goes through each edge exactly
+/// once. It exists when all the edges are connected, and each node has an
+/// even degree in an undirected graph, or as many incoming as outgoing edges
+/// in a directed graph. Nodes without edges are ignored, so a graph without
+/// edges has an empty circuit.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{has_eulerian_path, is_eulerian};
+/// use petgraph::prelude::*;
+///
+/// let mut g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// assert!(is_eulerian(&g));
+///
+/// g.extend_with_edges(&[(2, 3)]);
+/// assert!(!is_eulerian(&g));
+/// assert!(has_eulerian_path(&g));
+/// ```
+pub fn is_eulerian<G>(graph: G) -> bool
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    eulerian_circuit(graph).is_ok()
+}
+
+/// \[Generic\] Return `true` if the graph has an Eulerian path.
+///
+/// An Eulerian path is a walk that goes through each edge exactly once. It
+/// exists when all the edges are connected, and either the graph has an
+/// Eulerian circuit, or in an undirected graph exactly two nodes have an
+/// odd degree, or in a directed graph one node has one more outgoing than
+/// incoming edge, one node one more incoming than outgoing edge, and the
+/// other nodes as many incoming as outgoing edges.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+pub fn has_eulerian_path<G>(graph: G) -> bool
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    eulerian_path(graph).is_ok()
+}
+
+/// An Eulerian path or circuit, as returned by [`eulerian_path`][ep] and
+/// [`eulerian_circuit`][ec].
+///
+/// The edge `edges[i]` is walked from `nodes[i]` to `nodes[i + 1]`, so in an
+/// undirected graph it may be walked from its target to its source. Both
+/// vectors are empty if the graph has no edges.
+///
+/// [ep]: fn.eulerian_path.html
+/// [ec]: fn.eulerian_circuit.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct EulerianWalk<N, E> {
+    /// The nodes in the order they are visited, one more than the edges.
+    pub nodes: Vec<N>,
+    /// The edges in the order they are walked.
+    pub edges: Vec<E>,
+}
+
+/// \[Generic\] Eulerian circuit of the graph, with [Hierholzer's
+/// algorithm][hierholzer].
+///
+/// Returns the nodes and edges of the circuit in the order they are walked,
+/// starting and ending at the first node of `graph.node_identifiers()` that
+/// has an edge. Each edge of the graph appears exactly once, so parallel
+/// edges and self loops are all walked.
+///
+/// If the graph has no Eulerian circuit (see [`is_eulerian`][ie]), returns
+/// `NotEulerian::Unbalanced` with the nodes whose degrees are unbalanced, or
+/// `NotEulerian::Disconnected` if the degrees are balanced but the edges
+/// are not all connected.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// [hierholzer]: https://en.wikipedia.org/wiki/Eulerian_path#Hierholzer's_algorithm
+/// [ie]: fn.is_eulerian.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{eulerian_circuit, NotEulerian};
+/// use petgraph::prelude::*;
+///
+/// // Two parallel edges between 0 and 1, and a triangle 1 -> 2 -> 3 -> 1.
+/// let mut g = DiGraph::<(), &str>::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// g.extend_with_edges(&[
+///     (a, b, "ab"), (b, a, "ba"),
+///     (b, c, "bc"), (c, d, "cd"), (d, b, "db"),
+/// ]);
+///
+/// let circuit = eulerian_circuit(&g).unwrap();
+/// let names: Vec<&str> = circuit.edges.iter().map(|e| *e.weight()).collect();
+/// assert_eq!(names, vec!["ab", "bc", "cd", "db", "ba"]);
+/// assert_eq!(circuit.nodes, vec![a, b, c, d, b, a]);
+///
+/// // The edge 0 -> 2 leaves 0 with two outgoing edges, and 2 with two
+/// // incoming edges.
+/// g.add_edge(a, c, "ac");
+/// assert_eq!(eulerian_circuit(&g).err(), Some(NotEulerian::Unbalanced(vec![a, c])));
+/// ```
+pub fn eulerian_circuit<G>(graph: G) -> WalkResult<G>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let walk = Walk::new(graph);
+    let unbalanced = walk.unbalanced(graph);
+    if !unbalanced.is_empty() {
+        return Err(NotEulerian::Unbalanced(unbalanced));
+    }
+    let start = graph
+        .node_identifiers()
+        .find(|&n| !walk.adjacency[graph.to_index(n)].is_empty());
+    match start {
+        Some(start) => walk.hierholzer(graph, start),
+        None => Ok(EulerianWalk {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }),
+    }
+}
+
+/// \[Generic\] Eulerian path of the graph, with [Hierholzer's
+/// algorithm][hierholzer].
+///
+/// Returns the nodes and edges of the path in the order they are walked.
+/// The path starts at the first node of `graph.node_identifiers()` with an
+/// odd degree in an undirected graph, or at the node with one more outgoing
+/// than incoming edge in a directed graph. If the graph has an Eulerian
+/// circuit, this circuit is returned instead (see
+/// [`eulerian_circuit`][ec]). Each edge of the graph appears exactly once.
+///
+/// If the graph has no Eulerian path (see [`has_eulerian_path`][hep]),
+/// returns `NotEulerian::Unbalanced` with the nodes whose degrees are
+/// unbalanced, or `NotEulerian::Disconnected` if the degrees allow a path
+/// but the edges are not all connected.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// [hierholzer]: https://en.wikipedia.org/wiki/Eulerian_path#Hierholzer's_algorithm
+/// [ec]: fn.eulerian_circuit.html
+/// [hep]: fn.has_eulerian_path.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{eulerian_path, NotEulerian};
+/// use petgraph::prelude::*;
+///
+/// // A triangle with the tail 2 - 3: 2 and 3 have an odd degree.
+/// let mut g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+///
+/// let path = eulerian_path(&g).unwrap();
+/// let nodes: Vec<_> = path.nodes.iter().map(|n| n.index()).collect();
+/// assert_eq!(nodes, vec![2, 1, 0, 2, 3]);
+/// // The edge 1 - 2 is walked from its target to its source.
+/// let first = path.edges[0];
+/// assert_eq!((first.source(), first.target()), (path.nodes[1], path.nodes[0]));
+///
+/// // Four nodes with an odd degree.
+/// g.extend_with_edges(&[(0, 4)]);
+/// assert_eq!(
+///     eulerian_path(&g).err(),
+///     Some(NotEulerian::Unbalanced(vec![
+///         NodeIndex::new(0),
+///         NodeIndex::new(2),
+///         NodeIndex::new(3),
+///         NodeIndex::new(4),
+///     ]))
+/// );
+/// ```
+pub fn eulerian_path<G>(graph: G) -> WalkResult<G>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let walk = Walk::new(graph);
+    let unbalanced = walk.unbalanced(graph);
+    let start = if unbalanced.is_empty() {
+        graph
+            .node_identifiers()
+            .find(|&n| !walk.adjacency[graph.to_index(n)].is_empty())
+    } else if graph.is_directed() {
+        let starts: Vec<_> = unbalanced
+            .iter()
+            .filter(|&&n| walk.balance[graph.to_index(n)] == 1)
+            .collect();
+        let ends = unbalanced
+            .iter()
+            .filter(|&&n| walk.balance[graph.to_index(n)] == -1)
+            .count();
+        if unbalanced.len() != 2 || starts.len() != 1 || ends != 1 {
+            return Err(NotEulerian::Unbalanced(unbalanced));
+        }
+        Some(*starts[0])
+    } else if unbalanced.len() == 2 {
+        Some(unbalanced[0])
+    } else {
+        return Err(NotEulerian::Unbalanced(unbalanced));
+    };
+    match start {
+        Some(start) => walk.hierholzer(graph, start),
+        None => Ok(EulerianWalk {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }),
+    }
+}
+
+/// The edges of a graph, with the edges each node can walk next.
+struct Walk<E> {
+    edges: Vec<E>,
+    /// The edges leaving each node index, with the node they lead to.
+    adjacency: Vec<Vec<(usize, usize)>>,
+    /// Outgoing minus incoming edges of each node index in a directed graph,
+    /// and the degree in an undirected graph.
+    balance: Vec<isize>,
+}
+
+impl<E: Copy> Walk<E> {
+    fn new<G>(graph: G) -> Self
+    where
+        G: IntoEdgeReferences<EdgeRef = E> + NodeIndexable + GraphProp,
+        E: EdgeRef<NodeId = G::NodeId>,
+    {
+        let edges: Vec<E> = graph.edge_references().collect();
+        let mut adjacency = vec![Vec::new(); graph.node_bound()];
+        let mut balance = vec![0; graph.node_bound()];
+        for (i, edge) in edges.iter().enumerate() {
+            let a = graph.to_index(edge.source());
+            let b = graph.to_index(edge.target());
+            adjacency[a].push((i, b));
+            balance[a] += 1;
+            if graph.is_directed() {
+                balance[b] -= 1;
+            } else {
+                adjacency[b].push((i, a));
+                balance[b] += 1;
+            }
+        }
+        // The edges are taken from the back.
+        for edges in &mut adjacency {
+            edges.reverse();
+        }
+        Walk {
+            edges,
+            adjacency,
+            balance,
+        }
+    }
+
+    /// The nodes whose degrees prevent an Eulerian circuit, in the order of
+    /// `graph.node_identifiers()`.
+    fn unbalanced<G>(&self, graph: G) -> Vec<G::NodeId>
+    where
+        G: IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    {
+        let directed = graph.is_directed();
+        graph
+            .node_identifiers()
+            .filter(|&n| {
+                let balance = self.balance[graph.to_index(n)];
+                if directed {
+                    balance != 0
+                } else {
+                    balance % 2 != 0
+                }
+            })
+            .collect()
+    }
+
+    /// Walks all the edges from `start`, or fails if some of them can not be
+    /// reached.
+    fn hierholzer<G>(
+        mut self,
+        graph: G,
+        start: G::NodeId,
+    ) -> Result<EulerianWalk<G::NodeId, E>, NotEulerian<G::NodeId>>
+    where
+        G: NodeIndexable,
+    {
+        let mut used = vec![false; self.edges.len()];
+        let mut nodes = Vec::with_capacity(self.edges.len() + 1);
+        let mut edges = Vec::with_capacity(self.edges.len());
+        // The current walk, as the nodes with the edge that led to them.
+        let mut stack = vec![(graph.to_index(start), None)];
+        while let Some(&(node, edge)) = stack.last() {
+            let mut next = None;
+            while let Some((e, target)) = self.adjacency[node].pop() {
+                if !used[e] {
+                    next = Some((e, target));
+                    break;
+                }
+            }
+            match next {
+                Some((e, target)) => {
+                    used[e] = true;
+                    stack.push((target, Some(e)));
+                }
+                None => {
+                    stack.pop();
+                    nodes.push(graph.from_index(node));
+                    edges.extend(edge.map(|e| self.edges[e]));
+                }
+            }
+        }
+        if edges.len() < self.edges.len() {
+            return Err(NotEulerian::Disconnected);
+        }
+        nodes.reverse();
+        edges.reverse();
+        Ok(EulerianWalk { nodes, edges })
+    }
+}
@@ -5,6 +5,7 @@ use std::hash::Hash;
 use crate::algo::{BoundedMeasure, NegativeCycle};
 use crate::visit::{
     EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeCompactIndexable,
+    NodeIndexable,
 };
 
 #[allow(clippy::type_complexity, clippy::needless_range_loop)]
@@ -135,3 +136,202 @@ where
 
     Ok(distance_map)
 }
+
+/// All pairs shortest paths, as a dense matrix.
+///
+/// Rows and columns are indexed by the graph's node indices, as given by
+/// `NodeIndexable::to_index`. Along with the length of each shortest path,
+/// the matrix stores the next node on it, so that the paths can be
+/// reconstructed.
+///
+/// Returned by [`floyd_warshall_matrix`].
+#[derive(Debug, Clone)]
+pub struct DistanceMatrix<K> {
+    node_bound: usize,
+    distances: Vec<K>,
+    next: Vec<usize>,
+}
+
+/// Marks a missing next hop.
+const NO_HOP: usize = std::usize::MAX;
+
+impl<K: Copy> DistanceMatrix<K> {
+    /// Return the number of rows and columns of the matrix, the node bound of
+    /// the graph.
+    pub fn node_bound(&self) -> usize {
+        self.node_bound
+    }
+
+    /// Return the length of the shortest path from node index `u` to node
+    /// index `v`, or `None` if `v` is not reachable from `u`.
+    ///
+    /// **Panics** if `u` or `v` is not below the node bound.
+    pub fn distance(&self, u: usize, v: usize) -> Option<K> {
+        let i = self.position(u, v);
+        if self.next[i] == NO_HOP {
+            None
+        } else {
+            Some(self.distances[i])
+        }
+    }
+
+    /// Return the node index that follows `u` on the shortest path from node
+    /// index `u` to node index `v`, or `None` if `v` is not reachable from
+    /// `u`. The next hop from a node to itself is the node.
+    ///
+    /// **Panics** if `u` or `v` is not below the node bound.
+    pub fn next_hop(&self, u: usize, v: usize) -> Option<usize> {
+        match self.next[self.position(u, v)] {
+            NO_HOP => None,
+            hop => Some(hop),
+        }
+    }
+
+    /// Return the node indices of the shortest path from node index `u` to
+    /// node index `v`, both included, or `None` if `v` is not reachable from
+    /// `u`.
+    ///
+    /// **Panics** if `u` or `v` is not below the node bound.
+    pub fn path(&self, u: usize, v: usize) -> Option<Vec<usize>> {
+        // Even for `u == v`, so that a vacant node index has no path.
+        self.next_hop(u, v)?;
+        let mut node = u;
+        let mut path = vec![node];
+        while node != v {
+            node = self.next_hop(node, v)?;
+            path.push(node);
+        }
+        Some(path)
+    }
+
+    fn position(&self, u: usize, v: usize) -> usize {
+        assert!(
+            u < self.node_bound && v < self.node_bound,
+            "node index out of bounds"
+        );
+        u * self.node_bound + v
+    }
+}
+
+/// \[Generic\] [Floyd–Warshall algorithm](https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm),
+/// with a dense matrix result and path reconstruction.
+///
+/// Compute shortest paths in a weighted graph with positive or negative edge
+/// weights (but with no negative cycles), like [`floyd_warshall`]. The result
+/// is a [`DistanceMatrix`] indexed by `NodeIndexable::to_index`, with the
+/// next hop along each shortest path, instead of a `HashMap`.
+///
+/// Among parallel edges the cheapest one is used, and a self-loop with
+/// negative cost is a negative cycle.
+///
+/// # Arguments
+/// * `graph`: graph with no negative cycle
+/// * `edge_cost`: closure that returns cost of a particular edge
+///
+/// # Returns
+/// * `Ok`: (if graph contains no negative cycle) the matrix of all pairs
+///   shortest paths
+/// * `Err`: if graph contains negative cycle.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|³)**.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the node bound of the graph.
+///
+/// # Examples
+/// ```rust
+/// use petgraph::algo::floyd_warshall_matrix;
+/// use petgraph::matrix_graph::DiMatrix;
+///
+/// let mut graph: DiMatrix<(), i32> = DiMatrix::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (a, c, 4), (a, d, 10), (b, c, 2), (b, d, 2), (c, d, -3)]);
+/// //     ----- b --------
+/// //    |      ^         | 2
+/// //    |    1 |    4    v
+/// //  2 |      a ------> c
+/// //    |   10 |         | -3
+/// //    |      v         v
+/// //     --->  d <-------
+///
+/// let res = floyd_warshall_matrix(&graph, |edge| *edge.2).unwrap();
+/// let (a, b, c, d) = (a.index(), b.index(), c.index(), d.index());
+/// assert_eq!(res.distance(a, d), Some(0));
+/// assert_eq!(res.next_hop(a, d), Some(b));
+/// assert_eq!(res.path(a, d), Some(vec![a, b, c, d]));
+///
+/// assert_eq!(res.distance(d, a), None);
+/// assert_eq!(res.path(d, a), None);
+/// assert_eq!(res.path(d, d), Some(vec![d]));
+/// ```
+#[allow(clippy::needless_range_loop)]
+pub fn floyd_warshall_matrix<G, F, K>(
+    graph: G,
+    mut edge_cost: F,
+) -> Result<DistanceMatrix<K>, NegativeCycle>
+where
+    G: NodeIndexable + IntoEdgeReferences + IntoNodeIdentifiers + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    let n = graph.node_bound();
+    let mut dist = vec![K::max(); n * n];
+    let mut next = vec![NO_HOP; n * n];
+
+    // distance of each node to itself is 0(default value)
+    for node in graph.node_identifiers() {
+        let i = graph.to_index(node);
+        dist[i * n + i] = K::default();
+        next[i * n + i] = i;
+    }
+
+    // init distances of paths with no intermediate nodes
+    for edge in graph.edge_references() {
+        let cost = edge_cost(edge);
+        let i = graph.to_index(edge.source());
+        let j = graph.to_index(edge.target());
+        let pairs = [(i, j), (j, i)];
+        let directions = if graph.is_directed() { 1 } else { 2 };
+        for &(i, j) in &pairs[..directions] {
+            if next[i * n + j] == NO_HOP || cost < dist[i * n + j] {
+                dist[i * n + j] = cost;
+                next[i * n + j] = j;
+            }
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            if next[i * n + k] == NO_HOP {
+                continue;
+            }
+            for j in 0..n {
+                if next[k * n + j] == NO_HOP {
+                    continue;
+                }
+                let (result, overflow) = dist[i * n + k].overflowing_add(dist[k * n + j]);
+                if !overflow && (next[i * n + j] == NO_HOP || result < dist[i * n + j]) {
+                    dist[i * n + j] = result;
+                    next[i * n + j] = next[i * n + k];
+                }
+            }
+        }
+    }
+
+    // value less than 0(default value) indicates a negative cycle
+    for i in 0..n {
+        if next[i * n + i] != NO_HOP && dist[i * n + i] < K::default() {
+            return Err(NegativeCycle(()));
+        }
+    }
+
+    Ok(DistanceMatrix {
+        node_bound: n,
+        distances: dist,
+        next,
+    })
+}
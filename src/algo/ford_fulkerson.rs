@@ -108,6 +108,66 @@ where
     }
 }
 
+/// The residual network of a flow network, shared by the maximum flow
+/// algorithms that scan the arcs of each node many times.
+pub(crate) struct ResidualNetwork<N>
+where
+    N: IntoEdgesDirected + NodeIndexable + EdgeIndexable,
+{
+    network: N,
+    /// Residual arcs of each node: its edges, and whether they are traversed
+    /// forward.
+    arcs: Vec<Vec<(N::EdgeRef, bool)>>,
+}
+
+impl<N> ResidualNetwork<N>
+where
+    N: IntoEdgesDirected + NodeIndexable + EdgeIndexable,
+    N::EdgeWeight: Sub<Output = N::EdgeWeight> + PositiveMeasure,
+{
+    pub(crate) fn new(network: N) -> Self {
+        let mut arcs = vec![Vec::new(); network.node_bound()];
+        for (i, node_arcs) in arcs.iter_mut().enumerate() {
+            let node = NodeIndexable::from_index(&network, i);
+            for edge in network.edges_directed(node, Direction::Outgoing) {
+                node_arcs.push((edge, true));
+            }
+            for edge in network.edges_directed(node, Direction::Incoming) {
+                node_arcs.push((edge, false));
+            }
+        }
+        ResidualNetwork { network, arcs }
+    }
+
+    /// Return the residual arcs of the node with index `i`.
+    pub(crate) fn arcs(&self, i: usize) -> &[(N::EdgeRef, bool)] {
+        &self.arcs[i]
+    }
+
+    /// Return the residual capacity of `arc`, given the edge flows.
+    pub(crate) fn capacity(
+        &self,
+        flows: &[N::EdgeWeight],
+        (edge, forward): (N::EdgeRef, bool),
+    ) -> N::EdgeWeight {
+        let flow = flows[EdgeIndexable::to_index(&self.network, edge.id())];
+        if forward {
+            *edge.weight() - flow
+        } else {
+            flow
+        }
+    }
+
+    /// Return the node that `arc` leads to.
+    pub(crate) fn head(&self, (edge, forward): (N::EdgeRef, bool)) -> N::NodeId {
+        if forward {
+            edge.target()
+        } else {
+            edge.source()
+        }
+    }
+}
+
 /// \[Generic\] Ford-Fulkerson algorithm.
 ///
 /// Computes the [maximum flow][ff] of a weighted directed graph.
@@ -194,3 +254,81 @@ where
     }
     (max_flow, flows)
 }
+
+/// \[Generic\] Minimum cut of a flow network.
+///
+/// Given the edge `flows` of a maximum flow from `source`, as returned by
+/// [`ford_fulkerson`], [`dinic`][dinic] or [`push_relabel`][pr], computes a
+/// [minimum cut][cut]: the nodes that can still be reached from `source` in
+/// the residual network, and the edges that leave them. The cut edges are
+/// saturated, and their capacities sum to the maximum flow.
+///
+/// Returns the source side nodes, in breadth-first order from `source`, and
+/// the cut edges.
+///
+/// [dinic]: ../dinic/fn.dinic.html
+/// [pr]: ../push_relabel/fn.push_relabel.html
+/// [cut]: https://en.wikipedia.org/wiki/Max-flow_min-cut_theorem
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::{ford_fulkerson, min_cut};
+/// use petgraph::visit::EdgeRef;
+///
+/// let mut graph = Graph::<u8, u8>::new();
+/// let source = graph.add_node(0);
+/// let a = graph.add_node(1);
+/// let b = graph.add_node(2);
+/// let destination = graph.add_node(3);
+/// graph.extend_with_edges(&[(0, 1, 4), (0, 2, 4), (1, 2, 5), (1, 3, 2), (2, 3, 3)]);
+/// // 0 --4--> 1 --2--> 3
+/// // |        |5       ^
+/// // +---4--> 2 --3----+
+///
+/// let (max_flow, flows) = ford_fulkerson(&graph, source, destination);
+/// let (mut source_side, cut_edges) = min_cut(&graph, source, &flows);
+/// source_side.sort();
+/// assert_eq!(source_side, vec![source, a, b]);
+/// let mut cut: Vec<_> = cut_edges.iter().map(|e| (e.source(), e.target())).collect();
+/// cut.sort();
+/// assert_eq!(cut, vec![(a, destination), (b, destination)]);
+/// assert_eq!(cut_edges.iter().map(|e| e.weight()).sum::<u8>(), max_flow);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn min_cut<N>(
+    network: N,
+    source: N::NodeId,
+    flows: &[N::EdgeWeight],
+) -> (Vec<N::NodeId>, Vec<N::EdgeRef>)
+where
+    N: IntoEdgesDirected + EdgeIndexable + NodeIndexable + DataMap + Visitable,
+    N::EdgeWeight: Sub<Output = N::EdgeWeight> + PositiveMeasure,
+{
+    let mut visited = network.visit_map();
+    let mut source_side = vec![source];
+    visited.visit(source);
+
+    let mut i = 0;
+    while let Some(&vertex) = source_side.get(i) {
+        let out_edges = network.edges_directed(vertex, Direction::Outgoing);
+        let in_edges = network.edges_directed(vertex, Direction::Incoming);
+        for edge in out_edges.chain(in_edges) {
+            let next = other_endpoint(&network, edge, vertex);
+            let edge_index: usize = EdgeIndexable::to_index(&network, edge.id());
+            let residual_cap = residual_capacity(&network, edge, next, flows[edge_index]);
+            if !visited.is_visited(&next) && (residual_cap > N::EdgeWeight::zero()) {
+                visited.visit(next);
+                source_side.push(next);
+            }
+        }
+        i += 1;
+    }
+
+    let cut_edges = source_side
+        .iter()
+        .flat_map(|&vertex| network.edges_directed(vertex, Direction::Outgoing))
+        .filter(|edge| !visited.is_visited(&edge.target()))
+        .collect();
+    (source_side, cut_edges)
+}
@@ -0,0 +1,187 @@
+//! Johnson's algorithm.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::algo::{dijkstra, BoundedMeasure, NegativeCycle};
+use crate::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable, Visitable};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// \[Generic\] [Johnson's algorithm][johnson] for all pairs shortest path problem.
+///
+/// Compute the lengths of shortest paths in a weighted graph with positive or
+/// negative edge weights, but no negative cycles.
+///
+/// The edges are first reweighted to be non-negative, using node potentials
+/// computed with one Bellman–Ford pass. Then Dijkstra's algorithm is run from
+/// every node. This is faster than [`floyd_warshall`][fw] on sparse graphs.
+///
+/// # Arguments
+/// * `graph`: weighted graph.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * `Ok`: (if graph contains no negative cycle) a hashmap mapping each pair
+///   of nodes `(source, target)` where `target` is reachable from `source` to
+///   the length of the shortest path between them.
+/// * `Err`: if graph contains negative cycle.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|·|E| + |V|·(|V| + |E|)·log(|V|))**.
+/// * Auxiliary space: **O(|V|² + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [johnson]: https://en.wikipedia.org/wiki/Johnson%27s_algorithm
+/// [fw]: ../floyd_warshall/fn.floyd_warshall.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::{prelude::*, Graph, Directed};
+/// use petgraph::algo::johnson;
+/// use std::collections::HashMap;
+///
+/// let mut graph: Graph<(), i32, Directed> = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+///
+/// graph.extend_with_edges(&[
+///    (a, b, 1),
+///    (a, c, 4),
+///    (a, d, 10),
+///    (b, c, 2),
+///    (b, d, 2),
+///    (c, d, -3),
+/// ]);
+/// //     ----- b --------
+/// //    |      ^         | 2
+/// //    |    1 |    4    v
+/// //  2 |      a ------> c
+/// //    |   10 |         | -3
+/// //    |      v         v
+/// //     --->  d <-------
+///
+/// let expected_res: HashMap<(NodeIndex, NodeIndex), i32> = [
+///    ((a, a), 0), ((a, b), 1), ((a, c), 3), ((a, d), 0),
+///    ((b, b), 0), ((b, c), 2), ((b, d), -1),
+///    ((c, c), 0), ((c, d), -3),
+///    ((d, d), 0),
+/// ].iter().cloned().collect();
+///
+/// let res = johnson(&graph, |edge| *edge.weight()).unwrap();
+/// assert_eq!(res, expected_res);
+///
+/// // A negative cycle is reported as an error.
+/// graph.add_edge(d, a, -1);
+/// assert!(johnson(&graph, |edge| *edge.weight()).is_err());
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn johnson<G, F, K>(
+    graph: G,
+    mut edge_cost: F,
+) -> Result<HashMap<(G::NodeId, G::NodeId), K>, NegativeCycle>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeCount + NodeIndexable + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    let ix = |i| graph.to_index(i);
+    let potential = johnson_potentials(graph, &mut edge_cost)?;
+
+    let mut distance_map = HashMap::with_capacity(graph.node_count());
+    for source in graph.node_identifiers() {
+        let reweighted = dijkstra(graph, source, None, |edge| {
+            edge_cost(edge) + potential[ix(edge.source())] - potential[ix(edge.target())]
+        });
+        for (target, distance) in reweighted {
+            distance_map.insert(
+                (source, target),
+                distance - potential[ix(source)] + potential[ix(target)],
+            );
+        }
+    }
+
+    Ok(distance_map)
+}
+
+/// \[Generic\] Parallel [Johnson's algorithm][johnson].
+///
+/// The Dijkstra searches from each node are run in parallel.
+/// See [`johnson`].
+///
+/// [johnson]: https://en.wikipedia.org/wiki/Johnson%27s_algorithm
+#[cfg(feature = "rayon")]
+#[allow(clippy::type_complexity)]
+pub fn parallel_johnson<G, F, K>(
+    graph: G,
+    edge_cost: F,
+) -> Result<HashMap<(G::NodeId, G::NodeId), K>, NegativeCycle>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeCount + NodeIndexable + Visitable + Sync,
+    G::NodeId: Eq + Hash + Send + Sync,
+    F: Fn(G::EdgeRef) -> K + Sync,
+    K: BoundedMeasure + Copy + Send + Sync,
+{
+    let ix = |i| graph.to_index(i);
+    let potential = johnson_potentials(graph, &mut |edge| edge_cost(edge))?;
+
+    let sources: Vec<_> = graph.node_identifiers().collect();
+    let distance_map = sources
+        .into_par_iter()
+        .flat_map_iter(|source| {
+            let reweighted = dijkstra(graph, source, None, |edge| {
+                edge_cost(edge) + potential[ix(edge.source())] - potential[ix(edge.target())]
+            });
+            let potential = &potential;
+            reweighted.into_iter().map(move |(target, distance)| {
+                (
+                    (source, target),
+                    distance - potential[ix(source)] + potential[ix(target)],
+                )
+            })
+        })
+        .collect();
+
+    Ok(distance_map)
+}
+
+/// Compute the node potentials used to reweight the edges, or return an error
+/// if the graph contains a negative cycle.
+///
+/// This is the Bellman–Ford algorithm from an extra node that has a zero-cost
+/// edge to every node of the graph.
+fn johnson_potentials<G, F, K>(graph: G, edge_cost: &mut F) -> Result<Vec<K>, NegativeCycle>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeCount + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    let ix = |i| graph.to_index(i);
+    // The extra node makes the distances start at zero instead of infinity.
+    let mut potential = vec![K::default(); graph.node_bound()];
+
+    // With the extra node there are |V| + 1 nodes, so shortest paths are found
+    // after |V| rounds. A change in one more round means a negative cycle.
+    for _ in 0..=graph.node_count() {
+        let mut did_update = false;
+        for i in graph.node_identifiers() {
+            for edge in graph.edges(i) {
+                let j = edge.target();
+                let distance = potential[ix(i)] + edge_cost(edge);
+                if distance < potential[ix(j)] {
+                    potential[ix(j)] = distance;
+                    did_update = true;
+                }
+            }
+        }
+        if !did_update {
+            return Ok(potential);
+        }
+    }
+    Err(NegativeCycle(()))
+}
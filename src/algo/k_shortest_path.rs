@@ -1,10 +1,12 @@
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 
 use std::hash::Hash;
 
-use crate::algo::Measure;
+use crate::algo::{astar, Measure};
 use crate::scored::MinScored;
-use crate::visit::{EdgeRef, IntoEdges, NodeCount, NodeIndexable, Visitable};
+use crate::visit::{
+    EdgeFiltered, EdgeRef, IntoEdges, NodeCount, NodeFiltered, NodeIndexable, Visitable,
+};
 
 /// \[Generic\] k'th shortest path algorithm.
 ///
@@ -113,3 +115,154 @@ where
     }
     scores
 }
+
+/// \[Generic\] Yen's k shortest loopless paths algorithm.
+///
+/// Compute up to `k` distinct simple paths from `start` to `goal`, in order of
+/// increasing cost, using [Yen's algorithm][yen]. Unlike [`k_shortest_path`],
+/// no node is visited twice on a path, and the paths themselves are returned.
+///
+/// Each path after the first deviates from one of the previous paths at some
+/// *spur* node: the shortest path from the spur node is searched with
+/// [`astar`] in the graph with the nodes of the common prefix and the already
+/// used next edges masked out by [`NodeFiltered`] and [`EdgeFiltered`].
+///
+/// The graph should be `Visitable` and implement `IntoEdges`. The function
+/// `edge_cost` should return the cost for a particular edge, which is used
+/// to compute path costs. Edge costs must be non-negative.
+///
+/// Paths are sequences of nodes, so paths using different parallel edges
+/// between the same nodes are not distinct; the cheapest edge is used.
+///
+/// Computes in **O(k·|V|·(|E| + |V|·log(|V|)))** time.
+///
+/// Returns a vector of at most `k` pairs of the total cost and the path of
+/// nodes from `start` (first) to `goal` (last). Paths of equal cost are
+/// returned in an unspecified order.
+///
+/// [yen]: https://en.wikipedia.org/wiki/Yen%27s_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::yen_k_shortest_paths;
+///
+/// let mut graph: Graph<(), u32> = Graph::new();
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let e = graph.add_node(());
+/// let f = graph.add_node(());
+/// let g = graph.add_node(());
+/// let h = graph.add_node(());
+/// graph.extend_with_edges(&[
+///     (c, d, 3),
+///     (c, e, 2),
+///     (d, f, 4),
+///     (e, d, 1),
+///     (e, f, 2),
+///     (e, g, 3),
+///     (f, g, 2),
+///     (f, h, 1),
+///     (g, h, 2),
+/// ]);
+/// // c --3--> d --4--> f --1--> h
+/// // |        ^      ^ |        ^
+/// // 2        1     /  2        2
+/// // v        |    2   v        |
+/// // e -------+---+    g -------+
+/// // |                 ^
+/// // +--------3--------+
+///
+/// let paths = yen_k_shortest_paths(&graph, c, h, 3, |e| *e.weight());
+/// assert_eq!(paths.len(), 3);
+/// assert_eq!(paths[0], (5, vec![c, e, f, h]));
+/// assert_eq!(paths[1], (7, vec![c, e, g, h]));
+/// // Both c -> d -> f -> h and c -> e -> d -> f -> h cost 8.
+/// assert_eq!(paths[2].0, 8);
+/// ```
+pub fn yen_k_shortest_paths<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    k: usize,
+    mut edge_cost: F,
+) -> Vec<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut paths = Vec::new();
+    if k == 0 {
+        return paths;
+    }
+    let zero_score = K::default();
+    match astar(graph, start, |n| n == goal, &mut edge_cost, |_| zero_score) {
+        Some(path) => paths.push(path),
+        None => return paths,
+    }
+
+    // Candidate paths, and every path that was ever a candidate.
+    let mut candidates = BinaryHeap::new();
+    let mut seen = HashSet::new();
+    seen.insert(paths[0].1.clone());
+
+    while paths.len() < k {
+        let (_, previous) = &paths[paths.len() - 1];
+        let mut root_score = zero_score;
+        for i in 0..previous.len() - 1 {
+            let root = &previous[..=i];
+            let spur = previous[i];
+            // Next nodes after the root in the paths found so far.
+            let used: HashSet<_> = paths
+                .iter()
+                .filter(|(_, path)| path.len() > i + 1 && path[..=i] == *root)
+                .map(|(_, path)| path[i + 1])
+                .collect();
+            let masked: HashSet<_> = root[..i].iter().cloned().collect();
+            let nodes = NodeFiltered::from_fn(graph, |n| !masked.contains(&n));
+            let edges = EdgeFiltered::from_fn(&nodes, |edge: G::EdgeRef| {
+                edge.source() != spur || !used.contains(&edge.target())
+            });
+            if let Some((spur_score, spur_path)) =
+                astar(&edges, spur, |n| n == goal, &mut edge_cost, |_| zero_score)
+            {
+                let mut path = root[..i].to_vec();
+                path.extend(spur_path);
+                if seen.insert(path.clone()) {
+                    candidates.push(MinScored(root_score + spur_score, path));
+                }
+            }
+
+            match cheapest_edge_cost(graph, previous[i], previous[i + 1], &mut edge_cost) {
+                Some(score) => root_score = root_score + score,
+                None => break,
+            }
+        }
+
+        match candidates.pop() {
+            Some(MinScored(score, path)) => paths.push((score, path)),
+            None => break,
+        }
+    }
+    paths
+}
+
+/// Return the least cost of the edges from `a` to `b`.
+fn cheapest_edge_cost<G, F, K>(graph: G, a: G::NodeId, b: G::NodeId, edge_cost: &mut F) -> Option<K>
+where
+    G: IntoEdges,
+    G::NodeId: Eq,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    graph
+        .edges(a)
+        .filter(|edge| edge.target() == b)
+        .map(edge_cost)
+        .fold(None, |best, score| match best {
+            Some(best) if best <= score => Some(best),
+            _ => Some(score),
+        })
+}
@@ -1,9 +1,13 @@
-use std::collections::VecDeque;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
 use std::hash::Hash;
+use std::ops::{Div, Sub};
 
+use crate::algo::{BoundedMeasure, Measure};
+use crate::scored::MinScored;
 use crate::visit::{
-    EdgeRef, GraphBase, IntoEdges, IntoNeighbors, IntoNodeIdentifiers, NodeCount, NodeIndexable,
-    VisitMap, Visitable,
+    EdgeRef, GraphBase, IntoEdgeReferences, IntoEdges, IntoNeighbors, IntoNodeIdentifiers,
+    NodeCount, NodeIndexable, VisitMap, Visitable,
 };
 
 /// Computed
@@ -609,3 +613,1265 @@ fn augment_path<G>(
         panic!("Unexpected label when augmenting path");
     }
 }
+
+/// \[Generic\] Compute a minimum cost
+/// [*assignment*](https://en.wikipedia.org/wiki/Assignment_problem) in a
+/// bipartite graph, using the
+/// [Hungarian algorithm](https://en.wikipedia.org/wiki/Hungarian_algorithm).
+///
+/// The graph is given with the two node sets `left` and `right` of its
+/// bipartition; it is treated as if undirected, and only edges between the
+/// two sets are considered. The function `edge_cost` should return the cost
+/// of a particular edge. Among parallel edges, the cheapest one is used.
+///
+/// The graph may be sparse and the sets may have different sizes: the
+/// assignment is a maximum (cardinality) matching, of least total cost among
+/// the maximum matchings. It is perfect if the sets have the same size and
+/// every node can be assigned.
+///
+/// Returns the matching and its total cost.
+///
+/// The algorithm is the successive shortest paths variant, which runs in
+/// *O(|V|·|E|·log(|V|))*.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::algo::min_cost_assignment;
+///
+/// // Three workers and the costs of the jobs they can do.
+/// let mut graph: UnGraph<&str, u32> = UnGraph::new_undirected();
+/// let alice = graph.add_node("Alice");
+/// let bob = graph.add_node("Bob");
+/// let carol = graph.add_node("Carol");
+/// let clean = graph.add_node("clean");
+/// let cook = graph.add_node("cook");
+/// let shop = graph.add_node("shop");
+/// graph.extend_with_edges(&[
+///     (alice, clean, 2),
+///     (alice, cook, 3),
+///     (alice, shop, 3),
+///     (bob, clean, 3),
+///     (bob, cook, 2),
+///     (bob, shop, 3),
+///     (carol, clean, 3),
+///     (carol, cook, 3),
+///     (carol, shop, 2),
+/// ]);
+///
+/// let workers = [alice, bob, carol];
+/// let jobs = [clean, cook, shop];
+/// let (matching, cost) =
+///     min_cost_assignment(&graph, workers.iter().cloned(), jobs.iter().cloned(), |e| *e.weight());
+/// assert_eq!(cost, 6);
+/// assert_eq!(matching.mate(alice), Some(clean));
+/// assert_eq!(matching.mate(bob), Some(cook));
+/// assert_eq!(matching.mate(carol), Some(shop));
+/// ```
+pub fn min_cost_assignment<G, I, J, F, K>(
+    graph: G,
+    left: I,
+    right: J,
+    edge_cost: F,
+) -> (Matching<G>, K)
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    I: IntoIterator<Item = G::NodeId>,
+    J: IntoIterator<Item = G::NodeId>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    assignment(graph, left, right, edge_cost, false)
+}
+
+/// \[Generic\] Compute a maximum weight
+/// [*assignment*](https://en.wikipedia.org/wiki/Assignment_problem) in a
+/// bipartite graph, using the
+/// [Hungarian algorithm](https://en.wikipedia.org/wiki/Hungarian_algorithm).
+///
+/// Like [`min_cost_assignment`][1], but the function `edge_weight` returns
+/// the weight of a particular edge, and the assignment is a maximum
+/// (cardinality) matching of greatest total weight among the maximum
+/// matchings. Among parallel edges, the heaviest one is used.
+///
+/// Returns the matching and its total weight.
+///
+/// [1]: fn.min_cost_assignment.html
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::algo::max_weight_assignment;
+///
+/// // The profit of each worker on each machine.
+/// let mut graph: UnGraph<(), f64> = UnGraph::new_undirected();
+/// let workers = [graph.add_node(()), graph.add_node(())];
+/// let machines = [graph.add_node(()), graph.add_node(()), graph.add_node(())];
+/// let profits = [[7., 4., 3.], [6., 8., 5.]];
+/// for (&w, row) in workers.iter().zip(&profits) {
+///     for (&m, &profit) in machines.iter().zip(row) {
+///         graph.add_edge(w, m, profit);
+///     }
+/// }
+///
+/// let (matching, profit) = max_weight_assignment(
+///     &graph,
+///     workers.iter().cloned(),
+///     machines.iter().cloned(),
+///     |e| *e.weight(),
+/// );
+/// assert_eq!(profit, 15.);
+/// assert_eq!(matching.len(), 2);
+/// assert_eq!(matching.mate(workers[0]), Some(machines[0]));
+/// assert_eq!(matching.mate(workers[1]), Some(machines[1]));
+/// assert!(!matching.contains_node(machines[2]));
+/// ```
+pub fn max_weight_assignment<G, I, J, F, K>(
+    graph: G,
+    left: I,
+    right: J,
+    edge_weight: F,
+) -> (Matching<G>, K)
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    I: IntoIterator<Item = G::NodeId>,
+    J: IntoIterator<Item = G::NodeId>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    assignment(graph, left, right, edge_weight, true)
+}
+
+fn assignment<G, I, J, F, K>(
+    graph: G,
+    left: I,
+    right: J,
+    mut edge_weight: F,
+    maximize: bool,
+) -> (Matching<G>, K)
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    I: IntoIterator<Item = G::NodeId>,
+    J: IntoIterator<Item = G::NodeId>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    let zero = K::default();
+    let left: Vec<_> = left.into_iter().collect();
+    let right: Vec<_> = right.into_iter().collect();
+    let (n, m) = (left.len(), right.len());
+
+    // Rows are the left nodes and columns the right ones.
+    let adjacency: Vec<Vec<(usize, K)>> = bipartite_edges(graph, &left, &right)
+        .into_iter()
+        .map(|edges| {
+            edges
+                .into_iter()
+                .map(|(j, edge)| (j, edge_weight(edge)))
+                .collect()
+        })
+        .collect();
+
+    // Maximizing the weight of a maximum matching is minimizing the costs
+    // `max_weight - weight`, since all maximum matchings have as many edges.
+    let max_weight = adjacency
+        .iter()
+        .flatten()
+        .map(|&(_, weight)| weight)
+        .fold(None, |max, weight| match max {
+            Some(max) if max >= weight => Some(max),
+            _ => Some(weight),
+        })
+        .unwrap_or(zero);
+    let cost = |weight| {
+        if maximize {
+            max_weight - weight
+        } else {
+            weight
+        }
+    };
+
+    // Potentials keep the reduced costs `cost + row_potential -
+    // column_potential` non-negative, so that shortest augmenting paths can
+    // be found with Dijkstra's algorithm.
+    let mut row_potential = vec![zero; n];
+    let mut column_potential: Vec<Option<K>> = vec![None; m];
+    for &(j, weight) in adjacency.iter().flatten() {
+        let cost = cost(weight);
+        match column_potential[j] {
+            Some(potential) if potential <= cost => {}
+            _ => column_potential[j] = Some(cost),
+        }
+    }
+    let mut column_potential: Vec<K> = column_potential
+        .into_iter()
+        .map(|p| p.unwrap_or(zero))
+        .collect();
+
+    let mut row_mate: Vec<Option<usize>> = vec![None; n];
+    let mut column_mate: Vec<Option<usize>> = vec![None; m];
+    // Rows are the nodes `0..n` and columns `n..n + m` of the search.
+    let mut distance: Vec<Option<K>> = vec![None; n + m];
+    let mut settled = vec![false; n + m];
+    let mut predecessor = vec![0; m];
+    let mut visit_next = BinaryHeap::new();
+    loop {
+        distance.iter_mut().for_each(|d| *d = None);
+        settled.iter_mut().for_each(|s| *s = false);
+        visit_next.clear();
+        // The free rows keep a zero potential, so all of them are at distance
+        // zero from a common source.
+        for i in (0..n).filter(|&i| row_mate[i].is_none()) {
+            distance[i] = Some(zero);
+            visit_next.push(MinScored(zero, i));
+        }
+
+        // Find the shortest path from a free row to a free column, alternating
+        // between unmatched and matched edges.
+        let mut free_column = None;
+        while let Some(MinScored(score, node)) = visit_next.pop() {
+            if settled[node] {
+                continue;
+            }
+            settled[node] = true;
+            if node < n {
+                let i = node;
+                for &(j, weight) in &adjacency[i] {
+                    if settled[n + j] {
+                        continue;
+                    }
+                    let reduced = cost(weight) + row_potential[i] - column_potential[j];
+                    let next_score = score + reduced;
+                    if distance[n + j].map_or(true, |d| next_score < d) {
+                        distance[n + j] = Some(next_score);
+                        predecessor[j] = i;
+                        visit_next.push(MinScored(next_score, n + j));
+                    }
+                }
+            } else {
+                let j = node - n;
+                match column_mate[j] {
+                    None => {
+                        free_column = Some((j, score));
+                        break;
+                    }
+                    // Matched edges have a zero reduced cost.
+                    Some(i) => {
+                        distance[i] = Some(score);
+                        visit_next.push(MinScored(score, i));
+                    }
+                }
+            }
+        }
+        // No augmenting path: the matching is maximum.
+        let (end, limit) = match free_column {
+            Some(end) => end,
+            None => break,
+        };
+
+        let potentials = row_potential.iter_mut().chain(column_potential.iter_mut());
+        for (node, potential) in potentials.enumerate() {
+            let d = match distance[node] {
+                Some(d) if settled[node] && d < limit => d,
+                _ => limit,
+            };
+            *potential = *potential + d;
+        }
+
+        let mut j = end;
+        loop {
+            let i = predecessor[j];
+            let next = row_mate[i];
+            row_mate[i] = Some(j);
+            column_mate[j] = Some(i);
+            match next {
+                Some(next) => j = next,
+                None => break,
+            }
+        }
+    }
+
+    let mut mate = vec![None; graph.node_bound()];
+    let mut n_edges = 0;
+    let mut total = zero;
+    for (i, j) in row_mate.iter().enumerate() {
+        if let Some(j) = *j {
+            let (a, b) = (left[i], right[j]);
+            mate[graph.to_index(a)] = Some(b);
+            mate[graph.to_index(b)] = Some(a);
+            n_edges += 1;
+            // The weight of the parallel edge with the least cost.
+            let weight = adjacency[i]
+                .iter()
+                .filter(|&&(k, _)| k == j)
+                .map(|&(_, weight)| weight)
+                .fold(None, |best: Option<K>, weight| match best {
+                    Some(best) if cost(best) <= cost(weight) => Some(best),
+                    _ => Some(weight),
+                });
+            if let Some(weight) = weight {
+                total = total + weight;
+            }
+        }
+    }
+    (Matching::new(graph, mate, n_edges), total)
+}
+
+/// The edges between the `left` and `right` nodes, in either direction, with
+/// the position of their right node, for each left node.
+fn bipartite_edges<G>(
+    graph: G,
+    left: &[G::NodeId],
+    right: &[G::NodeId],
+) -> Vec<Vec<(usize, G::EdgeRef)>>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let mut row = vec![None; graph.node_bound()];
+    let mut column = vec![None; graph.node_bound()];
+    for (i, &node) in left.iter().enumerate() {
+        row[graph.to_index(node)] = Some(i);
+    }
+    for (j, &node) in right.iter().enumerate() {
+        column[graph.to_index(node)] = Some(j);
+    }
+    let mut adjacency = vec![Vec::new(); left.len()];
+    for edge in graph.edge_references() {
+        let a = graph.to_index(edge.source());
+        let b = graph.to_index(edge.target());
+        let (i, j) = match (row[a], column[b], row[b], column[a]) {
+            (Some(i), Some(j), _, _) | (_, _, Some(i), Some(j)) => (i, j),
+            _ => continue,
+        };
+        adjacency[i].push((j, edge));
+    }
+    adjacency
+}
+
+/// \[Generic\] Compute a [*maximum
+/// matching*](https://en.wikipedia.org/wiki/Matching_(graph_theory)) of a
+/// bipartite graph using the
+/// [Hopcroft–Karp algorithm](https://en.wikipedia.org/wiki/Hopcroft%E2%80%93Karp_algorithm).
+///
+/// The graph is given with the two node sets `left` and `right` of its
+/// bipartition, which [`bipartition`][1] can compute. It is treated as if
+/// undirected, and only edges between the two sets are considered.
+///
+/// The algorithm runs in *O(|E|·√|V|)*, which is much faster than
+/// [`maximum_matching`][2] on large bipartite graphs.
+///
+/// [1]: ../fn.bipartition.html
+/// [2]: fn.maximum_matching.html
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::algo::bipartition;
+/// use petgraph::algo::matching::bipartite_maximum_matching;
+///
+/// // The example graph:
+/// //
+/// //  a ---- d
+/// //    \
+/// //     \
+/// //  b ---- e
+/// //    \
+/// //     \
+/// //  c      f
+/// //
+/// let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let e = graph.add_node(());
+/// let f = graph.add_node(());
+/// graph.extend_with_edges(&[(a, d), (a, e), (b, e), (b, f)]);
+///
+/// let (left, right) = bipartition(&graph).unwrap();
+/// let matching = bipartite_maximum_matching(&graph, left, right);
+/// assert_eq!(matching.len(), 2);
+/// assert_eq!(matching.mate(a), Some(d));
+/// assert!(!matching.contains_node(c));
+/// ```
+pub fn bipartite_maximum_matching<G, I, J>(graph: G, left: I, right: J) -> Matching<G>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    I: IntoIterator<Item = G::NodeId>,
+    J: IntoIterator<Item = G::NodeId>,
+{
+    const UNREACHED: usize = std::usize::MAX;
+
+    let left: Vec<_> = left.into_iter().collect();
+    let right: Vec<_> = right.into_iter().collect();
+    let adjacency: Vec<Vec<usize>> = bipartite_edges(graph, &left, &right)
+        .into_iter()
+        .map(|edges| edges.into_iter().map(|(j, _)| j).collect())
+        .collect();
+
+    let mut row_mate: Vec<Option<usize>> = vec![None; left.len()];
+    let mut column_mate: Vec<Option<usize>> = vec![None; right.len()];
+    let mut layer = vec![UNREACHED; left.len()];
+    let mut next_edge = vec![0; left.len()];
+    let mut queue = VecDeque::new();
+    loop {
+        // Split the rows in layers by their distance to a free row along
+        // alternating paths, up to the first layer with an edge to a free
+        // column, so that only shortest augmenting paths are used.
+        for (i, mate) in row_mate.iter().enumerate() {
+            if mate.is_none() {
+                layer[i] = 0;
+                queue.push_back(i);
+            } else {
+                layer[i] = UNREACHED;
+            }
+        }
+        let mut free_layer = UNREACHED;
+        while let Some(i) = queue.pop_front() {
+            if layer[i] >= free_layer {
+                queue.clear();
+                break;
+            }
+            for &j in &adjacency[i] {
+                match column_mate[j] {
+                    None => free_layer = layer[i],
+                    Some(k) if layer[k] == UNREACHED => {
+                        layer[k] = layer[i] + 1;
+                        queue.push_back(k);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if free_layer == UNREACHED {
+            break;
+        }
+
+        // Augment along a maximal set of disjoint shortest paths, found with
+        // depth-first searches that go one layer down at each step.
+        next_edge.iter_mut().for_each(|e| *e = 0);
+        for start in 0..left.len() {
+            if row_mate[start].is_some() {
+                continue;
+            }
+            let mut path = vec![start];
+            while let Some(&i) = path.last() {
+                if next_edge[i] == adjacency[i].len() {
+                    // A dead end.
+                    layer[i] = UNREACHED;
+                    path.pop();
+                    continue;
+                }
+                let j = adjacency[i][next_edge[i]];
+                next_edge[i] += 1;
+                match column_mate[j] {
+                    None if layer[i] == free_layer => {
+                        for &i in &path {
+                            let j = adjacency[i][next_edge[i] - 1];
+                            row_mate[i] = Some(j);
+                            column_mate[j] = Some(i);
+                        }
+                        break;
+                    }
+                    Some(k) if layer[k] == layer[i] + 1 => path.push(k),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let mut mate = vec![None; graph.node_bound()];
+    let mut n_edges = 0;
+    for (i, j) in row_mate.into_iter().enumerate() {
+        if let Some(j) = j {
+            mate[graph.to_index(left[i])] = Some(right[j]);
+            mate[graph.to_index(right[j])] = Some(left[i]);
+            n_edges += 1;
+        }
+    }
+    Matching::new(graph, mate, n_edges)
+}
+
+/// \[Generic\] Compute a [*minimum vertex
+/// cover*](https://en.wikipedia.org/wiki/Vertex_cover) of a bipartite graph
+/// from a maximum matching, using [Kőnig's
+/// theorem](https://en.wikipedia.org/wiki/K%C5%91nig%27s_theorem_(graph_theory)).
+///
+/// The graph is given with the two node sets `left` and `right` of its
+/// bipartition, like in [`bipartite_maximum_matching`][1], and `matching`
+/// must be a maximum matching between them. The cover has one node of each
+/// edge of the matching, and every edge between the two sets has an endpoint
+/// in the cover. The other nodes form a maximum independent set.
+///
+/// The cover is computed in *O(|V| + |E|)*.
+///
+/// [1]: fn.bipartite_maximum_matching.html
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::algo::matching::{bipartite_maximum_matching, bipartite_minimum_vertex_cover};
+///
+/// let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+/// let left = [graph.add_node(()), graph.add_node(()), graph.add_node(())];
+/// let right = [graph.add_node(()), graph.add_node(())];
+/// graph.extend_with_edges(&[
+///     (left[0], right[0]),
+///     (left[1], right[0]),
+///     (left[2], right[0]),
+///     (left[2], right[1]),
+/// ]);
+///
+/// let matching =
+///     bipartite_maximum_matching(&graph, left.iter().cloned(), right.iter().cloned());
+/// let mut cover =
+///     bipartite_minimum_vertex_cover(&graph, left.iter().cloned(), right.iter().cloned(), &matching);
+/// cover.sort();
+/// assert_eq!(cover, vec![left[2], right[0]]);
+/// ```
+pub fn bipartite_minimum_vertex_cover<G, I, J>(
+    graph: G,
+    left: I,
+    right: J,
+    matching: &Matching<G>,
+) -> Vec<G::NodeId>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    I: IntoIterator<Item = G::NodeId>,
+    J: IntoIterator<Item = G::NodeId>,
+{
+    let left: Vec<_> = left.into_iter().collect();
+    let right: Vec<_> = right.into_iter().collect();
+    let adjacency = bipartite_edges(graph, &left, &right);
+    let mut column = vec![None; graph.node_bound()];
+    for (j, &node) in right.iter().enumerate() {
+        column[graph.to_index(node)] = Some(j);
+    }
+    let mut row = vec![None; graph.node_bound()];
+    for (i, &node) in left.iter().enumerate() {
+        row[graph.to_index(node)] = Some(i);
+    }
+
+    // Find the nodes reachable from the free left nodes along alternating
+    // paths: the cover is made of the unreachable left nodes and the
+    // reachable right nodes.
+    let mut row_reached = vec![false; left.len()];
+    let mut column_reached = vec![false; right.len()];
+    let mut stack = Vec::new();
+    for (i, &node) in left.iter().enumerate() {
+        if !matching.contains_node(node) {
+            row_reached[i] = true;
+            stack.push(i);
+        }
+    }
+    while let Some(i) = stack.pop() {
+        for &(j, _) in &adjacency[i] {
+            if column_reached[j] {
+                continue;
+            }
+            column_reached[j] = true;
+            let mate = matching
+                .mate(right[j])
+                .and_then(|mate| row[graph.to_index(mate)]);
+            if let Some(k) = mate {
+                if !row_reached[k] {
+                    row_reached[k] = true;
+                    stack.push(k);
+                }
+            }
+        }
+    }
+
+    let rows = left
+        .iter()
+        .zip(row_reached)
+        .filter(|&(_, reached)| !reached);
+    let columns = right
+        .iter()
+        .zip(column_reached)
+        .filter(|&(_, reached)| reached);
+    rows.chain(columns).map(|(&node, _)| node).collect()
+}
+
+/// \[Generic\] Compute a [*maximum weight
+/// matching*](https://en.wikipedia.org/wiki/Maximum_weight_matching) using
+/// [Edmonds' weighted blossom algorithm][1].
+///
+/// [1]: https://doi.org/10.1145/6462.6502
+///
+/// The input graph is treated as if undirected, self-loops are ignored and
+/// among parallel edges the heaviest one is used. The function `edge_weight`
+/// should return the weight of a particular edge. Edges with a negative weight
+/// are never used, unless `max_cardinality` is `true`: the matching is then a
+/// maximum (cardinality) matching of greatest weight among the maximum
+/// matchings. In that mode the dual variables of the algorithm may become
+/// negative, so the weight type must be signed.
+///
+/// Integer weights are computed exactly. The algorithm runs in *O(|V|³)*,
+/// like the primal-dual method described by Galil.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::algo::maximum_weight_matching;
+///
+/// // The example graph:
+/// //
+/// //      1      3      1
+/// //  a ---- b ---- c ---- d
+/// //
+/// let mut graph: UnGraph<(), i32> = UnGraph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (b, c, 3), (c, d, 1)]);
+///
+/// // The heaviest matching has a single edge.
+/// let matching = maximum_weight_matching(&graph, false, |e| *e.weight());
+/// assert_eq!(matching.len(), 1);
+/// assert_eq!(matching.mate(b), Some(c));
+///
+/// // Unless every node must be matched if possible.
+/// let matching = maximum_weight_matching(&graph, true, |e| *e.weight());
+/// assert_eq!(matching.len(), 2);
+/// assert!(matching.contains_edge(a, b));
+/// assert!(matching.contains_edge(c, d));
+/// ```
+pub fn maximum_weight_matching<G, F, K>(
+    graph: G,
+    max_cardinality: bool,
+    mut edge_weight: F,
+) -> Matching<G>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<Output = K> + Div<Output = K> + From<u8>,
+{
+    // Keep the heaviest of parallel edges.
+    let mut edges: Vec<(usize, usize, K)> = Vec::new();
+    let mut edge_of_pair: HashMap<(usize, usize), usize> = HashMap::new();
+    for edge in graph.edge_references() {
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        if a == b {
+            continue;
+        }
+        let weight = edge_weight(edge);
+        let pair = if a < b { (a, b) } else { (b, a) };
+        match edge_of_pair.entry(pair) {
+            Entry::Occupied(entry) => {
+                let k = *entry.get();
+                if edges[k].2 < weight {
+                    edges[k].2 = weight;
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(edges.len());
+                edges.push((a, b, weight));
+            }
+        }
+    }
+
+    let mut blossom = WeightedBlossom::new(graph.node_bound(), edges);
+    blossom.run(max_cardinality);
+
+    let mut mate = vec![None; graph.node_bound()];
+    let mut n_edges = 0;
+    for (v, p) in blossom.mate.iter().enumerate() {
+        if let Some(p) = *p {
+            mate[v] = Some(graph.from_index(blossom.endpoint(p)));
+            n_edges += 1;
+        }
+    }
+    Matching::new(graph, mate, n_edges / 2)
+}
+
+// Labels of the vertices and blossoms in the alternating trees.
+const FREE: u8 = 0;
+const OUTER: u8 = 1;
+const INNER: u8 = 2;
+// Marks the blossoms visited while looking for a common ancestor.
+const BREADCRUMB: u8 = 4;
+
+/// The kind of dual update at the end of a search step.
+enum DualChange {
+    // Optimality is reached, or no augmenting path can be found.
+    Stop,
+    // The edge from an outer vertex to a free vertex becomes tight.
+    OuterToFree(usize),
+    // The edge between two outer blossoms becomes tight.
+    OuterToOuter(usize),
+    // The inner blossom has a zero dual and can be expanded.
+    Expand(usize),
+}
+
+/// The state of the weighted blossom algorithm.
+///
+/// Vertices are numbered `0..n`, and the blossoms `n..2n`. Edge `k` has the
+/// two endpoints `2k` and `2k + 1`, the first being its first vertex. The
+/// vertices are matched through endpoints, the mate of vertex `v` being the
+/// vertex of the endpoint `mate[v]`.
+struct WeightedBlossom<K> {
+    n: usize,
+    edges: Vec<(usize, usize, K)>,
+    // The remote endpoints of the edges of each vertex.
+    neighbour_endpoints: Vec<Vec<usize>>,
+    mate: Vec<Option<usize>>,
+    label: Vec<u8>,
+    // The endpoint through which a vertex or top-level blossom got its label.
+    label_end: Vec<Option<usize>>,
+    // The top-level blossom of each vertex.
+    in_blossom: Vec<usize>,
+    blossom_parent: Vec<Option<usize>>,
+    // The sub-blossoms of each blossom, in cycle order starting at the base,
+    // and the endpoints of the edges that connect them.
+    blossom_children: Vec<Vec<usize>>,
+    blossom_endpoints: Vec<Vec<usize>>,
+    blossom_base: Vec<Option<usize>>,
+    // The least slack edge to an outer blossom, for each free vertex and
+    // outer blossom.
+    best_edge: Vec<Option<usize>>,
+    // For each outer blossom, its least slack edges to other outer blossoms.
+    blossom_best_edges: Vec<Option<Vec<usize>>>,
+    unused_blossoms: Vec<usize>,
+    // The dual variables of the vertices, then of the blossoms. The slack of
+    // edge (i, j) is dual[i] + dual[j] - 2 weight(i, j) plus the duals of the
+    // blossoms that contain it.
+    dual: Vec<K>,
+    // Edges known to have a zero slack.
+    allowed: Vec<bool>,
+    // Outer vertices whose edges are not scanned yet.
+    queue: Vec<usize>,
+}
+
+impl<K> WeightedBlossom<K>
+where
+    K: Measure + Copy + Sub<Output = K> + Div<Output = K> + From<u8>,
+{
+    fn new(n: usize, edges: Vec<(usize, usize, K)>) -> Self {
+        let zero = K::default();
+        let max_weight = edges
+            .iter()
+            .map(|&(_, _, weight)| weight)
+            .fold(zero, |max, weight| if max < weight { weight } else { max });
+        let mut neighbour_endpoints = vec![Vec::new(); n];
+        for (k, &(i, j, _)) in edges.iter().enumerate() {
+            neighbour_endpoints[i].push(2 * k + 1);
+            neighbour_endpoints[j].push(2 * k);
+        }
+        let mut dual = vec![max_weight; n];
+        dual.resize(2 * n, zero);
+        let mut blossom_base: Vec<_> = (0..n).map(Some).collect();
+        blossom_base.resize(2 * n, None);
+        WeightedBlossom {
+            n,
+            neighbour_endpoints,
+            mate: vec![None; n],
+            label: vec![FREE; 2 * n],
+            label_end: vec![None; 2 * n],
+            in_blossom: (0..n).collect(),
+            blossom_parent: vec![None; 2 * n],
+            blossom_children: vec![Vec::new(); 2 * n],
+            blossom_endpoints: vec![Vec::new(); 2 * n],
+            blossom_base,
+            best_edge: vec![None; 2 * n],
+            blossom_best_edges: vec![None; 2 * n],
+            unused_blossoms: (n..2 * n).collect(),
+            dual,
+            allowed: vec![false; edges.len()],
+            queue: Vec::new(),
+            edges,
+        }
+    }
+
+    fn endpoint(&self, p: usize) -> usize {
+        let (i, j, _) = self.edges[p / 2];
+        if p % 2 == 0 {
+            i
+        } else {
+            j
+        }
+    }
+
+    fn slack(&self, k: usize) -> K {
+        let (i, j, weight) = self.edges[k];
+        self.dual[i] + self.dual[j] - weight - weight
+    }
+
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = Vec::new();
+        let mut stack = vec![b];
+        while let Some(b) = stack.pop() {
+            if b < self.n {
+                leaves.push(b);
+            } else {
+                stack.extend(self.blossom_children[b].iter().cloned());
+            }
+        }
+        leaves
+    }
+
+    /// Label the top-level blossom of vertex `w`, reached through endpoint
+    /// `p`. The mate of an inner blossom is labeled outer in turn.
+    fn assign_label(&mut self, mut w: usize, mut label: u8, mut p: Option<usize>) {
+        loop {
+            let b = self.in_blossom[w];
+            self.label[w] = label;
+            self.label[b] = label;
+            self.label_end[w] = p;
+            self.label_end[b] = p;
+            self.best_edge[w] = None;
+            self.best_edge[b] = None;
+            if label == OUTER {
+                let leaves = self.leaves(b);
+                self.queue.extend(leaves);
+                return;
+            }
+            let base = self.blossom_base[b].unwrap();
+            let mate = self.mate[base].unwrap();
+            w = self.endpoint(mate);
+            label = OUTER;
+            p = Some(mate ^ 1);
+        }
+    }
+
+    /// Trace back from outer vertices `v` and `w` to find the base of a new
+    /// blossom, or `None` if they are in different trees (an augmenting path).
+    fn scan_blossom(&mut self, v: usize, w: usize) -> Option<usize> {
+        let mut path = Vec::new();
+        let mut base = None;
+        let (mut v, mut w) = (Some(v), Some(w));
+        while let Some(u) = v {
+            let b = self.in_blossom[u];
+            if self.label[b] & BREADCRUMB != 0 {
+                base = self.blossom_base[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = OUTER | BREADCRUMB;
+            v = self.label_end[b].map(|p| {
+                let t = self.in_blossom[self.endpoint(p)];
+                self.endpoint(self.label_end[t].unwrap())
+            });
+            if w.is_some() {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = OUTER;
+        }
+        base
+    }
+
+    /// Make a new outer blossom with the given base, through edge `k` between
+    /// two outer vertices.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (v, w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+        let b = self.unused_blossoms.pop().unwrap();
+        self.blossom_base[b] = Some(base);
+        self.blossom_parent[b] = None;
+        self.blossom_parent[bb] = Some(b);
+
+        let mut children = Vec::new();
+        let mut endpoints = Vec::new();
+        while bv != bb {
+            self.blossom_parent[bv] = Some(b);
+            children.push(bv);
+            let p = self.label_end[bv].unwrap();
+            endpoints.push(p);
+            bv = self.in_blossom[self.endpoint(p)];
+        }
+        children.push(bb);
+        children.reverse();
+        endpoints.reverse();
+        endpoints.push(2 * k);
+        while bw != bb {
+            self.blossom_parent[bw] = Some(b);
+            children.push(bw);
+            let p = self.label_end[bw].unwrap();
+            endpoints.push(p ^ 1);
+            bw = self.in_blossom[self.endpoint(p)];
+        }
+        self.blossom_children[b] = children.clone();
+        self.blossom_endpoints[b] = endpoints;
+
+        self.label[b] = OUTER;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = K::default();
+        for v in self.leaves(b) {
+            if self.label[self.in_blossom[v]] == INNER {
+                // The inner vertices become outer.
+                self.queue.push(v);
+            }
+            self.in_blossom[v] = b;
+        }
+
+        // Merge the least slack edges to other outer blossoms.
+        let mut best_edge_to: Vec<Option<usize>> = vec![None; 2 * self.n];
+        for &bv in &children {
+            let candidates = match self.blossom_best_edges[bv].take() {
+                Some(candidates) => candidates,
+                None => self
+                    .leaves(bv)
+                    .iter()
+                    .flat_map(|&v| self.neighbour_endpoints[v].iter().map(|p| p / 2))
+                    .collect(),
+            };
+            for k in candidates {
+                let (i, j, _) = self.edges[k];
+                let j = if self.in_blossom[j] == b { i } else { j };
+                let bj = self.in_blossom[j];
+                if bj != b
+                    && self.label[bj] == OUTER
+                    && best_edge_to[bj].map_or(true, |e| self.slack(k) < self.slack(e))
+                {
+                    best_edge_to[bj] = Some(k);
+                }
+            }
+            self.best_edge[bv] = None;
+        }
+        let best_edges: Vec<usize> = best_edge_to.into_iter().flatten().collect();
+        self.best_edge[b] = None;
+        for &k in &best_edges {
+            if self.best_edge[b].map_or(true, |e| self.slack(k) < self.slack(e)) {
+                self.best_edge[b] = Some(k);
+            }
+        }
+        self.blossom_best_edges[b] = Some(best_edges);
+    }
+
+    /// Expand blossom `b`, recursively for the sub-blossoms with a zero dual
+    /// at the end of a stage.
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        let children = std::mem::take(&mut self.blossom_children[b]);
+        let endpoints = std::mem::take(&mut self.blossom_endpoints[b]);
+        for &s in &children {
+            self.blossom_parent[s] = None;
+            if s < self.n {
+                self.in_blossom[s] = s;
+            } else if end_stage && self.dual[s] == K::default() {
+                self.expand_blossom(s, end_stage);
+            } else {
+                for v in self.leaves(s) {
+                    self.in_blossom[v] = s;
+                }
+            }
+        }
+
+        if !end_stage && self.label[b] == INNER {
+            // Relabel the sub-blossoms on the even path from the entry child
+            // to the base, and the others that are reachable.
+            let len = children.len() as isize;
+            let at = |j: isize| j.rem_euclid(len) as usize;
+            let mut p = self.label_end[b].unwrap();
+            let entry_child = self.in_blossom[self.endpoint(p ^ 1)];
+            let mut j = children.iter().position(|&c| c == entry_child).unwrap() as isize;
+            // Go to the base along the even side of the cycle.
+            let (step, trick) = if j & 1 != 0 {
+                j -= len;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+            while j != 0 {
+                let q = endpoints[at(j - trick as isize)] ^ trick;
+                let (v, w) = (self.endpoint(p ^ 1), self.endpoint(q ^ 1));
+                self.label[v] = FREE;
+                self.label[w] = FREE;
+                self.assign_label(v, INNER, Some(p));
+                self.allowed[q / 2] = true;
+                j += step;
+                p = endpoints[at(j - trick as isize)] ^ trick;
+                self.allowed[p / 2] = true;
+                j += step;
+            }
+            let bv = children[at(j)];
+            let v = self.endpoint(p ^ 1);
+            self.label[v] = INNER;
+            self.label[bv] = INNER;
+            self.label_end[v] = Some(p);
+            self.label_end[bv] = Some(p);
+            self.best_edge[bv] = None;
+            j += step;
+            while children[at(j)] != entry_child {
+                let bv = children[at(j)];
+                j += step;
+                if self.label[bv] == OUTER {
+                    continue;
+                }
+                let labeled = self.leaves(bv).into_iter().find(|&v| self.label[v] != FREE);
+                if let Some(v) = labeled {
+                    self.label[v] = FREE;
+                    let mate = self.mate[self.blossom_base[bv].unwrap()].unwrap();
+                    let w = self.endpoint(mate);
+                    self.label[w] = FREE;
+                    let label_end = self.label_end[v];
+                    self.assign_label(v, INNER, label_end);
+                }
+            }
+        }
+
+        self.label[b] = FREE;
+        self.label_end[b] = None;
+        self.blossom_base[b] = None;
+        self.blossom_best_edges[b] = None;
+        self.best_edge[b] = None;
+        self.unused_blossoms.push(b);
+    }
+
+    /// Swap the matched and unmatched edges of blossom `b` along the path
+    /// from vertex `v` to the base, so that `v` becomes the base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.blossom_parent[t] != Some(b) {
+            t = self.blossom_parent[t].unwrap();
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+        let len = self.blossom_children[b].len() as isize;
+        let at = |j: isize| j.rem_euclid(len) as usize;
+        let i = self.blossom_children[b]
+            .iter()
+            .position(|&c| c == t)
+            .unwrap();
+        let mut j = i as isize;
+        let (step, trick) = if i & 1 != 0 {
+            j -= len;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+        while j != 0 {
+            j += step;
+            let t = self.blossom_children[b][at(j)];
+            let p = self.blossom_endpoints[b][at(j - trick as isize)] ^ trick;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint(p));
+            }
+            j += step;
+            let t = self.blossom_children[b][at(j)];
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint(p ^ 1));
+            }
+            let (v, w) = (self.endpoint(p), self.endpoint(p ^ 1));
+            self.mate[v] = Some(p ^ 1);
+            self.mate[w] = Some(p);
+        }
+        self.blossom_children[b].rotate_left(i);
+        self.blossom_endpoints[b].rotate_left(i);
+        self.blossom_base[b] = self.blossom_base[self.blossom_children[b][0]];
+        debug_assert_eq!(self.blossom_base[b], Some(v));
+    }
+
+    /// Augment the matching along the path through edge `k`, between the
+    /// roots of two trees.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for &(s, p) in &[(v, 2 * k + 1), (w, 2 * k)] {
+            let (mut s, mut p) = (s, p);
+            loop {
+                let bs = self.in_blossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = Some(p);
+                let label_end = match self.label_end[bs] {
+                    Some(label_end) => label_end,
+                    // Reached the root.
+                    None => break,
+                };
+                let bt = self.in_blossom[self.endpoint(label_end)];
+                let q = self.label_end[bt].unwrap();
+                s = self.endpoint(q);
+                let j = self.endpoint(q ^ 1);
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = Some(q);
+                p = q ^ 1;
+            }
+        }
+    }
+
+    fn run(&mut self, max_cardinality: bool) {
+        let zero = K::default();
+        let two = K::from(2);
+        let n = self.n;
+        // Each stage augments the matching by one edge, or ends the search.
+        for _ in 0..n {
+            self.label.iter_mut().for_each(|l| *l = FREE);
+            self.best_edge.iter_mut().for_each(|e| *e = None);
+            self.blossom_best_edges[n..]
+                .iter_mut()
+                .for_each(|e| *e = None);
+            self.allowed.iter_mut().for_each(|a| *a = false);
+            self.queue.clear();
+            for v in 0..n {
+                if self.mate[v].is_none() && self.label[self.in_blossom[v]] == FREE {
+                    self.assign_label(v, OUTER, None);
+                }
+            }
+
+            let mut augmented = false;
+            loop {
+                // Grow the trees along tight edges.
+                'scan: while let Some(v) = self.queue.pop() {
+                    for i in 0..self.neighbour_endpoints[v].len() {
+                        let p = self.neighbour_endpoints[v][i];
+                        let k = p / 2;
+                        let w = self.endpoint(p);
+                        if self.in_blossom[v] == self.in_blossom[w] {
+                            continue;
+                        }
+                        let mut slack = zero;
+                        if !self.allowed[k] {
+                            slack = self.slack(k);
+                            if slack <= zero {
+                                self.allowed[k] = true;
+                            }
+                        }
+                        let bw = self.in_blossom[w];
+                        if self.allowed[k] {
+                            if self.label[bw] == FREE {
+                                self.assign_label(w, INNER, Some(p ^ 1));
+                            } else if self.label[bw] == OUTER {
+                                match self.scan_blossom(v, w) {
+                                    Some(base) => self.add_blossom(base, k),
+                                    None => {
+                                        self.augment_matching(k);
+                                        augmented = true;
+                                        break 'scan;
+                                    }
+                                }
+                            } else if self.label[w] == FREE {
+                                // A vertex in an inner blossom.
+                                self.label[w] = INNER;
+                                self.label_end[w] = Some(p ^ 1);
+                            }
+                        } else if self.label[bw] == OUTER {
+                            let b = self.in_blossom[v];
+                            if self.best_edge[b].map_or(true, |e| slack < self.slack(e)) {
+                                self.best_edge[b] = Some(k);
+                            }
+                        } else if self.label[w] == FREE
+                            && self.best_edge[w].map_or(true, |e| slack < self.slack(e))
+                        {
+                            self.best_edge[w] = Some(k);
+                        }
+                    }
+                }
+                if augmented {
+                    break;
+                }
+
+                // Change the duals as much as possible while keeping them
+                // feasible, to make a new edge tight or a blossom expandable.
+                let min_vertex_dual = self.dual[..n]
+                    .iter()
+                    .fold(None, |min: Option<K>, &d| match min {
+                        Some(min) if min <= d => Some(min),
+                        _ => Some(d),
+                    })
+                    .unwrap_or(zero);
+                let mut change = None;
+                if !max_cardinality {
+                    change = Some((min_vertex_dual, DualChange::Stop));
+                }
+                let mut consider = |delta: K, kind| match change {
+                    Some((min, _)) if min <= delta => {}
+                    _ => change = Some((delta, kind)),
+                };
+                for v in 0..n {
+                    if self.label[self.in_blossom[v]] == FREE {
+                        if let Some(k) = self.best_edge[v] {
+                            consider(self.slack(k), DualChange::OuterToFree(k));
+                        }
+                    }
+                }
+                for b in 0..2 * n {
+                    if self.blossom_parent[b].is_none() && self.label[b] == OUTER {
+                        if let Some(k) = self.best_edge[b] {
+                            consider(self.slack(k) / two, DualChange::OuterToOuter(k));
+                        }
+                    }
+                }
+                for b in n..2 * n {
+                    if self.blossom_base[b].is_some()
+                        && self.blossom_parent[b].is_none()
+                        && self.label[b] == INNER
+                    {
+                        consider(self.dual[b], DualChange::Expand(b));
+                    }
+                }
+                let (delta, change) = change.unwrap_or_else(|| {
+                    // No augmenting path is left in maximum cardinality mode.
+                    let delta = if zero < min_vertex_dual {
+                        min_vertex_dual
+                    } else {
+                        zero
+                    };
+                    (delta, DualChange::Stop)
+                });
+
+                for v in 0..n {
+                    match self.label[self.in_blossom[v]] {
+                        OUTER => self.dual[v] = self.dual[v] - delta,
+                        INNER => self.dual[v] = self.dual[v] + delta,
+                        _ => {}
+                    }
+                }
+                for b in n..2 * n {
+                    if self.blossom_base[b].is_some() && self.blossom_parent[b].is_none() {
+                        match self.label[b] {
+                            OUTER => self.dual[b] = self.dual[b] + delta,
+                            INNER => self.dual[b] = self.dual[b] - delta,
+                            _ => {}
+                        }
+                    }
+                }
+
+                match change {
+                    DualChange::Stop => break,
+                    DualChange::OuterToFree(k) => {
+                        self.allowed[k] = true;
+                        let (i, j, _) = self.edges[k];
+                        let i = if self.label[self.in_blossom[i]] == FREE {
+                            j
+                        } else {
+                            i
+                        };
+                        self.queue.push(i);
+                    }
+                    DualChange::OuterToOuter(k) => {
+                        self.allowed[k] = true;
+                        self.queue.push(self.edges[k].0);
+                    }
+                    DualChange::Expand(b) => self.expand_blossom(b, false),
+                }
+            }
+
+            if !augmented {
+                break;
+            }
+            // Expand the outer blossoms with a zero dual.
+            for b in n..2 * n {
+                if self.blossom_parent[b].is_none()
+                    && self.blossom_base[b].is_some()
+                    && self.label[b] == OUTER
+                    && self.dual[b] == zero
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+    }
+}
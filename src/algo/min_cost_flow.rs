@@ -0,0 +1,209 @@
+//! Minimum cost flow.
+
+use std::ops::Mul;
+
+use crate::graph::{EdgeReference, Graph, NodeIndex};
+use crate::visit::{EdgeFiltered, EdgeIndexable, EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+use super::{dijkstra_paths, spfa, BoundedMeasure, NegativeCycle};
+
+/// \[Generic\] Minimum cost maximum flow.
+///
+/// Computes a [maximum flow][mcf] from `source` to `destination` of least
+/// total cost in a directed graph, where the function `capacity` returns
+/// the capacity of an edge, and the function `cost` its cost per unit of
+/// flow. Edges with no positive capacity are ignored.
+///
+/// The successive shortest path algorithm is used: the flow is augmented
+/// along cheapest paths in the residual network. Node potentials make the
+/// reduced costs non-negative, so that the paths are found with
+/// [`dijkstra_paths`][dijkstra]; the initial potentials are computed with
+/// [`spfa`][spfa], so edge costs may be negative. [`spfa`][spfa] is used
+/// rather than `bellman_ford`, which needs a `FloatMeasure`, so that costs
+/// may be integers too, signed or not. The graph must not have a cycle of
+/// negative cost reachable from `source` (in that case it will return an
+/// error).
+///
+/// Returns the total flow, the total cost, and the computed edge flows,
+/// indexed by edge index like in [`ford_fulkerson`][ff].
+///
+/// Computes in **O(|V|·|E| + F·|E|·log(|V|))** time, where **F** is the
+/// number of augmenting paths, which is at most the total flow for
+/// integral capacities.
+///
+/// [mcf]: https://en.wikipedia.org/wiki/Minimum-cost_flow_problem
+/// [dijkstra]: ../dijkstra/fn.dijkstra_paths.html
+/// [spfa]: ../spfa/fn.spfa.html
+/// [ff]: ../ford_fulkerson/fn.ford_fulkerson.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::min_cost_flow;
+///
+/// // Edges weights are (capacity, cost).
+/// let mut graph = Graph::<(), (f64, f64)>::new();
+/// let s = graph.add_node(());
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let t = graph.add_node(());
+/// graph.extend_with_edges(&[
+///     (s, a, (2., 1.)),
+///     (s, b, (2., 4.)),
+///     (a, b, (1., 1.)),
+///     (a, t, (1., 5.)),
+///     (b, t, (3., 1.)),
+/// ]);
+/// //      (2, 1)       (1, 5)
+/// //   s -------> a ---------> t
+/// //   |          | (1, 1)     ^
+/// //   |          v            |
+/// //   +--------> b -----------+
+/// //      (2, 4)       (3, 1)
+///
+/// let (flow, cost, flows) =
+///     min_cost_flow(&graph, s, t, |e| e.weight().0, |e| e.weight().1).unwrap();
+/// assert_eq!(flow, 4.);
+/// // s -> a -> b -> t, s -> a -> t and twice s -> b -> t
+/// assert_eq!(cost, 3. + 6. + 2. * 5.);
+/// assert_eq!(flows, vec![2., 2., 1., 1., 3.]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn min_cost_flow<N, C, W, K>(
+    network: N,
+    source: N::NodeId,
+    destination: N::NodeId,
+    mut capacity: C,
+    mut cost: W,
+) -> Result<(K, K, Vec<K>), NegativeCycle>
+where
+    N: IntoEdgeReferences + NodeIndexable + EdgeIndexable,
+    C: FnMut(N::EdgeRef) -> K,
+    W: FnMut(N::EdgeRef) -> K,
+    K: BoundedMeasure + Copy + Mul<Output = K>,
+{
+    let ix = |i| NodeIndexable::to_index(&network, i);
+    let zero = K::default();
+
+    let mut capacities = vec![zero; network.edge_bound()];
+    let mut costs = vec![zero; network.edge_bound()];
+    let mut flows = vec![zero; network.edge_bound()];
+    let mut total_flow = zero;
+    let mut total_cost = zero;
+    if source == destination {
+        return Ok((total_flow, total_cost, flows));
+    }
+
+    // The residual network has an arc in each direction for every edge, with
+    // the edge index and whether the arc is forward as weight. Only the arcs
+    // with residual capacity are followed.
+    let mut residual = Graph::<(), (usize, bool)>::with_capacity(network.node_bound(), 0);
+    // The initial potentials are the costs of the cheapest paths from the
+    // source.
+    let mut cost_graph = Graph::<(), K>::with_capacity(network.node_bound(), 0);
+    for _ in 0..network.node_bound() {
+        residual.add_node(());
+        cost_graph.add_node(());
+    }
+    for edge in network.edge_references() {
+        let e = EdgeIndexable::to_index(&network, edge.id());
+        capacities[e] = capacity(edge);
+        costs[e] = cost(edge);
+        if capacities[e] > zero {
+            let (a, b) = (
+                NodeIndex::new(ix(edge.source())),
+                NodeIndex::new(ix(edge.target())),
+            );
+            residual.add_edge(a, b, (e, true));
+            residual.add_edge(b, a, (e, false));
+            cost_graph.add_edge(a, b, costs[e]);
+        }
+    }
+    let source = NodeIndex::new(ix(source));
+    let destination = NodeIndex::new(ix(destination));
+    // The nodes that are not reachable from the source stay so in the
+    // residual network, so their potentials do not matter.
+    let mut potentials: Vec<K> = spfa(&cost_graph, source, |e| *e.weight())?
+        .distances
+        .into_iter()
+        .map(|distance| distance.unwrap_or(zero))
+        .collect();
+
+    let residual_capacity = |flows: &[K], arc: EdgeReference<(usize, bool)>| {
+        let (e, forward) = *arc.weight();
+        if forward {
+            capacities[e] - flows[e]
+        } else {
+            flows[e]
+        }
+    };
+
+    loop {
+        let augmenting_path = {
+            let flows = &flows;
+            let potentials = &potentials;
+            let network =
+                EdgeFiltered::from_fn(&residual, |arc| residual_capacity(flows, arc) > zero);
+            // The cost of a backward arc is the negated cost of its edge, so
+            // it is moved to the other side to not negate an unsigned cost.
+            let reduced_cost = |arc: EdgeReference<(usize, bool)>| {
+                let (e, forward) = *arc.weight();
+                let (from, to) = (
+                    potentials[arc.source().index()],
+                    potentials[arc.target().index()],
+                );
+                let (plus, minus) = if forward {
+                    (costs[e] + from, to)
+                } else {
+                    (from, costs[e] + to)
+                };
+                // Rounding errors may make it slightly negative.
+                if plus < minus {
+                    zero
+                } else {
+                    plus - minus
+                }
+            };
+            let paths = dijkstra_paths(&network, source, Some(destination), reduced_cost);
+            paths
+                .edges_to(&network, source, destination, reduced_cost)
+                .map(|arcs| (arcs, paths.distances))
+        };
+        let (arcs, distances) = match augmenting_path {
+            Some(path) => path,
+            None => break,
+        };
+
+        let path_flow = arcs
+            .iter()
+            .map(|&arc| residual_capacity(&flows, arc))
+            .fold(K::max(), |min, r| if r < min { r } else { min });
+        for &arc in &arcs {
+            // The total cost stays the sum of the flows times the costs of
+            // the edges, so it does not underflow for unsigned costs.
+            let (e, forward) = *arc.weight();
+            if forward {
+                flows[e] = flows[e] + path_flow;
+                total_cost = total_cost + path_flow * costs[e];
+            } else {
+                flows[e] = flows[e] - path_flow;
+                total_cost = total_cost - path_flow * costs[e];
+            }
+        }
+        total_flow = total_flow + path_flow;
+
+        // The search stopped at the destination, so only the nodes that were
+        // reached before it have their distance; the others are at least
+        // as far.
+        let limit = distances[destination.index()].unwrap();
+        for (potential, &distance) in potentials.iter_mut().zip(&distances) {
+            let distance = match distance {
+                Some(distance) if distance < limit => distance,
+                _ => limit,
+            };
+            *potential = *potential + distance;
+        }
+    }
+
+    Ok((total_flow, total_cost, flows))
+}
@@ -1,14 +1,20 @@
 //! Minimum Spanning Tree algorithms.
 
+use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
+use std::ops::Sub;
 
 use crate::prelude::*;
 
+use crate::algo::{Measure, Unreachable};
 use crate::data::Element;
 use crate::scored::MinScored;
 use crate::unionfind::UnionFind;
 use crate::visit::{Data, IntoNodeReferences, NodeRef};
-use crate::visit::{IntoEdgeReferences, NodeIndexable};
+use crate::visit::{IntoEdgeReferences, IntoEdges, NodeIndexable, VisitMap, Visitable};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 /// \[Generic\] Compute a *minimum spanning tree* of a graph.
 ///
@@ -115,3 +121,685 @@ where
         None
     }
 }
+
+/// \[Generic\] Compute a *minimum spanning tree* of the connected component
+/// of `root`, using Prim's algorithm.
+///
+/// The tree grows from `root` along the cheapest edge to a new node, so the
+/// edges of the node are only looked at when it joins the tree. This avoids
+/// sorting all the edges up front, which is wasteful on dense graphs. The
+/// runtime is **O(|E| log |E|)**.
+///
+/// The edges are followed with [`edges`][1], so the input graph should be
+/// undirected: on a directed graph only the outgoing edges are used.
+///
+/// The resulting graph has all the vertices of the input graph (with
+/// identical node indices), and the **k - 1** edges of the tree, where **k**
+/// is the number of nodes in the component of `root`.
+///
+/// Use `from_elements` to create a graph from the resulting iterator.
+///
+/// [1]: ../../visit/trait.IntoEdges.html#tymethod.edges
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::min_spanning_tree_prim;
+/// use petgraph::data::FromElements;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let graph = UnGraph::<(), u32>::from_edges(&[(0, 1, 4), (1, 2, 1), (0, 2, 2), (3, 4, 1)]);
+/// //     4
+/// // 0 ----- 1      3
+/// //  \      |      |
+/// //   \ 2   | 1    | 1
+/// //    \    |      |
+/// //     \-- 2      4
+///
+/// let tree = UnGraph::<(), u32>::from_elements(min_spanning_tree_prim(&graph, NodeIndex::new(0)));
+/// assert_eq!(tree.node_count(), 5);
+/// assert_eq!(tree.edge_count(), 2);
+/// assert!(tree.find_edge(NodeIndex::new(0), NodeIndex::new(2)).is_some());
+/// assert!(tree.find_edge(NodeIndex::new(1), NodeIndex::new(2)).is_some());
+/// ```
+pub fn min_spanning_tree_prim<G>(g: G, root: G::NodeId) -> MinSpanningTreePrim<G>
+where
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone + PartialOrd,
+    G: IntoNodeReferences + IntoEdges + NodeIndexable + Visitable,
+{
+    MinSpanningTreePrim {
+        graph: g,
+        node_ids: Some(g.node_references()),
+        root: Some(root),
+        visited: g.visit_map(),
+        best_weights: vec![None; g.node_bound()],
+        sort_edges: BinaryHeap::new(),
+        node_map: HashMap::new(),
+        node_count: 0,
+    }
+}
+
+/// An iterator producing a minimum spanning tree of a graph, with Prim's
+/// algorithm.
+#[derive(Debug, Clone)]
+pub struct MinSpanningTreePrim<G>
+where
+    G: Data + IntoNodeReferences + Visitable,
+{
+    graph: G,
+    node_ids: Option<G::NodeReferences>,
+    root: Option<G::NodeId>,
+    visited: G::Map,
+    // The weight of the cheapest candidate edge to each node.
+    best_weights: Vec<Option<G::EdgeWeight>>,
+    #[allow(clippy::type_complexity)]
+    sort_edges: BinaryHeap<MinScored<G::EdgeWeight, (G::NodeId, G::NodeId)>>,
+    node_map: HashMap<usize, usize>,
+    node_count: usize,
+}
+
+impl<G> MinSpanningTreePrim<G>
+where
+    G: IntoNodeReferences + IntoEdges + NodeIndexable + Visitable,
+    G::EdgeWeight: Clone + PartialOrd,
+{
+    /// Add `node` to the tree, and its edges to the candidates if they are
+    /// cheaper than the previous ones to the same node.
+    fn visit(&mut self, node: G::NodeId) {
+        self.visited.visit(node);
+        for edge in self.graph.edges(node) {
+            let (a, b) = (edge.source(), edge.target());
+            let next = if self.visited.is_visited(&b) { a } else { b };
+            if self.visited.is_visited(&next) {
+                continue;
+            }
+            let best_weight = &mut self.best_weights[self.graph.to_index(next)];
+            if best_weight.as_ref().map_or(true, |w| edge.weight() < w) {
+                *best_weight = Some(edge.weight().clone());
+                self.sort_edges
+                    .push(MinScored(edge.weight().clone(), (a, b)));
+            }
+        }
+    }
+}
+
+impl<G> Iterator for MinSpanningTreePrim<G>
+where
+    G: IntoNodeReferences + IntoEdges + NodeIndexable + Visitable,
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone + PartialOrd,
+{
+    type Item = Element<G::NodeWeight, G::EdgeWeight>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let g = self.graph;
+        if let Some(ref mut iter) = self.node_ids {
+            if let Some(node) = iter.next() {
+                self.node_map.insert(g.to_index(node.id()), self.node_count);
+                self.node_count += 1;
+                return Some(Element::Node {
+                    weight: node.weight().clone(),
+                });
+            }
+        }
+        self.node_ids = None;
+        if let Some(root) = self.root.take() {
+            self.visit(root);
+        }
+
+        // Prim's algorithm: add the cheapest edge from the tree to a new node.
+        while let Some(MinScored(score, (a, b))) = self.sort_edges.pop() {
+            let next = match (self.visited.is_visited(&a), self.visited.is_visited(&b)) {
+                (true, false) => b,
+                (false, true) => a,
+                _ => continue,
+            };
+            self.visit(next);
+            let (&a_order, &b_order) = match (
+                self.node_map.get(&g.to_index(a)),
+                self.node_map.get(&g.to_index(b)),
+            ) {
+                (Some(a_id), Some(b_id)) => (a_id, b_id),
+                _ => panic!("Edge references unknown node"),
+            };
+            return Some(Element::Edge {
+                source: a_order,
+                target: b_order,
+                weight: score,
+            });
+        }
+        None
+    }
+}
+
+/// \[Generic\] Compute a *minimum spanning tree* of a graph, using
+/// Borůvka's algorithm.
+///
+/// The input graph is treated as if undirected.
+///
+/// In each round, every tree of the forest picks its cheapest outgoing edge,
+/// and the trees are merged along them, with runtime **O(|E| log |V|)**. Like
+/// [`min_spanning_tree`][1], it returns a minimum spanning forest, with all
+/// the vertices of the input graph (with identical node indices), and
+/// **|V| - c** edges, where **c** is the number of connected components.
+/// Edges of equal weight are picked by their order in the graph.
+///
+/// Use `from_elements` to create a graph from the resulting iterator.
+///
+/// [1]: fn.min_spanning_tree.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{min_spanning_tree, min_spanning_tree_boruvka};
+/// use petgraph::data::FromElements;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph = UnGraph::<(), u32>::from_edges(&[(0, 1, 4), (1, 2, 1), (0, 2, 2), (3, 4, 1)]);
+/// let forest = UnGraph::<(), u32>::from_elements(min_spanning_tree_boruvka(&graph));
+/// assert_eq!(forest.node_count(), 5);
+/// assert_eq!(forest.edge_count(), 3);
+/// let weight: u32 = forest.edge_weights().sum();
+/// assert_eq!(weight, 4);
+/// ```
+pub fn min_spanning_tree_boruvka<G>(g: G) -> MinSpanningForest<G::NodeWeight, G::EdgeWeight>
+where
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone + PartialOrd,
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable,
+{
+    boruvka(g, |edges, component, cheapest| {
+        for (k, &(a, b, ref weight)) in edges.iter().enumerate() {
+            let (a, b) = (component[a], component[b]);
+            if a != b {
+                for &c in &[a, b] {
+                    if is_cheaper(edges, k, weight, cheapest[c]) {
+                        cheapest[c] = Some(k);
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// \[Generic\] Compute a *minimum spanning tree* of a graph, using a
+/// parallel Borůvka's algorithm.
+///
+/// The cheapest edges of the trees are looked for in parallel.
+/// See [`min_spanning_tree_boruvka`][1].
+///
+/// [1]: fn.min_spanning_tree_boruvka.html
+#[cfg(feature = "rayon")]
+pub fn parallel_min_spanning_tree_boruvka<G>(
+    g: G,
+) -> MinSpanningForest<G::NodeWeight, G::EdgeWeight>
+where
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone + PartialOrd + Send + Sync,
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable,
+{
+    boruvka(g, |edges, component, cheapest| {
+        let merge = |mut cheapest: Vec<Option<usize>>, other: Vec<Option<usize>>| {
+            for (c, k) in other.into_iter().enumerate() {
+                if let Some(k) = k {
+                    if is_cheaper(edges, k, &edges[k].2, cheapest[c]) {
+                        cheapest[c] = Some(k);
+                    }
+                }
+            }
+            cheapest
+        };
+        // Each chunk of edges is scanned into its own cheapest edges.
+        const CHUNK_SIZE: usize = 1 << 12;
+        let found = edges
+            .par_chunks(CHUNK_SIZE)
+            .enumerate()
+            .map(|(chunk, chunk_edges)| {
+                let mut cheapest = vec![None; component.len()];
+                for (k, &(a, b, ref weight)) in chunk_edges.iter().enumerate() {
+                    let k = chunk * CHUNK_SIZE + k;
+                    let (a, b) = (component[a], component[b]);
+                    if a != b {
+                        for &c in &[a, b] {
+                            if is_cheaper(edges, k, weight, cheapest[c]) {
+                                cheapest[c] = Some(k);
+                            }
+                        }
+                    }
+                }
+                cheapest
+            })
+            .reduce(|| vec![None; component.len()], merge);
+        cheapest.copy_from_slice(&found);
+    })
+}
+
+/// Return `true` if edge `k` with the given weight is cheaper than edge
+/// `other`, ties being broken by the edge order so that no cycle is formed.
+fn is_cheaper<N, E: PartialOrd>(
+    edges: &[(N, N, E)],
+    k: usize,
+    weight: &E,
+    other: Option<usize>,
+) -> bool {
+    match other {
+        None => true,
+        Some(other) => match weight.partial_cmp(&edges[other].2) {
+            Some(Ordering::Less) => true,
+            Some(Ordering::Equal) => k < other,
+            _ => false,
+        },
+    }
+}
+
+/// Borůvka's algorithm, where `find_cheapest` fills the cheapest edge of each
+/// tree, given the edges and the tree of each node.
+fn boruvka<G, F>(g: G, mut find_cheapest: F) -> MinSpanningForest<G::NodeWeight, G::EdgeWeight>
+where
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone + PartialOrd,
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable,
+    F: FnMut(&[(usize, usize, G::EdgeWeight)], &[usize], &mut [Option<usize>]),
+{
+    let mut node_map = vec![0; g.node_bound()];
+    let mut elements = Vec::new();
+    for (order, node) in g.node_references().enumerate() {
+        node_map[g.to_index(node.id())] = order;
+        elements.push(Element::Node {
+            weight: node.weight().clone(),
+        });
+    }
+    let edges: Vec<_> = g
+        .edge_references()
+        .map(|edge| {
+            (
+                g.to_index(edge.source()),
+                g.to_index(edge.target()),
+                edge.weight().clone(),
+            )
+        })
+        .collect();
+
+    let mut subgraphs = UnionFind::new(g.node_bound());
+    let mut component = vec![0; g.node_bound()];
+    let mut cheapest = vec![None; g.node_bound()];
+    loop {
+        for (node, c) in component.iter_mut().enumerate() {
+            *c = subgraphs.find_mut(node);
+        }
+        cheapest.iter_mut().for_each(|k| *k = None);
+        find_cheapest(&edges, &component, &mut cheapest);
+
+        let mut merged = false;
+        for &k in cheapest.iter().flatten() {
+            let (a, b, ref weight) = edges[k];
+            if subgraphs.union(a, b) {
+                merged = true;
+                elements.push(Element::Edge {
+                    source: node_map[a],
+                    target: node_map[b],
+                    weight: weight.clone(),
+                });
+            }
+        }
+        if !merged {
+            break;
+        }
+    }
+
+    MinSpanningForest {
+        elements: elements.into_iter(),
+    }
+}
+
+/// An iterator producing a precomputed minimum spanning forest of a graph,
+/// with Borůvka's algorithm or as a minimum spanning arborescence.
+#[derive(Debug, Clone)]
+pub struct MinSpanningForest<N, E> {
+    elements: std::vec::IntoIter<Element<N, E>>,
+}
+
+impl<N, E> Iterator for MinSpanningForest<N, E> {
+    type Item = Element<N, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.elements.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+/// \[Generic\] Compute a *minimum spanning arborescence* of a directed graph,
+/// using the Chu–Liu/Edmonds algorithm.
+///
+/// An arborescence is a directed spanning tree: every node but the root has
+/// exactly one incoming edge, and can be reached from the root. Edge weights
+/// may be negative, and the direction of the edges is taken into account,
+/// unlike in [`min_spanning_tree`][1]. Self-loops are ignored.
+///
+/// With `Some(root)`, the arborescence grows from `root`, or an error names
+/// the nodes that can not be reached from it. With `None`, a *minimum
+/// branching* is returned: a forest of arborescences of least total weight,
+/// with any number of roots. Its edges all have a negative weight, if any,
+/// so it is empty when no weight is negative.
+///
+/// The runtime is **O(|V|·|E|)**.
+///
+/// The resulting graph has all the vertices of the input graph (with
+/// identical node indices), and the edges of the arborescence in their
+/// original direction.
+///
+/// Use `from_elements` to create a graph from the resulting iterator.
+///
+/// [1]: fn.min_spanning_tree.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::min_spanning_arborescence;
+/// use petgraph::data::FromElements;
+/// use petgraph::graph::{DiGraph, NodeIndex};
+///
+/// let graph = DiGraph::<(), i32>::from_edges(&[
+///     (0, 1, 5),
+///     (0, 2, 1),
+///     (2, 1, 1),
+///     (1, 3, 3),
+///     (3, 2, -2),
+///     (2, 3, 4),
+/// ]);
+/// //       5
+/// //  0 ------> 1
+/// //  |       ^ |
+/// //  | 1  1 /  | 3
+/// //  v     /   v
+/// //  2 <=====> 3
+/// //
+/// // 3 -> 2 has weight -2, and 2 -> 3 has weight 4.
+///
+/// let n = NodeIndex::new;
+/// let tree = DiGraph::<(), i32>::from_elements(min_spanning_arborescence(&graph, Some(n(0))).unwrap());
+/// assert_eq!(tree.edge_count(), 3);
+/// assert!(tree.find_edge(n(0), n(2)).is_some());
+/// assert!(tree.find_edge(n(2), n(1)).is_some());
+/// assert!(tree.find_edge(n(1), n(3)).is_some());
+///
+/// // Node 0 can not be reached from node 1.
+/// let error = min_spanning_arborescence(&graph, Some(n(1))).unwrap_err();
+/// assert_eq!(error.nodes(), &[n(0)]);
+///
+/// // Without a root, only the edges that lower the weight are kept.
+/// let branching = DiGraph::<(), i32>::from_elements(min_spanning_arborescence(&graph, None).unwrap());
+/// assert_eq!(branching.edge_count(), 1);
+/// assert!(branching.find_edge(n(3), n(2)).is_some());
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn min_spanning_arborescence<G>(
+    g: G,
+    root: Option<G::NodeId>,
+) -> Result<MinSpanningForest<G::NodeWeight, G::EdgeWeight>, Unreachable<G::NodeId>>
+where
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Measure + Copy + Sub<Output = G::EdgeWeight>,
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable,
+{
+    let mut node_map = vec![0; g.node_bound()];
+    let mut elements = Vec::new();
+    let mut node_ids = Vec::new();
+    for (order, node) in g.node_references().enumerate() {
+        node_map[g.to_index(node.id())] = order;
+        node_ids.push(node.id());
+        elements.push(Element::Node {
+            weight: node.weight().clone(),
+        });
+    }
+    let node_count = node_ids.len();
+    let weights: Vec<_> = g.edge_references().map(|edge| *edge.weight()).collect();
+    let mut edges: Vec<_> = g
+        .edge_references()
+        .map(|edge| {
+            (
+                node_map[g.to_index(edge.source())],
+                node_map[g.to_index(edge.target())],
+                ArborescenceWeight::new(*edge.weight()),
+            )
+        })
+        .collect();
+
+    let (root, total_count) = match root {
+        Some(root) => {
+            let root = node_map[g.to_index(root)];
+            let mut neighbors = vec![Vec::new(); node_count];
+            for &(a, b, _) in &edges {
+                neighbors[a].push(b);
+            }
+            let mut reached = vec![false; node_count];
+            reached[root] = true;
+            let mut stack = vec![root];
+            while let Some(a) = stack.pop() {
+                for &b in &neighbors[a] {
+                    if !reached[b] {
+                        reached[b] = true;
+                        stack.push(b);
+                    }
+                }
+            }
+            if reached.iter().any(|&r| !r) {
+                let unreachable = node_ids
+                    .into_iter()
+                    .zip(reached)
+                    .filter(|&(_, r)| !r)
+                    .map(|(node, _)| node)
+                    .collect();
+                return Err(Unreachable(unreachable));
+            }
+            (root, node_count)
+        }
+        None => {
+            // A virtual root with an edge of weight zero to every node: the
+            // real edges of an arborescence from it form a branching of the
+            // same weight.
+            for node in 0..node_count {
+                edges.push((
+                    node_count,
+                    node,
+                    ArborescenceWeight::new(G::EdgeWeight::default()),
+                ));
+            }
+            (node_count, node_count + 1)
+        }
+    };
+
+    let mut chosen = edmonds(total_count, root, &edges);
+    // Drop the virtual root edges.
+    chosen.retain(|&k| k < weights.len());
+    chosen.sort_unstable();
+    for k in chosen {
+        let (a, b, _) = edges[k];
+        elements.push(Element::Edge {
+            source: a,
+            target: b,
+            weight: weights[k],
+        });
+    }
+
+    Ok(MinSpanningForest {
+        elements: elements.into_iter(),
+    })
+}
+
+/// The weight of an edge for the Chu–Liu/Edmonds algorithm, kept as a sign
+/// and a magnitude so that it may become negative.
+#[derive(Clone, Copy, Debug)]
+struct ArborescenceWeight<K> {
+    positive: K,
+    negative: K,
+}
+
+impl<K> ArborescenceWeight<K>
+where
+    K: Measure + Copy + Sub<Output = K>,
+{
+    fn new(weight: K) -> Self {
+        ArborescenceWeight {
+            positive: weight,
+            negative: K::default(),
+        }
+        .normalized()
+    }
+
+    fn normalized(self) -> Self {
+        let (positive, negative) = if self.positive < self.negative {
+            (K::default(), self.negative - self.positive)
+        } else {
+            (self.positive - self.negative, K::default())
+        };
+        ArborescenceWeight { positive, negative }
+    }
+
+    fn minus(self, other: Self) -> Self {
+        ArborescenceWeight {
+            positive: self.positive + other.negative,
+            negative: self.negative + other.positive,
+        }
+        .normalized()
+    }
+
+    fn less_than(&self, other: &Self) -> bool {
+        self.positive + other.negative < other.positive + self.negative
+    }
+}
+
+/// A cycle contraction of the Chu–Liu/Edmonds algorithm, kept to expand the
+/// cycles once the contracted graph is solved.
+struct Contraction {
+    /// The target of each edge before the contraction.
+    targets: Vec<usize>,
+    /// The cheapest incoming edge of each node before the contraction.
+    incoming: Vec<Option<usize>>,
+    /// The cycle of each node before the contraction, if any.
+    cycle_of: Vec<Option<usize>>,
+    cycle_count: usize,
+    /// The edge before the contraction of each contracted edge.
+    origin: Vec<usize>,
+}
+
+/// The Chu–Liu/Edmonds algorithm on `node_count` nodes where every node can be
+/// reached from `root`. Returns the indices of the edges of a minimum
+/// arborescence.
+///
+/// Cycles are contracted until there are none left, and the contractions are
+/// then expanded in reverse order; there may be **O(|V|)** of them, so they
+/// are kept on an explicit stack.
+fn edmonds<K>(
+    mut node_count: usize,
+    mut root: usize,
+    edges: &[(usize, usize, ArborescenceWeight<K>)],
+) -> Vec<usize>
+where
+    K: Measure + Copy + Sub<Output = K>,
+{
+    let mut edges = edges.to_vec();
+    let mut contractions = Vec::new();
+    let mut chosen: Vec<usize> = loop {
+        // The cheapest incoming edge of each node.
+        let mut incoming: Vec<Option<usize>> = vec![None; node_count];
+        for (k, &(a, b, ref weight)) in edges.iter().enumerate() {
+            if a != b
+                && b != root
+                && incoming[b].map_or(true, |other| weight.less_than(&edges[other].2))
+            {
+                incoming[b] = Some(k);
+            }
+        }
+        let parent = |node: usize| edges[incoming[node].unwrap()].0;
+
+        // Find the cycles of the cheapest incoming edges.
+        let mut cycle_of: Vec<Option<usize>> = vec![None; node_count];
+        let mut walk_of = vec![None; node_count];
+        let mut cycle_count = 0;
+        for start in 0..node_count {
+            let mut node = start;
+            while node != root && walk_of[node].is_none() && incoming[node].is_some() {
+                walk_of[node] = Some(start);
+                node = parent(node);
+            }
+            if node != root && walk_of[node] == Some(start) && cycle_of[node].is_none() {
+                let mut cycle_node = node;
+                loop {
+                    cycle_of[cycle_node] = Some(cycle_count);
+                    cycle_node = parent(cycle_node);
+                    if cycle_node == node {
+                        break;
+                    }
+                }
+                cycle_count += 1;
+            }
+        }
+        if cycle_count == 0 {
+            break incoming.into_iter().flatten().collect();
+        }
+
+        // Contract each cycle into a node. The edges entering a cycle cost
+        // their weight minus the weight of the cycle edge they replace.
+        let mut contracted_node = vec![0; node_count];
+        let mut contracted_count = cycle_count;
+        for (node, cycle) in cycle_of.iter().enumerate() {
+            contracted_node[node] = match *cycle {
+                Some(cycle) => cycle,
+                None => {
+                    contracted_count += 1;
+                    contracted_count - 1
+                }
+            };
+        }
+        let mut contracted_edges = Vec::new();
+        let mut origin = Vec::new();
+        for (k, &(a, b, weight)) in edges.iter().enumerate() {
+            let (ca, cb) = (contracted_node[a], contracted_node[b]);
+            if ca != cb {
+                let weight = match cycle_of[b] {
+                    Some(_) => weight.minus(edges[incoming[b].unwrap()].2),
+                    None => weight,
+                };
+                contracted_edges.push((ca, cb, weight));
+                origin.push(k);
+            }
+        }
+
+        contractions.push(Contraction {
+            targets: edges.iter().map(|&(_, b, _)| b).collect(),
+            incoming,
+            cycle_of,
+            cycle_count,
+            origin,
+        });
+        node_count = contracted_count;
+        root = contracted_node[root];
+        edges = contracted_edges;
+    };
+
+    // Expand the cycles: each keeps all its edges but the one to the node
+    // where the arborescence enters it.
+    while let Some(contraction) = contractions.pop() {
+        let mut expanded = Vec::new();
+        let mut entry = vec![None; contraction.cycle_count];
+        for k in chosen {
+            let k = contraction.origin[k];
+            expanded.push(k);
+            let b = contraction.targets[k];
+            if let Some(cycle) = contraction.cycle_of[b] {
+                entry[cycle] = Some(b);
+            }
+        }
+        for (node, cycle) in contraction.cycle_of.into_iter().enumerate() {
+            if let Some(cycle) = cycle {
+                if entry[cycle] != Some(node) {
+                    expanded.push(contraction.incoming[node].unwrap());
+                }
+            }
+        }
+        chosen = expanded;
+    }
+    chosen
+}
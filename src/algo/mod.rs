@@ -4,25 +4,42 @@
 //! so that they are generally applicable. For now, some of these still require
 //! the `Graph` type.
 
+mod adjacency;
 pub mod astar;
 pub mod bellman_ford;
+pub mod biconnected;
+pub mod centrality;
+pub mod clique;
+pub mod coloring;
+pub mod community;
+pub mod covering;
 pub mod dijkstra;
+pub mod dinic;
 pub mod dominators;
+pub mod eulerian;
 pub mod feedback_arc_set;
 pub mod floyd_warshall;
 pub mod ford_fulkerson;
 pub mod isomorphism;
+pub mod johnson;
 pub mod k_shortest_path;
 pub mod matching;
+pub mod min_cost_flow;
 pub mod min_spanning_tree;
 pub mod page_rank;
+pub mod push_relabel;
 pub mod simple_paths;
+pub mod spfa;
+pub mod steiner_tree;
 pub mod tred;
+pub mod tsp;
 
 use std::num::NonZeroUsize;
 
 use crate::prelude::*;
 
+use fixedbitset::FixedBitSet;
+
 use super::graph::IndexType;
 use super::unionfind::UnionFind;
 use super::visit::{
@@ -34,19 +51,68 @@ use crate::visit::Walker;
 
 pub use astar::astar;
 pub use bellman_ford::{bellman_ford, find_negative_cycle};
-pub use dijkstra::dijkstra;
+pub use biconnected::{
+    articulation_points, biconnected_components, block_cut_tree, bridges, BlockCutNode,
+};
+pub use centrality::{
+    betweenness_centrality, closeness_centrality, edge_betweenness_centrality,
+    eigenvector_centrality, harmonic_centrality, hits, katz_centrality,
+    weighted_betweenness_centrality, weighted_edge_betweenness_centrality,
+};
+#[cfg(feature = "rayon")]
+pub use centrality::{
+    parallel_betweenness_centrality, parallel_closeness_centrality, parallel_harmonic_centrality,
+    parallel_weighted_betweenness_centrality,
+};
+pub use clique::{maximal_cliques, maximum_clique, MaximalCliques};
+pub use coloring::{
+    bipartite_edge_coloring, chromatic_number, chromatic_number_dense, edge_coloring,
+    greedy_coloring, greedy_coloring_dense, ColoringOrder,
+};
+pub use community::{label_propagation, louvain, modularity, quotient_graph};
+pub use covering::{
+    approx_vertex_cover, greedy_dominating_set, greedy_independent_set, is_dominating_set,
+    is_independent_set, is_vertex_cover, maximum_independent_set, minimum_vertex_cover,
+};
+pub use dijkstra::{
+    bidirectional_dijkstra, dijkstra, dijkstra_paths, multi_source_dijkstra,
+    multi_source_dijkstra_path,
+};
+pub use dinic::dinic;
+pub use eulerian::{eulerian_circuit, eulerian_path, has_eulerian_path, is_eulerian, EulerianWalk};
 pub use feedback_arc_set::greedy_feedback_arc_set;
-pub use floyd_warshall::floyd_warshall;
-pub use ford_fulkerson::ford_fulkerson;
+pub use floyd_warshall::{floyd_warshall, floyd_warshall_matrix, DistanceMatrix};
+pub use ford_fulkerson::{ford_fulkerson, min_cut};
 pub use isomorphism::{
     is_isomorphic, is_isomorphic_matching, is_isomorphic_subgraph, is_isomorphic_subgraph_matching,
     subgraph_isomorphisms_iter,
 };
-pub use k_shortest_path::k_shortest_path;
-pub use matching::{greedy_matching, maximum_matching, Matching};
-pub use min_spanning_tree::min_spanning_tree;
-pub use page_rank::page_rank;
+pub use johnson::johnson;
+#[cfg(feature = "rayon")]
+pub use johnson::parallel_johnson;
+pub use k_shortest_path::{k_shortest_path, yen_k_shortest_paths};
+pub use matching::{
+    bipartite_maximum_matching, bipartite_minimum_vertex_cover, greedy_matching,
+    max_weight_assignment, maximum_matching, maximum_weight_matching, min_cost_assignment,
+    Matching,
+};
+pub use min_cost_flow::min_cost_flow;
+#[cfg(feature = "rayon")]
+pub use min_spanning_tree::parallel_min_spanning_tree_boruvka;
+pub use min_spanning_tree::{
+    min_spanning_arborescence, min_spanning_tree, min_spanning_tree_boruvka, min_spanning_tree_prim,
+};
+pub use page_rank::{
+    page_rank, page_rank_with_options, weighted_page_rank_with_options, PageRankOptions,
+    PageRankScores,
+};
+#[cfg(feature = "rayon")]
+pub use page_rank::{parallel_page_rank_with_options, parallel_weighted_page_rank_with_options};
+pub use push_relabel::push_relabel;
 pub use simple_paths::all_simple_paths;
+pub use spfa::spfa;
+pub use steiner_tree::steiner_tree;
+pub use tsp::{christofides, hamiltonian_path, held_karp, nearest_neighbor_tour, or_opt, two_opt};
 
 /// \[Generic\] Return the number of connected components of the graph.
 ///
@@ -655,6 +721,28 @@ impl<N> Cycle<N> {
 #[derive(Clone, Debug, PartialEq)]
 pub struct NegativeCycle(pub ());
 
+/// An algorithm error: some nodes can not be reached from the start node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unreachable<N>(Vec<N>);
+
+impl<N> Unreachable<N> {
+    /// Return the nodes that can not be reached
+    pub fn nodes(&self) -> &[N] {
+        &self.0
+    }
+}
+
+/// An algorithm error: the graph has no Eulerian path or circuit.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NotEulerian<N> {
+    /// The degrees of these nodes are unbalanced: they are odd in an
+    /// undirected graph, or the incoming and outgoing degrees differ in a
+    /// directed graph.
+    Unbalanced(Vec<N>),
+    /// The degrees are balanced, but the edges are not all connected.
+    Disconnected,
+}
+
 /// Return `true` if the graph is bipartite. A graph is bipartite if its nodes can be divided into
 /// two disjoint and indepedent sets U and V such that every edge connects U to one in V. This
 /// algorithm implements 2-coloring algorithm based on the BFS algorithm.
@@ -667,47 +755,103 @@ where
     VM: VisitMap<N>,
 {
     let mut red = g.visit_map();
-    red.visit(start);
     let mut blue = g.visit_map();
+    two_color(&mut red, &mut blue, start, |node| g.neighbors(node))
+}
 
-    let mut stack = ::std::collections::VecDeque::new();
-    stack.push_front(start);
+/// 2-color the nodes reachable from `start` with a BFS, `start` red, and
+/// return `false` if two neighbors get the same color.
+fn two_color<N, VM, F, I>(red: &mut VM, blue: &mut VM, start: N, mut neighbors: F) -> bool
+where
+    N: Copy,
+    VM: VisitMap<N>,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    red.visit(start);
+    let mut queue = ::std::collections::VecDeque::new();
+    queue.push_back(start);
 
-    while let Some(node) = stack.pop_front() {
+    while let Some(node) = queue.pop_front() {
         let is_red = red.is_visited(&node);
-        let is_blue = blue.is_visited(&node);
-
-        assert!(is_red ^ is_blue);
-
-        for neighbour in g.neighbors(node) {
-            let is_neigbour_red = red.is_visited(&neighbour);
-            let is_neigbour_blue = blue.is_visited(&neighbour);
-
-            if (is_red && is_neigbour_red) || (is_blue && is_neigbour_blue) {
-                return false;
+        for neighbour in neighbors(node) {
+            if red.is_visited(&neighbour) {
+                if is_red {
+                    return false;
+                }
+            } else if blue.is_visited(&neighbour) {
+                if !is_red {
+                    return false;
+                }
+            } else {
+                // hasn't been visited yet
+                if is_red {
+                    blue.visit(neighbour);
+                } else {
+                    red.visit(neighbour);
+                }
+                queue.push_back(neighbour);
             }
+        }
+    }
 
-            if !is_neigbour_red && !is_neigbour_blue {
-                //hasn't been visited yet
+    true
+}
 
-                match (is_red, is_blue) {
-                    (true, false) => {
-                        blue.visit(neighbour);
-                    }
-                    (false, true) => {
-                        red.visit(neighbour);
-                    }
-                    (_, _) => {
-                        panic!("Invariant doesn't hold");
-                    }
-                }
+/// Return the two sets of a bipartition of the graph, or `None` if it is not
+/// bipartite. A graph is bipartite if its nodes can be divided into two
+/// disjoint and independent sets such that every edge connects a node of one
+/// set to one of the other.
+///
+/// Each connected component is 2-colored with a BFS, its first node in the
+/// first set. Always treats the input graph as if undirected.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::bipartition;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let mut graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (3, 4)]);
+/// let (left, right) = bipartition(&graph).unwrap();
+/// assert_eq!(left, vec![NodeIndex::new(0), NodeIndex::new(2), NodeIndex::new(3)]);
+/// assert_eq!(right, vec![NodeIndex::new(1), NodeIndex::new(4)]);
+///
+/// // An odd cycle is not bipartite.
+/// graph.add_edge(NodeIndex::new(2), NodeIndex::new(0), ());
+/// assert_eq!(bipartition(&graph), None);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn bipartition<G>(graph: G) -> Option<(Vec<G::NodeId>, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut neighbors = vec![Vec::new(); graph.node_bound()];
+    for edge in graph.edge_references() {
+        let a = graph.to_index(edge.source());
+        let b = graph.to_index(edge.target());
+        neighbors[a].push(b);
+        neighbors[b].push(a);
+    }
 
-                stack.push_back(neighbour);
-            }
+    let neighbors = &neighbors;
+    let mut red = FixedBitSet::with_capacity(graph.node_bound());
+    let mut blue = FixedBitSet::with_capacity(graph.node_bound());
+    for start in graph.node_identifiers() {
+        let start = graph.to_index(start);
+        if red[start] || blue[start] {
+            continue;
+        }
+        if !two_color(&mut red, &mut blue, start, |node| {
+            neighbors[node].iter().cloned()
+        }) {
+            return None;
         }
     }
 
-    true
+    let (left, right) = graph
+        .node_identifiers()
+        .partition(|&node| red[graph.to_index(node)]);
+    Some((left, right))
 }
 
 use std::fmt::Debug;
@@ -839,6 +983,36 @@ macro_rules! impl_unit_measure(
 );
 impl_unit_measure!(f32, f64);
 
+/// Apply `step` to `values` until the squared distance between two
+/// successive values is at most `tolerance`, or `max_iter` times.
+///
+/// Returns the last values, the number of iterations, and whether they
+/// converged.
+fn iterate_until_converged<D, S>(
+    mut values: Vec<D>,
+    max_iter: usize,
+    tolerance: D,
+    mut step: S,
+) -> (Vec<D>, usize, bool)
+where
+    D: UnitMeasure + Copy,
+    S: FnMut(&[D]) -> Vec<D>,
+{
+    for iterations in 1..=max_iter {
+        let next = step(&values);
+        let squared_distance: D = values
+            .iter()
+            .zip(&next)
+            .map(|(&old, &new)| (new - old) * (new - old))
+            .sum();
+        values = next;
+        if squared_distance <= tolerance {
+            return (values, iterations, true);
+        }
+    }
+    (values, max_iter, false)
+}
+
 /// Some measure of positive numbers, assuming positive
 /// float-pointing numbers
 pub trait PositiveMeasure: Measure + Copy {
@@ -1,9 +1,9 @@
-use crate::visit::{EdgeRef, IntoEdges, NodeCount, NodeIndexable};
+use crate::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable};
 
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
-use super::UnitMeasure;
+use super::{iterate_until_converged, UnitMeasure};
 /// \[Generic\] Page Rank algorithm.
 ///
 /// Computes the ranks of every node in a graph using the [Page Rank algorithm][pr].
@@ -183,3 +183,360 @@ where
     }
     ranks
 }
+
+/// Options of [`page_rank_with_options`][prwo] and the related functions.
+///
+/// By default, the damping factor is `0.85`, the iteration stops after `100`
+/// iterations or when the squared distance between the ranks of two
+/// iterations is at most `1e-6`, and the random jumps go to each node with
+/// the same probability.
+///
+/// [prwo]: fn.page_rank_with_options.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::PageRankOptions;
+///
+/// let options = PageRankOptions::new()
+///     .damping_factor(0.9_f64)
+///     .max_iter(1000)
+///     .tolerance(1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRankOptions<D> {
+    damping_factor: D,
+    max_iter: usize,
+    tolerance: D,
+    personalization: Option<Vec<D>>,
+}
+
+impl<D> Default for PageRankOptions<D>
+where
+    D: UnitMeasure + Copy,
+{
+    fn default() -> Self {
+        PageRankOptions {
+            damping_factor: D::from_usize(85) / D::from_usize(100),
+            max_iter: 100,
+            tolerance: D::default_tol(),
+            personalization: None,
+        }
+    }
+}
+
+impl<D> PageRankOptions<D>
+where
+    D: UnitMeasure + Copy,
+{
+    /// Create the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the probability to follow an edge rather than to jump to a
+    /// random node, which should be between 0 and 1.
+    pub fn damping_factor(mut self, damping_factor: D) -> Self {
+        self.damping_factor = damping_factor;
+        self
+    }
+
+    /// Set the largest number of iterations.
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Set the squared distance between the ranks of two iterations at
+    /// which the iteration stops.
+    pub fn tolerance(mut self, tolerance: D) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the probability of the random jumps to each node, by node index:
+    /// the values should be non-negative, and are divided by their sum.
+    /// The mass of the nodes without outgoing edges is redistributed the
+    /// same way.
+    pub fn personalization(mut self, personalization: Vec<D>) -> Self {
+        self.personalization = Some(personalization);
+        self
+    }
+}
+
+/// The ranks computed by [`page_rank_with_options`][prwo] and the related
+/// functions.
+///
+/// [prwo]: fn.page_rank_with_options.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRankScores<D> {
+    /// The rank of each node, by node index. The indices that are not nodes
+    /// of the graph have a rank of zero.
+    pub ranks: Vec<D>,
+    /// The number of iterations that were run.
+    pub iterations: usize,
+    /// Whether the iteration stopped at the tolerance, rather than at the
+    /// largest number of iterations.
+    pub converged: bool,
+}
+
+/// \[Generic\] Page Rank algorithm, with options.
+///
+/// Computes the ranks of every node in a graph using the [Page Rank
+/// algorithm][pr]. At each step the random surfer follows an outgoing edge
+/// with probability `damping_factor`, where parallel edges count
+/// separately, or else jumps to a node picked from the personalization
+/// distribution. The rank of the nodes without outgoing edges is
+/// redistributed following the personalization distribution too, so that
+/// the ranks always sum to one.
+///
+/// Unlike [`page_rank`][page_rank], the iteration stops as soon as the
+/// squared distance between the ranks of two iterations is at most the
+/// tolerance, and the number of iterations is reported.
+///
+/// # Panics
+/// If the damping factor is not between 0 and 1, or if the personalization
+/// distribution does not have a value for each node index (`node_bound`) or
+/// sums to zero on the nodes of the graph.
+///
+/// # Complexity
+/// * Time complexity: **O(N·(|V| + |E|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **N** is the number of iterations.
+///
+/// [pr]: https://en.wikipedia.org/wiki/PageRank
+/// [page_rank]: fn.page_rank.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{page_rank_with_options, PageRankOptions};
+/// use petgraph::prelude::*;
+///
+/// // The cycle 0 -> 1 -> 2 -> 0, and the node 3 with no outgoing edge.
+/// let g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+///
+/// let scores = page_rank_with_options(&g, &PageRankOptions::new().tolerance(1e-12_f64));
+/// assert!(scores.converged);
+/// assert!(scores.iterations < 100);
+/// assert!((scores.ranks.iter().sum::<f64>() - 1.).abs() < 1e-9);
+/// assert!(scores.ranks[2] > scores.ranks[3]);
+///
+/// // All the random jumps go to 3.
+/// let options = PageRankOptions::new().personalization(vec![0., 0., 0., 1.]);
+/// let scores = page_rank_with_options(&g, &options);
+/// assert!(scores.ranks[3] > 0.5);
+/// ```
+pub fn page_rank_with_options<G, D>(graph: G, options: &PageRankOptions<D>) -> PageRankScores<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    D: UnitMeasure + Copy,
+{
+    weighted_page_rank_with_options(graph, options, |_| D::one())
+}
+
+/// \[Generic\] Page Rank algorithm, with options and edge weights.
+///
+/// This is [`page_rank_with_options`][prwo], where the random surfer
+/// follows each outgoing edge with a probability proportional to its
+/// weight, given by `edge_weight`. The weights should be non-negative, and
+/// a node whose outgoing edges all have a weight of zero counts as a node
+/// without outgoing edges.
+///
+/// [prwo]: fn.page_rank_with_options.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{weighted_page_rank_with_options, PageRankOptions};
+/// use petgraph::prelude::*;
+///
+/// // From 0, the edge to 2 is followed 3 times as often as the one to 1.
+/// let g = Graph::<(), f64>::from_edges(&[(0, 1, 1.), (0, 2, 3.), (1, 0, 1.), (2, 0, 1.)]);
+///
+/// let options = PageRankOptions::new().tolerance(1e-12);
+/// let scores = weighted_page_rank_with_options(&g, &options, |e| *e.weight());
+/// assert!(scores.ranks[2] > scores.ranks[1]);
+/// ```
+pub fn weighted_page_rank_with_options<G, D, F>(
+    graph: G,
+    options: &PageRankOptions<D>,
+    edge_weight: F,
+) -> PageRankScores<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    D: UnitMeasure + Copy,
+    F: FnMut(G::EdgeRef) -> D,
+{
+    let transitions = Transitions::new(graph, options, edge_weight);
+    transitions.iterate(options, |ranks| {
+        let dangling = transitions.dangling_rank(ranks);
+        (0..ranks.len())
+            .map(|v| transitions.rank(ranks, dangling, v))
+            .collect()
+    })
+}
+
+/// \[Generic\] Parallel Page Rank algorithm, with options.
+///
+/// The ranks of the nodes are updated in parallel.
+/// See [`page_rank_with_options`][prwo].
+///
+/// [prwo]: fn.page_rank_with_options.html
+#[cfg(feature = "rayon")]
+pub fn parallel_page_rank_with_options<G, D>(
+    graph: G,
+    options: &PageRankOptions<D>,
+) -> PageRankScores<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    D: UnitMeasure + Copy + Send + Sync,
+{
+    parallel_weighted_page_rank_with_options(graph, options, |_| D::one())
+}
+
+/// \[Generic\] Parallel Page Rank algorithm, with options and edge weights.
+///
+/// The ranks of the nodes are updated in parallel.
+/// See [`weighted_page_rank_with_options`][wprwo].
+///
+/// [wprwo]: fn.weighted_page_rank_with_options.html
+#[cfg(feature = "rayon")]
+pub fn parallel_weighted_page_rank_with_options<G, D, F>(
+    graph: G,
+    options: &PageRankOptions<D>,
+    edge_weight: F,
+) -> PageRankScores<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    D: UnitMeasure + Copy + Send + Sync,
+    F: FnMut(G::EdgeRef) -> D,
+{
+    let transitions = Transitions::new(graph, options, edge_weight);
+    transitions.iterate(options, |ranks| {
+        let dangling = transitions
+            .dangling
+            .par_iter()
+            .map(|&u| ranks[u])
+            .sum::<D>();
+        (0..ranks.len())
+            .into_par_iter()
+            .map(|v| transitions.rank(ranks, dangling, v))
+            .collect()
+    })
+}
+
+/// The random surfer of the Page Rank algorithm.
+struct Transitions<D> {
+    /// The nodes with an edge to each node, and the probability to follow
+    /// it from there.
+    incoming: Vec<Vec<(usize, D)>>,
+    /// The nodes without outgoing edges.
+    dangling: Vec<usize>,
+    /// The probability of the random jumps to each node.
+    teleport: Vec<D>,
+    damping_factor: D,
+}
+
+impl<D> Transitions<D>
+where
+    D: UnitMeasure + Copy,
+{
+    fn new<G, F>(graph: G, options: &PageRankOptions<D>, mut edge_weight: F) -> Self
+    where
+        G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> D,
+    {
+        let damping_factor = options.damping_factor;
+        assert!(
+            D::zero() <= damping_factor && damping_factor <= D::one(),
+            "Damping factor should be between 0 and 1."
+        );
+        let node_bound = graph.node_bound();
+        let mut teleport = vec![D::zero(); node_bound];
+        match options.personalization {
+            Some(ref personalization) => {
+                assert_eq!(
+                    personalization.len(),
+                    node_bound,
+                    "The personalization should have a value for each node index."
+                );
+                for n in graph.node_identifiers() {
+                    let i = graph.to_index(n);
+                    teleport[i] = personalization[i];
+                }
+            }
+            None => {
+                for n in graph.node_identifiers() {
+                    teleport[graph.to_index(n)] = D::one();
+                }
+            }
+        }
+        let sum: D = teleport.iter().cloned().sum();
+        if graph.node_identifiers().next().is_some() {
+            assert!(
+                sum > D::zero(),
+                "The personalization should not sum to zero."
+            );
+            for p in &mut teleport {
+                *p = *p / sum;
+            }
+        }
+
+        let mut incoming = vec![vec![]; node_bound];
+        let mut dangling = vec![];
+        let mut weights = vec![];
+        for u in graph.node_identifiers() {
+            weights.clear();
+            weights.extend(
+                graph
+                    .edges(u)
+                    .map(|edge| (graph.to_index(edge.target()), edge_weight(edge))),
+            );
+            let total: D = weights.iter().map(|&(_, w)| w).sum();
+            if total == D::zero() {
+                dangling.push(graph.to_index(u));
+                continue;
+            }
+            for &(v, w) in &weights {
+                if w != D::zero() {
+                    incoming[v].push((graph.to_index(u), w / total));
+                }
+            }
+        }
+        Transitions {
+            incoming,
+            dangling,
+            teleport,
+            damping_factor,
+        }
+    }
+
+    fn dangling_rank(&self, ranks: &[D]) -> D {
+        self.dangling.iter().map(|&u| ranks[u]).sum()
+    }
+
+    /// The next rank of `v`, where `dangling` is the rank of the nodes
+    /// without outgoing edges.
+    fn rank(&self, ranks: &[D], dangling: D, v: usize) -> D {
+        let followed: D = self.incoming[v].iter().map(|&(u, p)| ranks[u] * p).sum();
+        self.damping_factor * (followed + dangling * self.teleport[v])
+            + (D::one() - self.damping_factor) * self.teleport[v]
+    }
+
+    fn iterate<S>(&self, options: &PageRankOptions<D>, step: S) -> PageRankScores<D>
+    where
+        S: FnMut(&[D]) -> Vec<D>,
+    {
+        // The ranks start as the personalization distribution.
+        let (ranks, iterations, converged) = iterate_until_converged(
+            self.teleport.clone(),
+            options.max_iter,
+            options.tolerance,
+            step,
+        );
+        PageRankScores {
+            ranks,
+            iterations,
+            converged,
+        }
+    }
+}
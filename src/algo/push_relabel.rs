@@ -0,0 +1,195 @@
+//! Highest-label push–relabel maximum flow algorithm.
+
+use std::ops::Sub;
+
+use crate::{
+    data::DataMap,
+    visit::{EdgeCount, EdgeIndexable, IntoEdgesDirected, NodeCount, NodeIndexable, Visitable},
+};
+
+use super::ford_fulkerson::ResidualNetwork;
+use super::{EdgeRef, PositiveMeasure};
+
+/// \[Generic\] Highest-label push–relabel algorithm.
+///
+/// Computes the [maximum flow][pr] of a weighted directed graph, where edge
+/// weights are capacities, from `source` to `destination`.
+///
+/// Excess flow is pushed from the active node with the highest label towards
+/// the destination, and nodes are relabeled when they cannot push anymore.
+/// With the gap heuristic, nodes that cannot reach the destination are
+/// lifted at once. This takes **O(|V|²·√|E|)** time, and is usually the
+/// fastest choice on large, dense networks.
+///
+/// Returns the maximum flow and the computed edge flows, indexed by edge
+/// index, like [`ford_fulkerson`][ff]. Use [`min_cut`][min_cut] to get a
+/// minimum cut from them.
+///
+/// [pr]: https://en.wikipedia.org/wiki/Push%E2%80%93relabel_maximum_flow_algorithm
+/// [ff]: ../ford_fulkerson/fn.ford_fulkerson.html
+/// [min_cut]: ../ford_fulkerson/fn.min_cut.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::push_relabel;
+/// // Example from CLRS book
+/// let mut graph = Graph::<u8, u8>::new();
+/// let source = graph.add_node(0);
+/// let _ = graph.add_node(1);
+/// let _ = graph.add_node(2);
+/// let _ = graph.add_node(3);
+/// let _ = graph.add_node(4);
+/// let destination = graph.add_node(5);
+/// graph.extend_with_edges(&[
+///    (0, 1, 16),
+///    (0, 2, 13),
+///    (1, 2, 10),
+///    (1, 3, 12),
+///    (2, 1, 4),
+///    (2, 4, 14),
+///    (3, 2, 9),
+///    (3, 5, 20),
+///    (4, 3, 7),
+///    (4, 5, 4),
+/// ]);
+/// let (max_flow, flows) = push_relabel(&graph, source, destination);
+/// assert_eq!(23, max_flow);
+/// // The flows respect the capacities.
+/// assert!(graph.edge_indices().all(|e| flows[e.index()] <= graph[e]));
+/// ```
+pub fn push_relabel<N>(
+    network: N,
+    source: N::NodeId,
+    destination: N::NodeId,
+) -> (N::EdgeWeight, Vec<N::EdgeWeight>)
+where
+    N: NodeCount
+        + EdgeCount
+        + IntoEdgesDirected
+        + EdgeIndexable
+        + NodeIndexable
+        + DataMap
+        + Visitable,
+    N::EdgeWeight: Sub<Output = N::EdgeWeight> + PositiveMeasure,
+{
+    let ix = |i| NodeIndexable::to_index(&network, i);
+    let eix = |e: N::EdgeRef| EdgeIndexable::to_index(&network, e.id());
+    let zero = N::EdgeWeight::zero();
+    let n = network.node_bound();
+
+    let mut flows = vec![zero; network.edge_bound()];
+    if source == destination {
+        return (zero, flows);
+    }
+
+    let residual = ResidualNetwork::new(network);
+
+    // Labels are below 2·|V|; `count` is the number of nodes with each label.
+    let mut label = vec![0; n];
+    let mut count = vec![0; 2 * n + 1];
+    let mut excess = vec![zero; n];
+    let mut current_arc = vec![0; n];
+    // Active nodes, bucketed by their label when they became active.
+    let mut active = vec![Vec::new(); 2 * n + 1];
+    let mut highest = 0;
+
+    label[ix(source)] = n;
+    count[0] = n - 1;
+    count[n] = 1;
+    for &arc in residual.arcs(ix(source)) {
+        let (edge, forward) = arc;
+        let next = residual.head(arc);
+        let capacity = residual.capacity(&flows, arc);
+        if forward && next != source && capacity > zero {
+            flows[eix(edge)] = *edge.weight();
+            if next != destination && excess[ix(next)] == zero {
+                active[0].push(next);
+            }
+            excess[ix(next)] = excess[ix(next)] + capacity;
+        }
+    }
+
+    loop {
+        let vertex = match active[highest].pop() {
+            Some(vertex) => vertex,
+            None if highest == 0 => break,
+            None => {
+                highest -= 1;
+                continue;
+            }
+        };
+        let v = ix(vertex);
+        if label[v] != highest {
+            // Lifted by the gap heuristic since it became active.
+            active[label[v]].push(vertex);
+            highest = highest.max(label[v]);
+            continue;
+        }
+
+        // Discharge the node: push its excess along admissible arcs, and
+        // relabel it when there are none left.
+        while excess[v] > zero {
+            match residual.arcs(v).get(current_arc[v]) {
+                Some(&arc) => {
+                    let next = residual.head(arc);
+                    let w = ix(next);
+                    let capacity = residual.capacity(&flows, arc);
+                    if capacity > zero && label[v] == label[w] + 1 {
+                        let delta = if excess[v] < capacity {
+                            excess[v]
+                        } else {
+                            current_arc[v] += 1;
+                            capacity
+                        };
+                        let (edge, forward) = arc;
+                        let flow = &mut flows[eix(edge)];
+                        *flow = if forward {
+                            *flow + delta
+                        } else {
+                            *flow - delta
+                        };
+                        if next != source && next != destination && excess[w] == zero {
+                            active[label[w]].push(next);
+                            highest = highest.max(label[w]);
+                        }
+                        excess[v] = excess[v] - delta;
+                        excess[w] = excess[w] + delta;
+                    } else {
+                        current_arc[v] += 1;
+                    }
+                }
+                None => {
+                    let old = label[v];
+                    let new = residual
+                        .arcs(v)
+                        .iter()
+                        .filter(|&&arc| residual.capacity(&flows, arc) > zero)
+                        .map(|&arc| label[ix(residual.head(arc))] + 1)
+                        .min()
+                        .unwrap_or(2 * n)
+                        .min(2 * n);
+                    count[old] -= 1;
+                    current_arc[v] = 0;
+                    if count[old] == 0 && old < n {
+                        // Gap: the nodes above `old` cannot reach the
+                        // destination anymore, so lift them above the source.
+                        for (u, l) in label.iter_mut().enumerate() {
+                            if *l > old && *l < n {
+                                count[*l] -= 1;
+                                *l = n + 1;
+                                count[*l] += 1;
+                                current_arc[u] = 0;
+                            }
+                        }
+                        label[v] = new.max(n + 1);
+                    } else {
+                        label[v] = new;
+                    }
+                    count[label[v]] += 1;
+                }
+            }
+        }
+    }
+    (excess[ix(destination)], flows)
+}
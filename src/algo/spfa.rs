@@ -0,0 +1,127 @@
+//! Shortest Path Faster Algorithm.
+
+use std::collections::VecDeque;
+
+use super::bellman_ford::Paths;
+use super::{BoundedMeasure, NegativeCycle};
+use crate::visit::{EdgeRef, IntoEdges, NodeCount, NodeIndexable};
+
+/// \[Generic\] Compute shortest paths from node `source` to all other.
+///
+/// Using the [Shortest Path Faster Algorithm][spfa], a queue-based variant of
+/// the Bellman–Ford algorithm which only relaxes the edges of nodes whose
+/// distance changed. Negative edge costs are permitted, but the graph must
+/// not have a cycle of negative weights (in that case it will return an error).
+/// A negative cycle is detected as soon as a node enters the queue more than
+/// **|V|** times.
+///
+/// The function `edge_cost` should return the cost for a particular edge.
+///
+/// On success, return one vec with path costs, and another one which points
+/// out the predecessor of a node along a shortest path. The vectors
+/// are indexed by the graph's node indices. Unreachable nodes have a cost of
+/// `None` and no predecessor.
+///
+/// The worst case runtime is **O(|V|·|E|)**, like [`bellman_ford`][bf], but it
+/// is usually much faster on sparse graphs.
+///
+/// [spfa]: https://en.wikipedia.org/wiki/Shortest_path_faster_algorithm
+/// [bf]: ../bellman_ford/fn.bellman_ford.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::spfa;
+/// use petgraph::prelude::*;
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node(()); // node with no weight
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// let e = g.add_node(());
+/// let f = g.add_node(());
+/// g.extend_with_edges(&[
+///     (0, 1, 2),
+///     (0, 3, 4),
+///     (1, 2, 1),
+///     (1, 5, 7),
+///     (2, 4, 5),
+///     (4, 5, -3),
+///     (3, 4, 1),
+/// ]);
+///
+/// // Graph represented with the weight of each edge
+/// //
+/// //     2       1
+/// // a ----> b ----> c
+/// // | 4     | 7     |
+/// // v       v       | 5
+/// // d       f       |
+/// // | 1     ^ -3    |
+/// // \-----> e <-----/
+///
+/// let path = spfa(&g, a, |edge| *edge.weight()).unwrap();
+/// assert_eq!(path.distances, vec![Some(0), Some(2), Some(3), Some(4), Some(5), Some(2)]);
+/// assert_eq!(path.predecessors, vec![None, Some(a),Some(b),Some(a), Some(d), Some(e)]);
+///
+/// // A negative cycle is reported as an error.
+/// g.add_edge(f, d, -1);
+/// assert!(spfa(&g, a, |edge| *edge.weight()).is_err());
+/// ```
+pub fn spfa<G, F, K>(
+    graph: G,
+    source: G::NodeId,
+    mut edge_cost: F,
+) -> Result<Paths<G::NodeId, Option<K>>, NegativeCycle>
+where
+    G: IntoEdges + NodeCount + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    let ix = |i| graph.to_index(i);
+
+    let mut distances = vec![None; graph.node_bound()];
+    let mut predecessors = vec![None; graph.node_bound()];
+    distances[ix(source)] = Some(K::default());
+
+    // Nodes whose outgoing edges need to be relaxed, and how many times each
+    // node has entered the queue.
+    let mut queue = VecDeque::new();
+    let mut in_queue = vec![false; graph.node_bound()];
+    let mut enqueued = vec![0; graph.node_bound()];
+    queue.push_back(source);
+    in_queue[ix(source)] = true;
+
+    while let Some(i) = queue.pop_front() {
+        in_queue[ix(i)] = false;
+        // Only reached nodes enter the queue.
+        let base = match distances[ix(i)] {
+            Some(d) => d,
+            None => continue,
+        };
+        for edge in graph.edges(i) {
+            let j = edge.target();
+            let (distance, overflow) = base.overflowing_add(edge_cost(edge));
+            if !overflow && distances[ix(j)].map_or(true, |d| distance < d) {
+                distances[ix(j)] = Some(distance);
+                predecessors[ix(j)] = Some(i);
+                if !in_queue[ix(j)] {
+                    // Only a negative cycle makes a node enter the queue
+                    // more than |V| times.
+                    enqueued[ix(j)] += 1;
+                    if enqueued[ix(j)] > graph.node_count() {
+                        return Err(NegativeCycle(()));
+                    }
+                    in_queue[ix(j)] = true;
+                    queue.push_back(j);
+                }
+            }
+        }
+    }
+
+    Ok(Paths {
+        distances,
+        predecessors,
+    })
+}
@@ -0,0 +1,202 @@
+//! Steiner tree approximation.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::algo::{dijkstra_paths, min_spanning_tree, BoundedMeasure};
+use crate::data::Element;
+use crate::graph::{edge_index, Graph, NodeIndex};
+use crate::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+use crate::Undirected;
+use fixedbitset::FixedBitSet;
+
+/// \[Generic\] Compute an approximate *minimum Steiner tree* of a graph.
+///
+/// A [Steiner tree][st] connects all the `terminals` with the least total
+/// edge cost, and may go through other nodes of the graph. Finding a minimum
+/// one is NP-hard, so this uses [Mehlhorn's 2-approximation][mehlhorn]: the
+/// result costs at most twice as much as a minimum Steiner tree. It is a
+/// faster variant of the approximation by a minimum spanning tree of the
+/// terminals' metric closure, with the same guarantee.
+///
+/// Each node is assigned to its closest terminal with [`dijkstra_paths`][dijkstra],
+/// then a [`min_spanning_tree`][mst] of the terminals is computed, where
+/// each edge between the regions of two terminals gives the cost of a path
+/// between them. The paths of the spanning tree form the result.
+///
+/// The graph is treated as if undirected. The function `edge_cost` should
+/// return the cost for a particular edge, which must be non-negative.
+/// Terminals that are not connected to each other are connected within each
+/// component of the graph, which makes a *Steiner forest*.
+///
+/// Returns the set of nodes and the set of edges of the tree, which include
+/// all the terminals. They can be used with [`NodeFiltered`][nf] and
+/// [`EdgeFiltered`][ef] to get the tree as a subgraph.
+///
+/// Computes in **O((|V| + |E|)·log(|V|))** time.
+///
+/// [st]: https://en.wikipedia.org/wiki/Steiner_tree_problem
+/// [mehlhorn]: https://doi.org/10.1016/0020-0190(88)90066-X
+/// [dijkstra]: ../dijkstra/fn.dijkstra_paths.html
+/// [mst]: ../min_spanning_tree/fn.min_spanning_tree.html
+/// [nf]: ../../visit/struct.NodeFiltered.html
+/// [ef]: ../../visit/struct.EdgeFiltered.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::steiner_tree;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::{EdgeFiltered, IntoNeighbors, NodeFiltered};
+///
+/// let mut graph = UnGraph::<&str, u32>::new_undirected();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// let x = graph.add_node("x");
+/// graph.extend_with_edges(&[
+///     (a, b, 3),
+///     (b, c, 3),
+///     (c, a, 3),
+///     (x, a, 1),
+///     (x, b, 1),
+///     (x, c, 1),
+/// ]);
+/// //        a
+/// //      / |  \
+/// //   3 /  |1  \ 3
+/// //    /   x    \
+/// //   / 1 / \ 1  \
+/// //  b ---------- c
+/// //        3
+///
+/// let (nodes, edges) = steiner_tree(&graph, vec![a, b, c], |e| *e.weight());
+/// assert_eq!(nodes.len(), 4);
+/// assert!(nodes.contains(&x));
+/// let cost: u32 = edges.iter().map(|&e| graph[e]).sum();
+/// assert_eq!(cost, 3);
+///
+/// // The tree as a subgraph.
+/// let subgraph = NodeFiltered(&graph, &nodes);
+/// let tree = EdgeFiltered::from_fn(&subgraph, |e| edges.contains(&e.id()));
+/// assert_eq!(tree.neighbors(x).count(), 3);
+/// ```
+pub fn steiner_tree<G, I, F, K>(
+    graph: G,
+    terminals: I,
+    mut edge_cost: F,
+) -> (HashSet<G::NodeId>, HashSet<G::EdgeId>)
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    I: IntoIterator<Item = G::NodeId>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    let ix = |i| graph.to_index(i);
+    let node_bound = graph.node_bound();
+
+    // The edges of the graph, with the same indices as in `edges`, and an
+    // extra node with an edge to each terminal: the shortest paths from it
+    // start at the closest terminal.
+    let mut edges = Vec::new();
+    let mut distances = Graph::<(), K, Undirected>::with_capacity(node_bound + 1, 0);
+    for _ in 0..node_bound + 1 {
+        distances.add_node(());
+    }
+    for edge in graph.edge_references() {
+        let (a, b) = (ix(edge.source()), ix(edge.target()));
+        distances.add_edge(NodeIndex::new(a), NodeIndex::new(b), edge_cost(edge));
+        edges.push((a, b, edge.id()));
+    }
+    let start = NodeIndex::new(node_bound);
+    let terminals: Vec<_> = terminals.into_iter().map(ix).collect();
+    for &terminal in &terminals {
+        distances.add_edge(start, NodeIndex::new(terminal), K::default());
+    }
+    let paths = dijkstra_paths(&distances, start, None, |e| *e.weight());
+    let predecessor = |i: usize| match paths.predecessors[i] {
+        Some(p) if p != start => Some(p.index()),
+        _ => None,
+    };
+
+    // The closest terminal of every reachable node.
+    let mut regions = vec![None; node_bound];
+    for i in 0..node_bound {
+        let mut path = vec![];
+        let mut node = i;
+        while regions[node].is_none() {
+            path.push(node);
+            match predecessor(node) {
+                Some(p) => node = p,
+                None => break,
+            }
+        }
+        let region = regions[node].or_else(|| {
+            if paths.predecessors[node] == Some(start) {
+                Some(node)
+            } else {
+                None
+            }
+        });
+        for node in path {
+            regions[node] = region;
+        }
+    }
+
+    // The edges between two regions make paths between their terminals.
+    let mut terminal_paths = Graph::<(), (K, usize), Undirected>::with_capacity(node_bound, 0);
+    for _ in 0..node_bound {
+        terminal_paths.add_node(());
+    }
+    for (i, &(a, b, _)) in edges.iter().enumerate() {
+        if let (Some(ra), Some(rb)) = (regions[a], regions[b]) {
+            if ra != rb {
+                // Nodes in a region are reached from the terminals.
+                let (da, db) = (paths.distances[a].unwrap(), paths.distances[b].unwrap());
+                let cost = da + distances[edge_index(i)] + db;
+                terminal_paths.add_edge(NodeIndex::new(ra), NodeIndex::new(rb), (cost, i));
+            }
+        }
+    }
+
+    // Go back to the terminals from both ends of each chosen edge, and from
+    // the terminals themselves, which may be in the region of another one
+    // if they are at zero cost. A node already in the tree has its path to
+    // its terminal in the tree too.
+    let mut in_tree = FixedBitSet::with_capacity(node_bound);
+    let mut tree_edges = HashSet::new();
+    let mut ends = terminals;
+    for element in min_spanning_tree(&terminal_paths) {
+        if let Element::Edge { weight: (_, i), .. } = element {
+            let (a, b, id) = edges[i];
+            tree_edges.insert(id);
+            ends.push(a);
+            ends.push(b);
+        }
+    }
+    for end in ends {
+        let mut node = end;
+        in_tree.insert(node);
+        while let Some(p) = predecessor(node) {
+            let edge = distances
+                .edges(NodeIndex::new(p))
+                .filter(|e| e.target().index() == node && e.id().index() < edges.len())
+                .min_by(|x, y| {
+                    x.weight()
+                        .partial_cmp(y.weight())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .expect("predecessor should be connected to its successor");
+            tree_edges.insert(edges[edge.id().index()].2);
+            if in_tree.put(p) {
+                break;
+            }
+            node = p;
+        }
+    }
+
+    let nodes = in_tree.ones().map(|i| graph.from_index(i)).collect();
+    (nodes, tree_edges)
+}
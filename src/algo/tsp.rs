@@ -0,0 +1,643 @@
+//! Travelling salesman heuristics and Hamiltonian paths.
+
+use std::ops::{Div, Sub};
+
+use crate::algo::{eulerian_circuit, maximum_weight_matching, min_spanning_tree, Measure};
+use crate::data::FromElements;
+use crate::graph::{NodeIndex, UnGraph};
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable,
+};
+
+/// \[Generic\] Tour of the graph built by always going to the nearest node
+/// not visited yet.
+///
+/// The tour starts at `start`, follows the cheapest edge to a node that has
+/// not been visited, and returns to `start` once all the nodes are visited.
+/// Edges are followed in their direction in a directed graph, and among
+/// equally cheap nodes the first one of `graph.node_identifiers()` is
+/// taken.
+///
+/// Returns the total cost of the tour, with its nodes in order starting at
+/// `start`, or `None` if the tour gets stuck at a node without an edge to
+/// an unvisited node, or without an edge back to `start`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |E|)**.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::nearest_neighbor_tour;
+/// use petgraph::prelude::*;
+///
+/// // A square with the cost 1 on its sides and 3 on its diagonals.
+/// let g = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 1),
+///     (0, 2, 3), (1, 3, 3),
+/// ]);
+///
+/// let (cost, tour) = nearest_neighbor_tour(&g, NodeIndex::new(0), |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 4);
+/// assert_eq!(tour, vec![0.into(), 1.into(), 2.into(), 3.into()]);
+/// ```
+pub fn nearest_neighbor_tour<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    edge_cost: F,
+) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let costs = Costs::new(graph, edge_cost, !graph.is_directed());
+    let n = costs.len();
+    let mut visited = vec![false; n];
+    let mut tour = vec![costs.positions[graph.to_index(start)]];
+    visited[tour[0]] = true;
+    while tour.len() < n {
+        let last = tour[tour.len() - 1];
+        let mut nearest: Option<(K, usize)> = None;
+        for (next, &seen) in visited.iter().enumerate() {
+            if let (false, Some(cost)) = (seen, costs.get(last, next)) {
+                if nearest.map_or(true, |(best, _)| cost < best) {
+                    nearest = Some((cost, next));
+                }
+            }
+        }
+        let (_, next) = nearest?;
+        visited[next] = true;
+        tour.push(next);
+    }
+    let cost = costs.tour_cost(&tour)?;
+    Some((cost, costs.node_ids(&tour)))
+}
+
+/// \[Generic\] Improve a tour with the 2-opt local search.
+///
+/// A 2-opt move replaces two edges of the tour by the two edges that
+/// reconnect it the other way, which reverses the part of the tour between
+/// them. Moves are made as long as one makes the tour cheaper, so the
+/// result is a local optimum. Reversing parts of the tour assumes that the
+/// costs are symmetric: the graph is treated as undirected, and the cost
+/// between two nodes is the cheapest edge between them.
+///
+/// `tour` holds each node of the graph once, and is improved in place.
+/// Returns the cost of the improved tour, or `None` if the given tour uses
+/// a missing edge, and is then left unchanged.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |E|)**, and **O(|V|²)** per pass over the tour.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::two_opt;
+/// use petgraph::prelude::*;
+///
+/// // A square with the cost 1 on its sides and 3 on its diagonals.
+/// let g = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 1),
+///     (0, 2, 3), (1, 3, 3),
+/// ]);
+///
+/// // The tour 0, 2, 1, 3 crosses the square twice.
+/// let mut tour = vec![0.into(), 2.into(), 1.into(), 3.into()];
+/// assert_eq!(two_opt(&g, &mut tour, |e| *e.weight()), Some(4));
+/// assert_eq!(tour, vec![0.into(), 1.into(), 2.into(), 3.into()]);
+/// ```
+pub fn two_opt<G, F, K>(graph: G, tour: &mut [G::NodeId], edge_cost: F) -> Option<K>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let costs = Costs::new(graph, edge_cost, true);
+    let mut positions = costs.tour_positions(graph, tour);
+    costs.tour_cost(&positions)?;
+    let n = positions.len();
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 0..n.saturating_sub(2) {
+            for j in i + 2..n {
+                if i == 0 && j == n - 1 {
+                    continue;
+                }
+                let (a, b) = (positions[i], positions[i + 1]);
+                let (c, d) = (positions[j], positions[(j + 1) % n]);
+                if let (Some(ac), Some(bd)) = (costs.get(a, c), costs.get(b, d)) {
+                    let before = costs.get(a, b).unwrap() + costs.get(c, d).unwrap();
+                    if ac + bd < before {
+                        positions[i + 1..=j].reverse();
+                        improved = true;
+                    }
+                }
+            }
+        }
+    }
+    for (node, &position) in tour.iter_mut().zip(&positions) {
+        *node = costs.nodes[position];
+    }
+    costs.tour_cost(&positions)
+}
+
+/// \[Generic\] Improve a tour with the Or-opt local search.
+///
+/// An Or-opt move takes a run of one, two or three consecutive nodes out of
+/// the tour, and inserts it between two other consecutive nodes, in the
+/// same or the reverse order. Moves are made as long as one makes the tour
+/// cheaper, so the result is a local optimum. Like [`two_opt`][two_opt],
+/// this assumes that the costs are symmetric: the graph is treated as
+/// undirected, and the cost between two nodes is the cheapest edge between
+/// them.
+///
+/// `tour` holds each node of the graph once, and is improved in place,
+/// keeping its first node. Returns the cost of the improved tour, or `None`
+/// if the given tour uses a missing edge, and is then left unchanged.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |E|)**, and **O(|V|²)** per pass over the tour.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// [two_opt]: fn.two_opt.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::or_opt;
+/// use petgraph::prelude::*;
+///
+/// // Five points on a line, where the cost is the distance.
+/// let mut g = UnGraph::<(), u32>::default();
+/// let n: Vec<_> = (0..5).map(|_| g.add_node(())).collect();
+/// for a in 0..5 {
+///     for b in a + 1..5 {
+///         g.add_edge(n[a], n[b], (b - a) as u32);
+///     }
+/// }
+///
+/// // Going back and forth costs 10, and going to 4 and back 8.
+/// let mut tour = vec![n[0], n[2], n[1], n[3], n[4]];
+/// assert_eq!(or_opt(&g, &mut tour, |e| *e.weight()), Some(8));
+/// assert_eq!(tour[0], n[0]);
+/// ```
+pub fn or_opt<G, F, K>(graph: G, tour: &mut [G::NodeId], edge_cost: F) -> Option<K>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let costs = Costs::new(graph, edge_cost, true);
+    let mut positions = costs.tour_positions(graph, tour);
+    costs.tour_cost(&positions)?;
+    let n = positions.len();
+    let first = positions.first().cloned();
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for len in 1..=3 {
+            if n < len + 3 {
+                break;
+            }
+            let mut i = 0;
+            while i + len <= n {
+                if let Some(next) = costs.improving_insertion(&positions, i, len) {
+                    let run: Vec<usize> = positions.drain(i..i + len).collect();
+                    let (after, reversed) = next;
+                    let at = positions.iter().position(|&p| p == after).unwrap() + 1;
+                    if reversed {
+                        positions.splice(at..at, run.into_iter().rev());
+                    } else {
+                        positions.splice(at..at, run);
+                    }
+                    improved = true;
+                }
+                i += 1;
+            }
+        }
+    }
+    if let Some(first) = first {
+        let start = positions.iter().position(|&p| p == first).unwrap();
+        positions.rotate_left(start);
+    }
+    for (node, &position) in tour.iter_mut().zip(&positions) {
+        *node = costs.nodes[position];
+    }
+    costs.tour_cost(&positions)
+}
+
+/// \[Generic\] Tour of a complete graph with [Christofides'
+/// algorithm][christofides].
+///
+/// The tour walks an Eulerian circuit of a [minimum spanning
+/// tree][min_spanning_tree], together with a minimum weight perfect
+/// [matching][matching] of the nodes with an odd degree in the tree, and
+/// skips the nodes already visited. When the costs are metric, that is they
+/// satisfy the triangle inequality, the tour costs at most 3/2 times an
+/// optimal tour.
+///
+/// The graph is treated as undirected, and the cost between two nodes is
+/// the cheapest edge between them. The matching maximizes the difference of
+/// each cost to the heaviest one, which is never negative, so the costs may
+/// be unsigned; `K` only needs the operations of
+/// [`maximum_weight_matching`][matching].
+///
+/// Returns the total cost of the tour, with its nodes in order starting at
+/// the first node of `graph.node_identifiers()`, or `None` if the graph is
+/// not complete.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|³ + |E|)**.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// [christofides]: https://en.wikipedia.org/wiki/Christofides_algorithm
+/// [min_spanning_tree]: fn.min_spanning_tree.html
+/// [matching]: fn.maximum_weight_matching.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::christofides;
+/// use petgraph::prelude::*;
+///
+/// // A square with the cost 1 on its sides and 2 on its diagonals.
+/// let g = UnGraph::<(), i32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 1),
+///     (0, 2, 2), (1, 3, 2),
+/// ]);
+///
+/// let (cost, tour) = christofides(&g, |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 4);
+/// assert_eq!(tour.len(), 4);
+/// ```
+pub fn christofides<G, F, K>(graph: G, edge_cost: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<Output = K> + Div<Output = K> + From<u8>,
+{
+    let costs = Costs::new(graph, edge_cost, true);
+    let n = costs.len();
+    let mut complete = UnGraph::<(), K>::with_capacity(n, n * n.saturating_sub(1) / 2);
+    for _ in 0..n {
+        complete.add_node(());
+    }
+    for a in 0..n {
+        for b in a + 1..n {
+            complete.add_edge(NodeIndex::new(a), NodeIndex::new(b), costs.get(a, b)?);
+        }
+    }
+    if n < 3 {
+        let tour: Vec<usize> = (0..n).collect();
+        return Some((costs.tour_cost(&tour)?, costs.node_ids(&tour)));
+    }
+
+    let mut multigraph = UnGraph::<(), K>::from_elements(min_spanning_tree(&complete));
+    let odd: Vec<NodeIndex> = multigraph
+        .node_indices()
+        .filter(|&a| multigraph.edges(a).count() % 2 == 1)
+        .collect();
+    let mut odd_graph = UnGraph::<(), K>::with_capacity(odd.len(), 0);
+    let mut heaviest = K::default();
+    for _ in 0..odd.len() {
+        odd_graph.add_node(());
+    }
+    for (i, &a) in odd.iter().enumerate() {
+        for (j, &b) in odd.iter().enumerate().skip(i + 1) {
+            let cost = costs.get(a.index(), b.index()).unwrap();
+            if heaviest < cost {
+                heaviest = cost;
+            }
+            odd_graph.add_edge(NodeIndex::new(i), NodeIndex::new(j), cost);
+        }
+    }
+    // All the odd nodes are matched, so the heaviest matching of the
+    // reversed costs is the cheapest one.
+    let matching = maximum_weight_matching(&odd_graph, true, |e| heaviest - *e.weight());
+    for (i, j) in matching.edges() {
+        let (a, b) = (odd[i.index()], odd[j.index()]);
+        multigraph.add_edge(a, b, costs.get(a.index(), b.index()).unwrap());
+    }
+
+    let mut visited = vec![false; n];
+    let mut tour = Vec::with_capacity(n);
+    for node in eulerian_circuit(&multigraph).ok()?.nodes {
+        let node = node.index();
+        if !visited[node] {
+            visited[node] = true;
+            tour.push(node);
+        }
+    }
+    Some((costs.tour_cost(&tour)?, costs.node_ids(&tour)))
+}
+
+/// The most nodes for [`held_karp`], whose table has 2<sup>|V| - 1</sup>·(|V| - 1)
+/// entries.
+const HELD_KARP_MAX_NODES: usize = 24;
+
+/// \[Generic\] Optimal tour of the graph with the [Held–Karp
+/// algorithm][held_karp].
+///
+/// The dynamic program computes the cheapest path from the first node of
+/// `graph.node_identifiers()` through each set of other nodes, so it needs
+/// exponential time and memory, and is only practical for graphs of about
+/// twenty nodes. Edges are followed in their direction in a directed graph.
+///
+/// # Panics
+/// If the graph has more than 24 nodes, whose table would take gigabytes of
+/// memory.
+///
+/// Returns the total cost of an optimal tour, with its nodes in order
+/// starting at the first node of `graph.node_identifiers()`, or `None` if
+/// the graph has no tour through all its nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(2<sup>|V|</sup>·|V|² + |E|)**.
+/// * Auxiliary space: **O(2<sup>|V|</sup>·|V|)**.
+///
+/// [held_karp]: https://en.wikipedia.org/wiki/Held%E2%80%93Karp_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::held_karp;
+/// use petgraph::prelude::*;
+///
+/// // Going around 0 -> 1 -> 2 -> 3 costs 4, and the other way 8.
+/// let g = DiGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 1),
+///     (1, 0, 2), (2, 1, 2), (3, 2, 2), (0, 3, 2),
+///     (0, 2, 1), (2, 0, 1), (1, 3, 1), (3, 1, 1),
+/// ]);
+///
+/// let (cost, tour) = held_karp(&g, |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 4);
+/// assert_eq!(tour, vec![0.into(), 1.into(), 2.into(), 3.into()]);
+/// ```
+pub fn held_karp<G, F, K>(graph: G, edge_cost: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let costs = Costs::new(graph, edge_cost, !graph.is_directed());
+    let n = costs.len();
+    assert!(
+        n <= HELD_KARP_MAX_NODES,
+        "held_karp: {} nodes is more than the limit of {}",
+        n,
+        HELD_KARP_MAX_NODES
+    );
+    if n <= 1 {
+        let tour: Vec<usize> = (0..n).collect();
+        return Some((K::default(), costs.node_ids(&tour)));
+    }
+    // The cheapest path from the node 0 through the set of the other nodes
+    // `set`, ending at the node `last + 1`, with the node before it.
+    let others = n - 1;
+    let mut paths: Vec<Option<(K, usize)>> = vec![None; (1 << others) * others];
+    for last in 0..others {
+        paths[(1 << last) * others + last] = costs.get(0, last + 1).map(|cost| (cost, 0));
+    }
+    for set in 1..1usize << others {
+        for last in 0..others {
+            let (cost, _) = match paths[set * others + last] {
+                Some(path) if set & 1 << last != 0 => path,
+                _ => continue,
+            };
+            for next in 0..others {
+                if set & 1 << next != 0 {
+                    continue;
+                }
+                if let Some(step) = costs.get(last + 1, next + 1) {
+                    let cost = cost + step;
+                    let path = &mut paths[(set | 1 << next) * others + next];
+                    if path.map_or(true, |(best, _)| cost < best) {
+                        *path = Some((cost, last + 1));
+                    }
+                }
+            }
+        }
+    }
+
+    let all = (1 << others) - 1;
+    let mut best: Option<(K, usize)> = None;
+    for last in 0..others {
+        if let (Some((cost, _)), Some(back)) = (paths[all * others + last], costs.get(last + 1, 0))
+        {
+            let cost = cost + back;
+            if best.map_or(true, |(best, _)| cost < best) {
+                best = Some((cost, last + 1));
+            }
+        }
+    }
+    let (cost, mut node) = best?;
+    let mut tour = Vec::with_capacity(n);
+    let mut set = all;
+    while node != 0 {
+        tour.push(node);
+        let (_, previous) = paths[set * others + node - 1].unwrap();
+        set &= !(1 << (node - 1));
+        node = previous;
+    }
+    tour.push(0);
+    tour.reverse();
+    Some((cost, costs.node_ids(&tour)))
+}
+
+/// \[Generic\] Search a Hamiltonian path of the graph, by backtracking.
+///
+/// A Hamiltonian path goes through each node of the graph exactly once.
+/// The path is searched from each node in the order of
+/// `graph.node_identifiers()`, following the edges given by
+/// `graph.neighbors`, so in a directed graph it follows the edge
+/// directions. The search takes exponential time in the worst case.
+///
+/// Returns the nodes of the first path found, or `None` if the graph has no
+/// Hamiltonian path.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|!)** in the worst case.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::hamiltonian_path;
+/// use petgraph::prelude::*;
+///
+/// // A star has no Hamiltonian path, until its leaves are linked.
+/// let mut g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(hamiltonian_path(&g), None);
+///
+/// g.extend_with_edges(&[(1, 2)]);
+/// let path = hamiltonian_path(&g).unwrap();
+/// assert_eq!(path, vec![1.into(), 2.into(), 0.into(), 3.into()]);
+/// ```
+pub fn hamiltonian_path<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut positions = vec![std::usize::MAX; graph.node_bound()];
+    for (i, &node) in nodes.iter().enumerate() {
+        positions[graph.to_index(node)] = i;
+    }
+    let neighbors: Vec<Vec<usize>> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &node)| {
+            let mut neighbors: Vec<usize> = graph
+                .neighbors(node)
+                .map(|next| positions[graph.to_index(next)])
+                .filter(|&next| next != i)
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors
+        })
+        .collect();
+
+    let n = nodes.len();
+    if n == 0 {
+        return Some(Vec::new());
+    }
+    let mut visited = vec![false; n];
+    for start in 0..n {
+        // The current path, with the next neighbor to try from each node.
+        let mut path = vec![(start, 0)];
+        visited[start] = true;
+        while let Some(&(node, tried)) = path.last() {
+            if path.len() == n {
+                return Some(path.iter().map(|&(node, _)| nodes[node]).collect());
+            }
+            match neighbors[node][tried..]
+                .iter()
+                .position(|&next| !visited[next])
+            {
+                Some(skipped) => {
+                    let next = neighbors[node][tried + skipped];
+                    path.last_mut().unwrap().1 = tried + skipped + 1;
+                    visited[next] = true;
+                    path.push((next, 0));
+                }
+                None => {
+                    visited[node] = false;
+                    path.pop();
+                }
+            }
+        }
+    }
+    None
+}
+
+/// The cheapest edge between each pair of nodes of a graph, by position in
+/// `graph.node_identifiers()`.
+struct Costs<N, K> {
+    nodes: Vec<N>,
+    /// The position of each node index, `usize::MAX` for the indices that
+    /// are not nodes.
+    positions: Vec<usize>,
+    costs: Vec<Option<K>>,
+}
+
+impl<N, K> Costs<N, K>
+where
+    N: Copy,
+    K: Measure + Copy,
+{
+    fn new<G, F>(graph: G, mut edge_cost: F, symmetric: bool) -> Self
+    where
+        G: IntoEdgeReferences<NodeId = N> + IntoNodeIdentifiers + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> K,
+    {
+        let nodes: Vec<N> = graph.node_identifiers().collect();
+        let mut positions = vec![std::usize::MAX; graph.node_bound()];
+        for (i, &node) in nodes.iter().enumerate() {
+            positions[graph.to_index(node)] = i;
+        }
+        let n = nodes.len();
+        let mut costs = vec![None; n * n];
+        for edge in graph.edge_references() {
+            let a = positions[graph.to_index(edge.source())];
+            let b = positions[graph.to_index(edge.target())];
+            let cost = edge_cost(edge);
+            let mut set = |i: usize| {
+                let current: &mut Option<K> = &mut costs[i];
+                if current.map_or(true, |current| cost < current) {
+                    *current = Some(cost);
+                }
+            };
+            set(a * n + b);
+            if symmetric {
+                set(b * n + a);
+            }
+        }
+        Costs {
+            nodes,
+            positions,
+            costs,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn get(&self, a: usize, b: usize) -> Option<K> {
+        self.costs[a * self.len() + b]
+    }
+
+    /// The cost of the closed tour, or `None` if it uses a missing edge.
+    fn tour_cost(&self, tour: &[usize]) -> Option<K> {
+        let mut cost = K::default();
+        if tour.len() > 1 {
+            for (i, &a) in tour.iter().enumerate() {
+                cost = cost + self.get(a, tour[(i + 1) % tour.len()])?;
+            }
+        }
+        Some(cost)
+    }
+
+    fn tour_positions<G>(&self, graph: G, tour: &[N]) -> Vec<usize>
+    where
+        G: NodeIndexable<NodeId = N>,
+    {
+        tour.iter()
+            .map(|&node| self.positions[graph.to_index(node)])
+            .collect()
+    }
+
+    fn node_ids(&self, tour: &[usize]) -> Vec<N> {
+        tour.iter().map(|&position| self.nodes[position]).collect()
+    }
+
+    /// The first place where moving the run of `len` nodes starting at `i`
+    /// makes the tour cheaper, as the node to insert the run after, and
+    /// whether to reverse it.
+    fn improving_insertion(&self, tour: &[usize], i: usize, len: usize) -> Option<(usize, bool)> {
+        let n = tour.len();
+        let first = tour[i];
+        let last = tour[i + len - 1];
+        let previous = tour[(i + n - 1) % n];
+        let next = tour[(i + len) % n];
+        let removed = self.get(previous, next)?;
+        let before = self.get(previous, first).unwrap() + self.get(last, next).unwrap();
+        // The edges of the tour that do not touch the run.
+        for k in i + len..i + n - 1 {
+            let (a, b) = (tour[k % n], tour[(k + 1) % n]);
+            let old = before + self.get(a, b).unwrap();
+            for &reversed in &[false, true] {
+                let (x, y) = if reversed {
+                    (last, first)
+                } else {
+                    (first, last)
+                };
+                if let (Some(ax), Some(yb)) = (self.get(a, x), self.get(y, b)) {
+                    if removed + ax + yb < old {
+                        return Some((a, reversed));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
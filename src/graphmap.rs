@@ -1256,7 +1256,11 @@ where
     }
 
     fn to_index(&self, ix: Self::EdgeId) -> usize {
-        self.edges.get_index_of(&ix).unwrap()
+        // Undirected edges are stored under a single orientation, so `(b, a)`
+        // has to be normalised before looking up its index.
+        self.edges
+            .get_index_of(&Self::edge_key(ix.0, ix.1))
+            .unwrap()
     }
 
     fn from_index(&self, ix: usize) -> Self::EdgeId {
@@ -0,0 +1,226 @@
+use petgraph::algo::{
+    articulation_points, biconnected_components, block_cut_tree, bridges, BlockCutNode,
+};
+use petgraph::csr::Csr;
+use petgraph::prelude::*;
+use petgraph::visit::EdgeRef;
+
+// 0 --- 1 --- 2
+//       |     |
+//       3 --- 4 --- 5
+//                   |
+//             7 --- 6 --- 8
+//              \         /
+//               ---------
+const EDGES: &[(u32, u32)] = &[
+    (0, 1),
+    (1, 2),
+    (1, 3),
+    (2, 4),
+    (3, 4),
+    (4, 5),
+    (5, 6),
+    (6, 7),
+    (6, 8),
+    (7, 8),
+];
+
+fn sorted_pairs<N: Ord + Copy>(pairs: impl Iterator<Item = (N, N)>) -> Vec<(N, N)> {
+    let mut pairs: Vec<_> = pairs
+        .map(|(a, b)| if a <= b { (a, b) } else { (b, a) })
+        .collect();
+    pairs.sort();
+    pairs
+}
+
+#[test]
+fn bridges_graph() {
+    let g = UnGraph::<(), ()>::from_edges(EDGES);
+    let found = sorted_pairs(bridges(&g).into_iter().map(|e| (e.source(), e.target())));
+    assert_eq!(
+        found,
+        vec![
+            (0.into(), 1.into()),
+            (4.into(), 5.into()),
+            (5.into(), 6.into())
+        ]
+    );
+}
+
+#[test]
+fn articulation_points_graph() {
+    let g = UnGraph::<(), ()>::from_edges(EDGES);
+    let found: Vec<_> = articulation_points(&g)
+        .into_iter()
+        .map(|n| n.index())
+        .collect();
+    assert_eq!(found, vec![1, 4, 5, 6]);
+}
+
+#[test]
+fn bridges_stable_graph() {
+    let mut g = StableUnGraph::<(), ()>::from_edges(EDGES);
+    // Removing node 0 leaves a hole in the indices.
+    g.remove_node(0.into());
+    let found = sorted_pairs(bridges(&g).into_iter().map(|e| (e.source(), e.target())));
+    assert_eq!(found, vec![(4.into(), 5.into()), (5.into(), 6.into())]);
+
+    let found: Vec<_> = articulation_points(&g)
+        .into_iter()
+        .map(|n| n.index())
+        .collect();
+    assert_eq!(found, vec![4, 5, 6]);
+}
+
+#[test]
+fn bridges_graphmap() {
+    let g = UnGraphMap::<u32, ()>::from_edges(EDGES);
+    let found = sorted_pairs(bridges(&g).into_iter().map(|(a, b, _)| (a, b)));
+    assert_eq!(found, vec![(0, 1), (4, 5), (5, 6)]);
+
+    let mut found = articulation_points(&g);
+    found.sort();
+    assert_eq!(found, vec![1, 4, 5, 6]);
+}
+
+#[test]
+fn bridges_csr() {
+    let mut g: Csr<(), (), Undirected> = Csr::with_nodes(9);
+    for &(a, b) in EDGES {
+        g.add_edge(a, b, ());
+    }
+    let found = sorted_pairs(bridges(&g).into_iter().map(|e| (e.source(), e.target())));
+    assert_eq!(found, vec![(0, 1), (4, 5), (5, 6)]);
+    assert_eq!(articulation_points(&g), vec![1, 4, 5, 6]);
+}
+
+#[test]
+fn parallel_edges_and_self_loops() {
+    let mut g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (1, 1)]);
+    let found = sorted_pairs(bridges(&g).into_iter().map(|e| (e.source(), e.target())));
+    assert_eq!(found, vec![(0.into(), 1.into()), (1.into(), 2.into())]);
+    assert_eq!(articulation_points(&g), vec![1.into()]);
+
+    // A parallel edge is never a bridge.
+    g.add_edge(1.into(), 2.into(), ());
+    let found = sorted_pairs(bridges(&g).into_iter().map(|e| (e.source(), e.target())));
+    assert_eq!(found, vec![(0.into(), 1.into())]);
+    assert_eq!(articulation_points(&g), vec![1.into()]);
+}
+
+#[test]
+fn disconnected_and_trivial() {
+    let g = UnGraph::<(), ()>::default();
+    assert!(bridges(&g).is_empty());
+    assert!(articulation_points(&g).is_empty());
+
+    // Two triangles and an isolated node.
+    let mut g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+    g.add_node(());
+    assert!(bridges(&g).is_empty());
+    assert!(articulation_points(&g).is_empty());
+}
+
+#[test]
+fn path_root_is_not_cut() {
+    // The DFS root is an endpoint of the path and must not be reported.
+    let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+    assert_eq!(bridges(&g).len(), 3);
+    assert_eq!(articulation_points(&g), vec![1.into(), 2.into()]);
+
+    // Star: the root is the centre and has several DFS children.
+    let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+    assert_eq!(articulation_points(&g), vec![0.into()]);
+}
+
+// Group edge endpoints by component id.
+fn component_groups(g: &UnGraph<(), ()>) -> Vec<Vec<(usize, usize)>> {
+    let labels = biconnected_components(g);
+    let n_components = labels.iter().flatten().max().map_or(0, |&c| c + 1);
+    let mut groups = vec![Vec::new(); n_components];
+    for edge in g.edge_references() {
+        if let Some(c) = labels[edge.id().index()] {
+            groups[c].push((edge.source().index(), edge.target().index()));
+        }
+    }
+    for group in &mut groups {
+        group.sort();
+    }
+    groups.sort();
+    groups
+}
+
+#[test]
+fn biconnected_components_graph() {
+    let g = UnGraph::<(), ()>::from_edges(EDGES);
+    assert_eq!(
+        component_groups(&g),
+        vec![
+            vec![(0, 1)],
+            vec![(1, 2), (1, 3), (2, 4), (3, 4)],
+            vec![(4, 5)],
+            vec![(5, 6)],
+            vec![(6, 7), (6, 8), (7, 8)],
+        ]
+    );
+}
+
+#[test]
+fn biconnected_components_multigraph() {
+    // Parallel edges form a block; the self-loop is unlabeled.
+    let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 0), (1, 2), (2, 2)]);
+    let labels = biconnected_components(&g);
+    assert_eq!(labels[0], labels[1]);
+    assert_ne!(labels[0], labels[2]);
+    assert!(labels[2].is_some());
+    assert_eq!(labels[3], None);
+}
+
+#[test]
+fn biconnected_components_stable_graph_and_graphmap() {
+    let mut g = StableUnGraph::<(), ()>::from_edges(EDGES);
+    g.remove_edge(EdgeIndex::new(0));
+    let labels = biconnected_components(&g);
+    assert_eq!(labels.len(), EDGES.len());
+    assert_eq!(labels[0], None);
+    assert_eq!(labels.iter().flatten().max(), Some(&3));
+
+    let g = UnGraphMap::<u32, ()>::from_edges(EDGES);
+    let labels = biconnected_components(&g);
+    let label = |a, b| labels[petgraph::visit::EdgeIndexable::to_index(&&g, (a, b))];
+    assert_eq!(label(1, 2), label(4, 3));
+    assert_eq!(label(7, 8), label(6, 7));
+    assert_ne!(label(4, 5), label(5, 6));
+    assert!(labels.iter().all(Option::is_some));
+}
+
+#[test]
+fn block_cut_tree_graph() {
+    let mut g = UnGraph::<(), ()>::from_edges(EDGES);
+    // An isolated node is not part of the tree.
+    g.add_node(());
+    let tree = block_cut_tree(&g);
+    assert_eq!(tree.node_count(), 5 + 4);
+    assert_eq!(tree.edge_count(), 8);
+
+    let labels = biconnected_components(&g);
+    for (i, edge) in g.edge_references().enumerate() {
+        let block = NodeIndex::new(labels[i].unwrap());
+        match &tree[block] {
+            BlockCutNode::Block(nodes) => {
+                assert!(nodes.contains(&edge.source()));
+                assert!(nodes.contains(&edge.target()));
+            }
+            BlockCutNode::Cut(_) => panic!("expected a block"),
+        }
+    }
+
+    let cuts: Vec<_> = tree
+        .node_indices()
+        .filter_map(|n| match tree[n] {
+            BlockCutNode::Cut(c) => Some((c.index(), tree.neighbors(n).count())),
+            BlockCutNode::Block(_) => None,
+        })
+        .collect();
+    assert_eq!(cuts, vec![(1, 2), (4, 2), (5, 2), (6, 2)]);
+}
@@ -0,0 +1,281 @@
+mod common;
+
+use common::assert_close;
+use petgraph::algo::{
+    betweenness_centrality, closeness_centrality, edge_betweenness_centrality,
+    eigenvector_centrality, harmonic_centrality, hits, katz_centrality,
+    weighted_betweenness_centrality, weighted_edge_betweenness_centrality,
+};
+use petgraph::prelude::*;
+
+#[cfg(feature = "rayon")]
+use petgraph::algo::{
+    parallel_betweenness_centrality, parallel_closeness_centrality, parallel_harmonic_centrality,
+    parallel_weighted_betweenness_centrality,
+};
+
+/// Krackhardt's kite.
+fn kite() -> UnGraph<(), ()> {
+    UnGraph::from_edges(&[
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (0, 5),
+        (1, 3),
+        (1, 4),
+        (1, 6),
+        (2, 3),
+        (2, 5),
+        (3, 4),
+        (3, 5),
+        (3, 6),
+        (4, 6),
+        (5, 6),
+        (5, 7),
+        (6, 7),
+        (7, 8),
+        (8, 9),
+    ])
+}
+
+fn directed() -> Graph<(), ()> {
+    Graph::from_edges(&[
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 4),
+        (4, 2),
+        (1, 4),
+        (5, 3),
+    ])
+}
+
+/// A weighted graph where the direct edges 0 - 2 and 1 - 3 are not on
+/// shortest paths.
+fn weighted() -> UnGraph<(), f64> {
+    UnGraph::from_edges(&[
+        (0, 1, 1.),
+        (1, 2, 2.),
+        (0, 2, 3.),
+        (2, 3, 1.),
+        (1, 3, 4.),
+        (3, 4, 2.),
+    ])
+}
+
+#[test]
+fn betweenness_kite() {
+    let g = kite();
+    let expected = [
+        0.023148, 0.023148, 0., 0.101852, 0., 0.231481, 0.231481, 0.388889, 0.222222, 0.,
+    ];
+    assert_close(&betweenness_centrality(&g, true), &expected);
+
+    let scores: Vec<f64> = betweenness_centrality(&g, false);
+    assert_close(
+        &scores,
+        &[
+            0.833333, 0.833333, 0., 3.666667, 0., 8.333333, 8.333333, 14., 8., 0.,
+        ],
+    );
+}
+
+#[test]
+fn betweenness_directed() {
+    let g = directed();
+    let scores: Vec<f64> = betweenness_centrality(&g, false);
+    assert_close(&scores, &[4., 3., 10., 5., 6., 0.]);
+
+    let scores: Vec<f64> = edge_betweenness_centrality(&g, false);
+    let expected = [8., 5., 9., 5., 9., 10., 2., 5.];
+    assert_close(&scores, &expected);
+
+    // There are 30 ordered pairs of nodes.
+    let scores: Vec<f64> = edge_betweenness_centrality(&g, true);
+    assert_close(
+        &scores,
+        &expected.iter().map(|score| score / 30.).collect::<Vec<_>>(),
+    );
+}
+
+#[test]
+fn betweenness_weighted() {
+    let g = weighted();
+    let scores: Vec<f64> = weighted_betweenness_centrality(&g, false, |e| *e.weight());
+    assert_close(&scores, &[0., 1.5, 4., 3., 0.]);
+
+    let scores: Vec<f64> = weighted_edge_betweenness_centrality(&g, false, |e| *e.weight());
+    assert_close(&scores, &[2.5, 4.5, 1.5, 6., 0., 4.]);
+
+    // With unit costs, the paths are the same as without costs.
+    let unit: Vec<f64> = weighted_betweenness_centrality(&g, true, |_| 1);
+    assert_eq!(unit, betweenness_centrality(&g, true));
+    let unit: Vec<f64> = weighted_edge_betweenness_centrality(&g, true, |_| 1);
+    assert_eq!(unit, edge_betweenness_centrality(&g, true));
+}
+
+#[test]
+fn betweenness_multigraph() {
+    // Two of the three shortest paths from 0 to 2 go through 1, and two of
+    // the three from 1 to 3 through 0.
+    let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 1), (1, 2), (0, 3), (3, 2), (1, 1)]);
+    let scores: Vec<f64> = betweenness_centrality(&g, false);
+    assert_close(&scores, &[2. / 3., 2. / 3., 1. / 3., 1. / 3.]);
+    let scores: Vec<f64> = edge_betweenness_centrality(&g, false);
+    assert_eq!(scores[5], 0.);
+}
+
+#[test]
+fn betweenness_stable_graph() {
+    let mut g = StableUnGraph::<(), ()>::default();
+    let n: Vec<_> = (0..5).map(|_| g.add_node(())).collect();
+    g.extend_with_edges(&[(0, 1), (1, 2), (2, 3), (3, 4)]);
+    g.remove_node(n[0]);
+
+    let scores: Vec<f64> = betweenness_centrality(&g, false);
+    assert_eq!(scores, vec![0., 0., 2., 2., 0.]);
+    let scores: Vec<f64> = edge_betweenness_centrality(&g, false);
+    assert_eq!(scores, vec![0., 3., 4., 3.]);
+}
+
+#[test]
+fn betweenness_graphmap() {
+    let g = DiGraphMap::<&str, ()>::from_edges(&[("a", "b"), ("b", "c"), ("c", "d")]);
+    let scores: Vec<f64> = betweenness_centrality(&g, true);
+    assert_eq!(scores, vec![0., 2. / 6., 2. / 6., 0.]);
+}
+
+#[test]
+fn closeness_kite() {
+    let g = kite();
+    assert_close(
+        &closeness_centrality(&g, |_| 1.),
+        &[
+            0.529412, 0.529412, 0.5, 0.6, 0.5, 0.642857, 0.642857, 0.6, 0.428571, 0.310345,
+        ],
+    );
+    assert_close(
+        &harmonic_centrality(&g, |_| 1.),
+        &[
+            6.083333, 6.083333, 5.583333, 7.083333, 5.583333, 6.833333, 6.833333, 6., 4.666667,
+            3.416667,
+        ],
+    );
+}
+
+#[test]
+fn closeness_directed() {
+    let g = directed();
+    assert_close(
+        &closeness_centrality(&g, |_| 1.),
+        &[0.4, 0.533333, 0.533333, 0.32, 0.4, 0.333333],
+    );
+    assert_close(
+        &harmonic_centrality(&g, |_| 1.),
+        &[2.333333, 3., 3., 2.083333, 2.333333, 2.283333],
+    );
+
+    // A node that can not reach any other.
+    let g = Graph::<(), ()>::from_edges(&[(0, 1)]);
+    assert_eq!(closeness_centrality(&g, |_| 1.), vec![1., 0.]);
+    assert_eq!(harmonic_centrality(&g, |_| 1.), vec![1., 0.]);
+}
+
+#[test]
+fn closeness_weighted() {
+    let g = weighted();
+    assert_close(
+        &closeness_centrality(&g, |e| *e.weight()),
+        &[0.285714, 0.363636, 0.444444, 0.4, 0.25],
+    );
+}
+
+#[test]
+fn eigenvector_kite() {
+    let g = kite();
+    assert_close(
+        &eigenvector_centrality(&g, 1000, Some(1e-15)),
+        &[
+            0.125449, 0.125449, 0.101808, 0.171329, 0.101808, 0.141648, 0.141648, 0.069761,
+            0.017123, 0.003976,
+        ],
+    );
+}
+
+#[test]
+fn eigenvector_bipartite() {
+    // An even cycle converges, even though it is bipartite.
+    let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let scores = eigenvector_centrality(&g, 1000, Some(1e-15));
+    assert_close(&scores, &[0.25; 4]);
+}
+
+#[test]
+fn katz() {
+    let g = kite();
+    assert_close(
+        &katz_centrality(&g, 0.1, 1., 1000, Some(1e-15)),
+        &[
+            1.717594, 1.717594, 1.561449, 2.029323, 1.561449, 1.867571, 1.867571, 1.499775,
+            1.262603, 1.12626,
+        ],
+    );
+
+    let g = directed();
+    assert_close(
+        &katz_centrality(&g, 0.2, 1., 1000, Some(1e-15)),
+        &[1.312704, 1.262541, 1.563518, 1.512704, 1.555049, 1.],
+    );
+}
+
+#[test]
+fn hits_bipartite() {
+    // 0 and 1 link to 2, 3 and 4, and 0 also to 1.
+    let g = Graph::<(), ()>::from_edges(&[(0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4)]);
+    let (hubs, authorities) = hits(&g, 1000, Some(1e-15));
+    assert_close(&hubs, &[0.5, 0.5, 0., 0., 0.]);
+    assert_close(&authorities, &[0., 0., 1. / 3., 1. / 3., 1. / 3.]);
+
+    // Without edges the authorities are all zero.
+    let mut g = Graph::<(), ()>::new();
+    g.add_node(());
+    g.add_node(());
+    let (hubs, authorities) = hits(&g, 10, None::<f64>);
+    assert_eq!(hubs, vec![0., 0.]);
+    assert_eq!(authorities, vec![0., 0.]);
+}
+
+#[test]
+fn power_iteration_stable_graph() {
+    let mut g = StableGraph::<(), ()>::default();
+    let n: Vec<_> = (0..4).map(|_| g.add_node(())).collect();
+    g.extend_with_edges(&[(0, 1), (1, 2), (2, 3), (3, 1)]);
+    g.remove_node(n[0]);
+
+    let scores = eigenvector_centrality(&g, 1000, Some(1e-15));
+    assert_close(&scores, &[0., 1. / 3., 1. / 3., 1. / 3.]);
+    let scores = katz_centrality(&g, 0.5, 1., 1000, Some(1e-15));
+    assert_close(&scores, &[0., 2., 2., 2.]);
+    let (hubs, _) = hits(&g, 1000, Some(1e-15));
+    assert_close(&hubs, &[0., 1. / 3., 1. / 3., 1. / 3.]);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn parallel_centrality() {
+    let g = kite();
+    let scores: Vec<f64> = parallel_betweenness_centrality(&g, true);
+    assert_close(&scores, &betweenness_centrality(&g, true));
+    let scores: Vec<f64> = parallel_closeness_centrality(&g, |_| 1.);
+    assert_close(&scores, &closeness_centrality(&g, |_| 1.));
+    let scores: Vec<f64> = parallel_harmonic_centrality(&g, |_| 1.);
+    assert_close(&scores, &harmonic_centrality(&g, |_| 1.));
+
+    let g = weighted();
+    let scores: Vec<f64> = parallel_weighted_betweenness_centrality(&g, false, |e| *e.weight());
+    assert_close(
+        &scores,
+        &weighted_betweenness_centrality(&g, false, |e| *e.weight()),
+    );
+}
@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+
+use petgraph::algo::{maximal_cliques, maximum_clique};
+use petgraph::prelude::*;
+use petgraph::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// The maximal cliques by node index, each sorted.
+fn sorted_cliques<G>(g: G) -> Vec<Vec<usize>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut cliques: Vec<Vec<_>> = maximal_cliques(g)
+        .map(|clique| {
+            let mut clique: Vec<_> = clique.into_iter().map(|n| g.to_index(n)).collect();
+            clique.sort();
+            clique
+        })
+        .collect();
+    cliques.sort();
+    cliques
+}
+
+#[test]
+fn maximal_cliques_small() {
+    let empty = UnGraph::<(), ()>::default();
+    assert_eq!(maximal_cliques(&empty).count(), 0);
+    assert!(maximum_clique(&empty).is_empty());
+
+    // Isolated nodes and self loops.
+    let mut g = UnGraph::<(), ()>::from_edges(&[(0, 0), (1, 2)]);
+    g.add_node(());
+    assert_eq!(sorted_cliques(&g), vec![vec![0], vec![1, 2], vec![3]]);
+    let mut clique = maximum_clique(&g);
+    clique.sort();
+    assert_eq!(clique, vec![1.into(), 2.into()]);
+}
+
+#[test]
+fn maximal_cliques_moon_moser() {
+    // The complete tripartite graph K(3, 3, 3) has 3^3 maximal cliques, all
+    // of size 3.
+    let mut edges = vec![];
+    for i in 0..9 {
+        for j in 0..i {
+            if i % 3 != j % 3 {
+                edges.push((i, j));
+            }
+        }
+    }
+    let g = UnGraph::<(), ()>::from_edges(&edges);
+    let cliques = sorted_cliques(&g);
+    assert_eq!(cliques.len(), 27);
+    assert!(cliques.iter().all(|clique| clique.len() == 3));
+    let distinct: HashSet<_> = cliques.iter().collect();
+    assert_eq!(distinct.len(), 27);
+    assert_eq!(maximum_clique(&g).len(), 3);
+}
+
+#[test]
+fn cliques_directed() {
+    // Edge directions are ignored, and parallel edges do not matter.
+    let g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (0, 2), (3, 2), (3, 1), (4, 3)]);
+    assert_eq!(
+        sorted_cliques(&g),
+        vec![vec![0, 1, 2], vec![1, 2, 3], vec![3, 4]]
+    );
+    assert_eq!(maximum_clique(&g).len(), 3);
+}
+
+#[test]
+fn maximum_clique_complement_of_cycle() {
+    // The complement of a cycle of length 10 has cliques of size 5.
+    let mut edges = vec![];
+    for i in 0..10 {
+        for j in 0..i {
+            if i - j != 1 && i - j != 9 {
+                edges.push((i, j));
+            }
+        }
+    }
+    let g = UnGraph::<(), ()>::from_edges(&edges);
+    let clique = maximum_clique(&g);
+    assert_eq!(clique.len(), 5);
+    for &a in &clique {
+        for &b in &clique {
+            assert!(a == b || g.contains_edge(a, b));
+        }
+    }
+    let largest = maximal_cliques(&g).map(|clique| clique.len()).max();
+    assert_eq!(largest, Some(5));
+}
+
+#[test]
+fn cliques_graphmap() {
+    let g = UnGraphMap::<&str, ()>::from_edges(&[
+        ("alice", "bob"),
+        ("alice", "carol"),
+        ("bob", "carol"),
+        ("carol", "dave"),
+        ("dave", "erin"),
+        ("carol", "erin"),
+    ]);
+    let mut cliques: Vec<Vec<_>> = maximal_cliques(&g)
+        .map(|mut clique| {
+            clique.sort();
+            clique
+        })
+        .collect();
+    cliques.sort();
+    assert_eq!(
+        cliques,
+        vec![vec!["alice", "bob", "carol"], vec!["carol", "dave", "erin"]]
+    );
+    assert_eq!(maximum_clique(&g).len(), 3);
+}
+
+#[test]
+fn cliques_stable_graph() {
+    let mut g = StableUnGraph::<(), ()>::default();
+    let n: Vec<_> = (0..5).map(|_| g.add_node(())).collect();
+    g.extend_with_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+    g.remove_node(n[0]);
+
+    assert_eq!(sorted_cliques(&g), vec![vec![1, 2], vec![2, 3, 4]]);
+    let mut clique = maximum_clique(&g);
+    clique.sort();
+    assert_eq!(clique, vec![n[2], n[3], n[4]]);
+}
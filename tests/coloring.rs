@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use petgraph::algo::{
+    bipartite_edge_coloring, chromatic_number, chromatic_number_dense, edge_coloring,
+    greedy_coloring, greedy_coloring_dense, ColoringOrder,
+};
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoEdges};
+
+const ORDERS: [ColoringOrder; 3] = [
+    ColoringOrder::LargestFirst,
+    ColoringOrder::SmallestLast,
+    ColoringOrder::DSatur,
+];
+
+fn assert_proper<G>(g: G, coloring: &HashMap<G::NodeId, usize>)
+where
+    G: IntoEdgeReferences,
+    G::NodeId: Eq + Hash,
+{
+    for edge in g.edge_references() {
+        if edge.source() != edge.target() {
+            assert_ne!(coloring[&edge.source()], coloring[&edge.target()]);
+        }
+    }
+}
+
+fn count(coloring: &HashMap<NodeIndex, usize>) -> usize {
+    coloring.values().max().map_or(0, |&c| c + 1)
+}
+
+/// The wheel graph: a cycle of `n` nodes around a center node `n`.
+fn wheel(n: u32) -> UnGraph<(), ()> {
+    let mut edges = vec![];
+    for i in 0..n {
+        edges.push((i, (i + 1) % n));
+        edges.push((i, n));
+    }
+    UnGraph::from_edges(&edges)
+}
+
+#[test]
+fn greedy_coloring_is_proper() {
+    let graphs = vec![
+        wheel(5),
+        wheel(8),
+        UnGraph::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (0, 0)]),
+        UnGraph::from_edges(&[(0, 1), (0, 1), (2, 3)]),
+    ];
+    for g in &graphs {
+        for &order in &ORDERS {
+            let coloring = greedy_coloring(g, order);
+            assert_eq!(coloring.len(), g.node_count());
+            assert_proper(g, &coloring);
+        }
+    }
+}
+
+#[test]
+fn greedy_coloring_wheel() {
+    // An even wheel needs 3 colors, an odd one 4.
+    for &order in &ORDERS {
+        assert_eq!(count(&greedy_coloring(&wheel(6), order)), 3);
+        assert_eq!(count(&greedy_coloring(&wheel(7), order)), 4);
+    }
+}
+
+#[test]
+fn dsatur_bipartite() {
+    // The crown graph: 0..5 and 5..10, with an edge between i and j + 5
+    // unless i == j. Coloring in the order 0, 5, 1, 6, 2, 7, ... would need
+    // 5 colors.
+    let mut edges = vec![];
+    for i in 0..5 {
+        for j in 0..5 {
+            if i != j {
+                edges.push((i, j + 5));
+            }
+        }
+    }
+    let g = UnGraph::<(), ()>::from_edges(&edges);
+    let coloring = greedy_coloring(&g, ColoringOrder::DSatur);
+    assert_proper(&g, &coloring);
+    assert_eq!(count(&coloring), 2);
+}
+
+#[test]
+fn smallest_last_degeneracy() {
+    // A tree has degeneracy 1.
+    let g =
+        UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (3, 4), (4, 5), (4, 6), (1, 7)]);
+    let coloring = greedy_coloring(&g, ColoringOrder::SmallestLast);
+    assert_proper(&g, &coloring);
+    assert_eq!(count(&coloring), 2);
+}
+
+#[test]
+fn coloring_directed() {
+    // Edge directions are ignored.
+    let g = Graph::<(), ()>::from_edges(&[(1, 0), (2, 1), (0, 2)]);
+    for &order in &ORDERS {
+        let coloring = greedy_coloring(&g, order);
+        assert_proper(&g, &coloring);
+        assert_eq!(count(&coloring), 3);
+    }
+    assert_eq!(chromatic_number(&g).0, 3);
+}
+
+#[test]
+fn coloring_dense() {
+    let g = wheel(4);
+    for &order in &ORDERS {
+        let colors = greedy_coloring_dense(&g, order);
+        let coloring = greedy_coloring(&g, order);
+        assert_eq!(colors.len(), g.node_count());
+        for n in g.node_indices() {
+            assert_eq!(colors[n.index()], coloring[&n]);
+        }
+    }
+
+    let (count, colors) = chromatic_number_dense(&g);
+    assert_eq!(count, 3);
+    for edge in g.edge_references() {
+        assert_ne!(colors[edge.source().index()], colors[edge.target().index()]);
+    }
+}
+
+#[test]
+fn chromatic_number_small() {
+    let empty = UnGraph::<(), ()>::default();
+    assert_eq!(chromatic_number(&empty), (0, HashMap::new()));
+
+    let mut isolated = UnGraph::<(), ()>::default();
+    isolated.add_node(());
+    isolated.add_node(());
+    assert_eq!(chromatic_number(&isolated).0, 1);
+
+    let complete = UnGraph::<(), ()>::from_edges((0..6).flat_map(|i| (0..i).map(move |j| (i, j))));
+    assert_eq!(chromatic_number(&complete).0, 6);
+
+    let odd_cycle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    assert_eq!(chromatic_number(&odd_cycle).0, 3);
+}
+
+#[test]
+fn chromatic_number_grotzsch() {
+    // The Grötzsch graph has no triangle, but needs 4 colors: a 5-cycle
+    // 0..5, nodes 5..10 with the neighbors of the matching cycle node, and
+    // node 10 linked to 5..10.
+    let mut edges = vec![];
+    for i in 0..5 {
+        edges.push((i, (i + 1) % 5));
+        edges.push((i + 5, (i + 1) % 5));
+        edges.push((i + 5, (i + 4) % 5));
+        edges.push((i + 5, 10));
+    }
+    let g = UnGraph::<(), ()>::from_edges(&edges);
+    let (count, coloring) = chromatic_number(&g);
+    assert_eq!(count, 4);
+    assert_proper(&g, &coloring);
+}
+
+#[test]
+fn coloring_graphmap() {
+    // Exams that share a student can not be at the same time.
+    let g = UnGraphMap::<&str, ()>::from_edges(&[
+        ("algebra", "physics"),
+        ("algebra", "history"),
+        ("physics", "chemistry"),
+        ("history", "chemistry"),
+        ("chemistry", "biology"),
+        ("physics", "biology"),
+    ]);
+    let (count, coloring) = chromatic_number(&g);
+    assert_eq!(count, 3);
+    assert_proper(&g, &coloring);
+    for &order in &ORDERS {
+        assert_proper(&g, &greedy_coloring(&g, order));
+    }
+}
+
+#[test]
+fn coloring_stable_graph() {
+    let mut g = StableUnGraph::<(), ()>::default();
+    let n: Vec<_> = (0..5).map(|_| g.add_node(())).collect();
+    g.extend_with_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)]);
+    g.remove_node(n[1]);
+
+    for &order in &ORDERS {
+        let coloring = greedy_coloring(&g, order);
+        assert_eq!(coloring.len(), 4);
+        assert_proper(&g, &coloring);
+    }
+    let (count, coloring) = chromatic_number(&g);
+    assert_eq!(count, 2);
+    assert_proper(&g, &coloring);
+}
+
+fn assert_proper_edges<G>(g: G, coloring: &HashMap<G::EdgeId, usize>)
+where
+    G: IntoEdges,
+    G::EdgeId: Eq + Hash,
+{
+    for a in g.edge_references() {
+        if a.source() == a.target() {
+            assert!(!coloring.contains_key(&a.id()));
+            continue;
+        }
+        for b in g.edges(a.source()).chain(g.edges(a.target())) {
+            if a.id() != b.id() && b.source() != b.target() {
+                assert_ne!(coloring[&a.id()], coloring[&b.id()]);
+            }
+        }
+    }
+}
+
+fn edge_count(coloring: &HashMap<EdgeIndex, usize>) -> usize {
+    coloring.values().max().map_or(0, |&c| c + 1)
+}
+
+#[test]
+fn edge_coloring_complete() {
+    // A complete graph needs Δ colors with an even number of nodes, and
+    // Δ + 1 with an odd one.
+    for n in 2..9 {
+        let g = UnGraph::<(), ()>::from_edges((0..n).flat_map(|i| (0..i).map(move |j| (i, j))));
+        let coloring = edge_coloring(&g);
+        assert_eq!(coloring.len(), g.edge_count());
+        assert_proper_edges(&g, &coloring);
+        assert!(edge_count(&coloring) <= n as usize);
+    }
+}
+
+#[test]
+fn edge_coloring_petersen() {
+    // The Petersen graph has Δ = 3, but needs 4 colors.
+    let mut edges = vec![];
+    for i in 0..5 {
+        edges.push((i, (i + 1) % 5));
+        edges.push((i, i + 5));
+        edges.push((i + 5, (i + 2) % 5 + 5));
+    }
+    let g = UnGraph::<(), ()>::from_edges(&edges);
+    let coloring = edge_coloring(&g);
+    assert_proper_edges(&g, &coloring);
+    assert_eq!(edge_count(&coloring), 4);
+}
+
+#[test]
+fn edge_coloring_multigraph() {
+    // Shannon's triangle: each pair of nodes is linked twice, and 6 colors
+    // are needed.
+    let g =
+        UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (0, 1), (1, 2), (2, 0), (1, 1)]);
+    let coloring = edge_coloring(&g);
+    assert_eq!(coloring.len(), 6);
+    assert_proper_edges(&g, &coloring);
+    assert_eq!(edge_count(&coloring), 6);
+}
+
+#[test]
+fn edge_coloring_directed() {
+    // Edge directions are ignored.
+    let g = Graph::<(), ()>::from_edges(&[(0, 1), (2, 1), (1, 3)]);
+    let coloring = edge_coloring(&g);
+    assert_proper_edges(&g, &coloring);
+    assert_eq!(edge_count(&coloring), 3);
+}
+
+#[test]
+fn edge_coloring_stable_graph() {
+    let mut g = StableUnGraph::<(), ()>::default();
+    let n: Vec<_> = (0..6).map(|_| g.add_node(())).collect();
+    g.extend_with_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 4), (4, 5), (5, 1)]);
+    g.remove_node(n[4]);
+
+    let coloring = edge_coloring(&g);
+    assert_eq!(coloring.len(), g.edge_count());
+    assert_proper_edges(&g, &coloring);
+
+    let coloring = bipartite_edge_coloring(&g).unwrap();
+    assert_proper_edges(&g, &coloring);
+    assert_eq!(edge_count(&coloring), 3);
+}
+
+#[test]
+fn bipartite_edge_coloring_regular() {
+    // Round robin between two teams of 4: every player meets each player of
+    // the other team in 4 rounds.
+    let mut edges = vec![];
+    for i in 0..4 {
+        for j in 4..8 {
+            edges.push((i, j));
+        }
+    }
+    let g = UnGraph::<(), ()>::from_edges(&edges);
+    let coloring = bipartite_edge_coloring(&g).unwrap();
+    assert_eq!(coloring.len(), 16);
+    assert_proper_edges(&g, &coloring);
+    assert_eq!(edge_count(&coloring), 4);
+}
+
+#[test]
+fn bipartite_edge_coloring_multigraph() {
+    let g =
+        UnGraph::<(), ()>::from_edges(&[(0, 3), (0, 3), (0, 3), (1, 3), (1, 4), (2, 4), (2, 4)]);
+    let coloring = bipartite_edge_coloring(&g).unwrap();
+    assert_proper_edges(&g, &coloring);
+    assert_eq!(edge_count(&coloring), 4);
+
+    let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 1)]);
+    assert_eq!(bipartite_edge_coloring(&g), None);
+}
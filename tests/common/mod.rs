@@ -0,0 +1,7 @@
+/// Assert that two vectors of scores are equal, up to rounding errors.
+pub fn assert_close(computed: &[f64], expected: &[f64]) {
+    assert_eq!(computed.len(), expected.len());
+    for (i, (a, b)) in computed.iter().zip(expected).enumerate() {
+        assert!((a - b).abs() < 1e-5, "{}: {} != {}", i, a, b);
+    }
+}
@@ -0,0 +1,260 @@
+use petgraph::algo::{label_propagation, louvain, modularity, quotient_graph};
+use petgraph::prelude::*;
+use petgraph::visit::NodeIndexable;
+
+/// Zachary's karate club.
+fn karate_club() -> UnGraph<(), ()> {
+    UnGraph::from_edges(&[
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (0, 4),
+        (0, 5),
+        (0, 6),
+        (0, 7),
+        (0, 8),
+        (0, 10),
+        (0, 11),
+        (0, 12),
+        (0, 13),
+        (0, 17),
+        (0, 19),
+        (0, 21),
+        (0, 31),
+        (1, 2),
+        (1, 3),
+        (1, 7),
+        (1, 13),
+        (1, 17),
+        (1, 19),
+        (1, 21),
+        (1, 30),
+        (2, 3),
+        (2, 7),
+        (2, 8),
+        (2, 9),
+        (2, 13),
+        (2, 27),
+        (2, 28),
+        (2, 32),
+        (3, 7),
+        (3, 12),
+        (3, 13),
+        (4, 6),
+        (4, 10),
+        (5, 6),
+        (5, 10),
+        (5, 16),
+        (6, 16),
+        (8, 30),
+        (8, 32),
+        (8, 33),
+        (9, 33),
+        (13, 33),
+        (14, 32),
+        (14, 33),
+        (15, 32),
+        (15, 33),
+        (18, 32),
+        (18, 33),
+        (19, 33),
+        (20, 32),
+        (20, 33),
+        (22, 32),
+        (22, 33),
+        (23, 25),
+        (23, 27),
+        (23, 29),
+        (23, 32),
+        (23, 33),
+        (24, 25),
+        (24, 27),
+        (24, 31),
+        (25, 31),
+        (26, 29),
+        (26, 33),
+        (27, 33),
+        (28, 31),
+        (28, 33),
+        (29, 32),
+        (29, 33),
+        (30, 32),
+        (30, 33),
+        (31, 32),
+        (31, 33),
+        (32, 33),
+    ])
+}
+
+/// The split of the club after the conflict.
+const CLUBS: [usize; 34] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1,
+];
+
+/// Checks that the communities are numbered in order from zero, and that
+/// each of them is connected.
+fn assert_connected_communities<N, E>(g: &UnGraph<N, E>, communities: &[usize]) {
+    let mut count = 0;
+    for &community in communities {
+        assert!(community <= count);
+        count = count.max(community + 1);
+    }
+    for community in 0..count {
+        let start = g
+            .node_indices()
+            .find(|n| communities[n.index()] == community)
+            .unwrap();
+        let mut stack = vec![start];
+        let mut seen = vec![start];
+        while let Some(node) = stack.pop() {
+            for next in g.neighbors(node) {
+                if communities[next.index()] == community && !seen.contains(&next) {
+                    seen.push(next);
+                    stack.push(next);
+                }
+            }
+        }
+        let size = communities.iter().filter(|&&c| c == community).count();
+        assert_eq!(seen.len(), size);
+    }
+}
+
+#[test]
+fn modularity_karate_club() {
+    let g = karate_club();
+    let q: f64 = modularity(&g, &CLUBS, 1., |_| 1.);
+    assert!((q - 0.3582347140039448).abs() < 1e-12);
+    let q: f64 = modularity(&g, &CLUBS, 0.5, |_| 1.);
+    assert!((q - 0.6086045364891519).abs() < 1e-12);
+}
+
+#[test]
+fn modularity_multigraph() {
+    let g = UnGraph::<(), f64>::from_edges(&[
+        (0, 1, 2.),
+        (0, 1, 1.),
+        (1, 2, 1.),
+        (2, 2, 3.),
+        (2, 3, 1.),
+        (3, 4, 2.),
+        (4, 4, 1.),
+    ]);
+    let q = modularity(&g, &[0, 0, 1, 1, 1], 1., |e| *e.weight());
+    assert!((q - 0.3429752066115702).abs() < 1e-12);
+
+    let empty = UnGraph::<(), f64>::default();
+    assert_eq!(modularity(&empty, &[], 1., |e| *e.weight()), 0.);
+}
+
+#[test]
+fn louvain_karate_club() {
+    let g = karate_club();
+    let communities = louvain(&g, 1., |_| 1.);
+    assert_connected_communities(&g, &communities);
+    let q: f64 = modularity(&g, &communities, 1., |_| 1.);
+    assert!(q > 0.41, "{}", q);
+
+    // Without the penalty, merging neighbors is always better.
+    let communities = louvain(&g, 0., |_| 1.);
+    assert_eq!(communities, vec![0; 34]);
+
+    // With a high resolution, the communities are smaller.
+    let communities = louvain(&g, 2., |_| 1.);
+    assert_connected_communities(&g, &communities);
+    assert!(communities.iter().max().unwrap() + 1 > 4);
+}
+
+#[test]
+fn louvain_weighted() {
+    // A ring of six nodes, where the heavy edges pair the nodes.
+    let mut g = UnGraph::<(), f32>::from_edges(&[
+        (0, 1, 5.),
+        (1, 2, 1.),
+        (2, 3, 5.),
+        (3, 4, 1.),
+        (4, 5, 5.),
+        (5, 0, 1.),
+    ]);
+    assert_eq!(louvain(&g, 1., |e| *e.weight()), vec![0, 0, 1, 1, 2, 2]);
+    for w in g.edge_weights_mut() {
+        *w = 6. - *w;
+    }
+    assert_eq!(louvain(&g, 1., |e| *e.weight()), vec![0, 1, 1, 2, 2, 0]);
+}
+
+#[test]
+fn louvain_directed_and_holes() {
+    // Two triangles joined by the edge 2 -> 3, and the isolated node 6.
+    let edges = [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)];
+    let mut g = StableDiGraph::<(), ()>::from_edges(&edges);
+    let six = g.add_node(());
+    g.add_node(());
+    assert_eq!(louvain(&g, 1., |_| 1.), vec![0, 0, 0, 1, 1, 1, 2, 3]);
+
+    g.remove_node(six);
+    g.remove_node(NodeIndex::new(1));
+    assert_eq!(g.node_bound(), 8);
+    let communities = louvain(&g, 1., |_| 1.);
+    let max = std::usize::MAX;
+    assert_eq!(communities, vec![0, max, 0, 1, 1, 1, max, 2]);
+
+    let edgeless = UnGraph::<(), ()>::from_edges(&[(2, 2)]);
+    assert_eq!(louvain(&edgeless, 1., |_| 0.), vec![0, 1, 2]);
+}
+
+#[test]
+fn label_propagation_karate_club() {
+    let g = karate_club();
+    let labels = label_propagation(&g, |_| 1);
+    // Every node has a label that is the most common among its neighbors.
+    for node in g.node_indices() {
+        let mut counts = vec![0; g.node_count()];
+        for next in g.neighbors(node) {
+            counts[labels[next.index()]] += 1;
+        }
+        assert_eq!(counts[labels[node.index()]], *counts.iter().max().unwrap());
+    }
+    let q: f64 = modularity(&g, &labels, 1., |_| 1.);
+    assert!(q > 0.25, "{}", q);
+}
+
+#[test]
+fn label_propagation_components() {
+    let mut g = GraphMap::<u32, u32, Undirected>::from_edges(&[
+        (10, 11, 1),
+        (11, 12, 1),
+        (12, 10, 1),
+        (20, 21, 3),
+        (21, 22, 1),
+        (22, 23, 3),
+    ]);
+    g.add_node(30);
+    let labels = label_propagation(&g, |e| *e.weight());
+    let labels: Vec<usize> = g.nodes().map(|n| labels[g.to_index(n)]).collect();
+    assert_eq!(labels, vec![0, 0, 0, 1, 1, 2, 2, 3]);
+}
+
+#[test]
+fn quotient_graph_directed() {
+    let g = Graph::<&str, u32>::from_edges(&[
+        (0, 1, 1),
+        (1, 0, 2),
+        (1, 2, 4),
+        (2, 3, 8),
+        (3, 2, 16),
+        (0, 3, 32),
+        (3, 0, 64),
+        (1, 3, 128),
+    ]);
+    let q = quotient_graph(&g, &[0, 0, 1, 1], |e| *e.weight());
+    assert_eq!(q.node_count(), 2);
+    assert_eq!(q.edge_count(), 4);
+    let n = NodeIndex::new;
+    assert_eq!(q[n(0)], vec![n(0), n(1)]);
+    assert_eq!(q[n(1)], vec![n(2), n(3)]);
+    assert_eq!(q[q.find_edge(n(0), n(0)).unwrap()], 3);
+    assert_eq!(q[q.find_edge(n(1), n(1)).unwrap()], 24);
+    assert_eq!(q[q.find_edge(n(0), n(1)).unwrap()], 164);
+    assert_eq!(q[q.find_edge(n(1), n(0)).unwrap()], 64);
+}
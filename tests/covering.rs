@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+
+use petgraph::algo::{
+    approx_vertex_cover, greedy_dominating_set, greedy_independent_set, is_dominating_set,
+    is_independent_set, is_vertex_cover, maximum_independent_set, minimum_vertex_cover,
+};
+use petgraph::prelude::*;
+
+fn set(nodes: &[u32]) -> HashSet<NodeIndex> {
+    nodes.iter().map(|&n| NodeIndex::new(n as usize)).collect()
+}
+
+fn petersen() -> UnGraph<(), ()> {
+    let mut edges = vec![];
+    for i in 0..5 {
+        edges.push((i, (i + 1) % 5));
+        edges.push((i, i + 5));
+        edges.push((i + 5, (i + 2) % 5 + 5));
+    }
+    UnGraph::from_edges(&edges)
+}
+
+/// A grid of `n` by `n` nodes.
+fn grid(n: u32) -> UnGraph<(), ()> {
+    let mut edges = vec![];
+    for i in 0..n {
+        for j in 0..n {
+            if i + 1 < n {
+                edges.push((i * n + j, (i + 1) * n + j));
+            }
+            if j + 1 < n {
+                edges.push((i * n + j, i * n + j + 1));
+            }
+        }
+    }
+    UnGraph::from_edges(&edges)
+}
+
+#[test]
+fn covering_small() {
+    let empty = UnGraph::<(), ()>::default();
+    assert!(minimum_vertex_cover(&empty).is_empty());
+    assert!(approx_vertex_cover(&empty).is_empty());
+    assert!(maximum_independent_set(&empty).is_empty());
+    assert!(greedy_independent_set(&empty).is_empty());
+    assert!(greedy_dominating_set(&empty).is_empty());
+
+    // A self loop at 0, the edge 1 - 2, and the isolated node 3.
+    let mut g = UnGraph::<(), ()>::from_edges(&[(0, 0), (1, 2)]);
+    g.add_node(());
+    let cover = minimum_vertex_cover(&g);
+    assert_eq!(cover.len(), 2);
+    assert!(cover.contains(&NodeIndex::new(0)));
+    assert!(approx_vertex_cover(&g).contains(&NodeIndex::new(0)));
+
+    let independent = maximum_independent_set(&g);
+    assert_eq!(independent.len(), 2);
+    assert!(independent.contains(&NodeIndex::new(3)));
+    assert!(!independent.contains(&NodeIndex::new(0)));
+    assert_eq!(greedy_independent_set(&g).len(), 2);
+
+    let dominating = greedy_dominating_set(&g);
+    assert_eq!(dominating.len(), 3);
+    assert!(is_dominating_set(&g, &dominating));
+}
+
+#[test]
+fn minimum_vertex_cover_petersen() {
+    // The Petersen graph has 10 nodes and independence number 4.
+    let g = petersen();
+    let cover = minimum_vertex_cover(&g);
+    assert!(is_vertex_cover(&g, &cover));
+    assert_eq!(cover.len(), 6);
+
+    let set = maximum_independent_set(&g);
+    assert!(is_independent_set(&g, &set));
+    assert_eq!(set.len(), 4);
+    assert!(set.iter().all(|n| !cover.contains(n)));
+}
+
+#[test]
+fn minimum_vertex_cover_grid() {
+    // A grid is bipartite, with a perfect matching.
+    let g = grid(8);
+    let cover = minimum_vertex_cover(&g);
+    assert!(is_vertex_cover(&g, &cover));
+    assert_eq!(cover.len(), 32);
+
+    let approx = approx_vertex_cover(&g);
+    assert!(is_vertex_cover(&g, &approx));
+    assert!(approx.len() <= 64);
+
+    let set = greedy_independent_set(&g);
+    assert!(is_independent_set(&g, &set));
+    assert_eq!(maximum_independent_set(&g).len(), 32);
+
+    let dominating = greedy_dominating_set(&g);
+    assert!(is_dominating_set(&g, &dominating));
+}
+
+#[test]
+fn covering_directed() {
+    // Edge directions are ignored: the path 1 - 0 - 2 - 3.
+    let g = Graph::<(), ()>::from_edges(&[(1, 0), (0, 2), (3, 2)]);
+    let cover = minimum_vertex_cover(&g);
+    assert!(is_vertex_cover(&g, &cover));
+    assert_eq!(cover.len(), 2);
+    assert!(cover.contains(&NodeIndex::new(0)));
+    assert_eq!(maximum_independent_set(&g).len(), 2);
+    assert!(is_dominating_set(&g, &set(&[0, 3])));
+    assert!(!is_dominating_set(&g, &set(&[0])));
+    assert_eq!(greedy_dominating_set(&g).len(), 2);
+}
+
+#[test]
+fn validate_sets() {
+    let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 3)]);
+    assert!(is_vertex_cover(&g, &set(&[0, 2, 3])));
+    assert!(!is_vertex_cover(&g, &set(&[0, 2])));
+    assert!(!is_vertex_cover(&g, &set(&[1, 2])));
+
+    assert!(is_independent_set(&g, &set(&[])));
+    assert!(is_independent_set(&g, &set(&[0])));
+    assert!(!is_independent_set(&g, &set(&[0, 1])));
+    // A node with a self loop is its own neighbor.
+    assert!(!is_independent_set(&g, &set(&[3])));
+
+    assert!(is_dominating_set(&g, &set(&[2])));
+    assert!(!is_dominating_set(&g, &set(&[0])));
+    assert!(!is_dominating_set(&g, &set(&[])));
+
+    // Nodes that are not in the graph.
+    assert!(!is_vertex_cover(&g, &set(&[0, 2, 3, 4])));
+    assert!(!is_independent_set(&g, &set(&[0, 7])));
+    assert!(!is_dominating_set(&g, &set(&[2, 10])));
+}
+
+#[test]
+fn covering_graphmap() {
+    // Monitors on the routers, where each link has a monitor at one end.
+    let g = UnGraphMap::<&str, ()>::from_edges(&[
+        ("core", "edge-a"),
+        ("core", "edge-b"),
+        ("core", "edge-c"),
+        ("edge-a", "edge-b"),
+        ("edge-c", "host-1"),
+        ("edge-c", "host-2"),
+        ("edge-c", "host-3"),
+        ("edge-b", "host-4"),
+        ("edge-b", "host-5"),
+    ]);
+    let cover = minimum_vertex_cover(&g);
+    assert!(is_vertex_cover(&g, &cover));
+    assert_eq!(cover.len(), 3);
+    assert_eq!(
+        greedy_dominating_set(&g),
+        vec!["edge-b", "edge-c"].into_iter().collect()
+    );
+    assert!(is_independent_set(&g, &maximum_independent_set(&g)));
+    assert_eq!(maximum_independent_set(&g).len(), 6);
+}
+
+#[test]
+fn covering_stable_graph() {
+    let mut g = StableUnGraph::<(), ()>::default();
+    let n: Vec<_> = (0..5).map(|_| g.add_node(())).collect();
+    g.extend_with_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    g.remove_node(n[0]);
+
+    let cover = minimum_vertex_cover(&g);
+    assert_eq!(cover.len(), 2);
+    assert!(is_vertex_cover(&g, &cover));
+    assert_eq!(maximum_independent_set(&g).len(), 2);
+    assert!(is_independent_set(&g, &greedy_independent_set(&g)));
+    assert!(is_dominating_set(&g, &greedy_dominating_set(&g)));
+
+    // The removed node is not in the graph.
+    let mut cover = cover;
+    cover.insert(n[0]);
+    assert!(!is_vertex_cover(&g, &cover));
+}
@@ -0,0 +1,206 @@
+use petgraph::algo::{
+    eulerian_circuit, eulerian_path, has_eulerian_path, is_eulerian, EulerianWalk, NotEulerian,
+};
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences};
+
+/// Checks that the walk goes through every edge of the graph, each from the
+/// node before it to the node after it, and returns its first and last nodes.
+fn assert_walk<G>(
+    g: G,
+    walk: &EulerianWalk<G::NodeId, G::EdgeRef>,
+) -> Option<(G::NodeId, G::NodeId)>
+where
+    G: IntoEdgeReferences + GraphProp,
+    G::EdgeId: PartialEq,
+    G::NodeId: PartialEq + std::fmt::Debug,
+{
+    let edges = &walk.edges;
+    assert_eq!(edges.len(), g.edge_references().count());
+    for (i, edge) in edges.iter().enumerate() {
+        assert!(edges[..i].iter().all(|e| e.id() != edge.id()));
+    }
+    if edges.is_empty() {
+        assert!(walk.nodes.is_empty());
+        return None;
+    }
+    assert_eq!(walk.nodes.len(), edges.len() + 1);
+    for (edge, ends) in edges.iter().zip(walk.nodes.windows(2)) {
+        if edge.source() != ends[0] || edge.target() != ends[1] {
+            assert!(!g.is_directed());
+            assert_eq!((edge.target(), edge.source()), (ends[0], ends[1]));
+        }
+    }
+    Some((walk.nodes[0], *walk.nodes.last().unwrap()))
+}
+
+#[test]
+fn circuit_undirected_multigraph() {
+    // Two parallel edges between 0 and 1, a self loop on 1, and a triangle
+    // 1 - 2 - 3.
+    let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 0), (1, 1), (1, 2), (2, 3), (3, 1)]);
+    assert!(is_eulerian(&g));
+    assert!(has_eulerian_path(&g));
+    let circuit = eulerian_circuit(&g).unwrap();
+    let (start, end) = assert_walk(&g, &circuit).unwrap();
+    assert_eq!(start, NodeIndex::new(0));
+    assert_eq!(end, NodeIndex::new(0));
+    assert_eq!(eulerian_path(&g).unwrap().edges.len(), 6);
+}
+
+#[test]
+fn circuit_directed() {
+    let g = DiGraph::<(), ()>::from_edges(&[
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 4),
+        (4, 2),
+        (4, 4),
+        (0, 1),
+        (1, 0),
+    ]);
+    assert!(is_eulerian(&g));
+    let circuit = eulerian_circuit(&g).unwrap();
+    assert_eq!(
+        assert_walk(&g, &circuit),
+        Some((NodeIndex::new(0), NodeIndex::new(0)))
+    );
+
+    // Removing an edge of a cycle leaves a path.
+    let mut g = g;
+    g.remove_edge(EdgeIndex::new(1));
+    assert!(!is_eulerian(&g));
+    assert_eq!(
+        eulerian_circuit(&g).err(),
+        Some(NotEulerian::Unbalanced(vec![
+            NodeIndex::new(1),
+            NodeIndex::new(2)
+        ]))
+    );
+    let path = eulerian_path(&g).unwrap();
+    assert_eq!(
+        assert_walk(&g, &path),
+        Some((NodeIndex::new(2), NodeIndex::new(1)))
+    );
+}
+
+#[test]
+fn path_undirected() {
+    // The house: a square with a roof and a diagonal, where 0 and 3 have an
+    // odd degree.
+    let mut g =
+        UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (2, 4), (3, 4), (0, 2)]);
+    assert!(!is_eulerian(&g));
+    assert_eq!(
+        eulerian_circuit(&g).err(),
+        Some(NotEulerian::Unbalanced(vec![
+            NodeIndex::new(0),
+            NodeIndex::new(3)
+        ]))
+    );
+    let path = eulerian_path(&g).unwrap();
+    assert_eq!(
+        assert_walk(&g, &path),
+        Some((NodeIndex::new(0), NodeIndex::new(3)))
+    );
+
+    g.add_edge(NodeIndex::new(1), NodeIndex::new(4), ());
+    assert!(!has_eulerian_path(&g));
+    assert_eq!(
+        eulerian_path(&g).err(),
+        Some(NotEulerian::Unbalanced(vec![
+            NodeIndex::new(0),
+            NodeIndex::new(1),
+            NodeIndex::new(3),
+            NodeIndex::new(4)
+        ]))
+    );
+}
+
+#[test]
+fn unbalanced_directed_path() {
+    // 0 has two more outgoing edges than incoming ones.
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 2), (2, 3), (2, 3)]);
+    assert!(!has_eulerian_path(&g));
+    assert_eq!(
+        eulerian_path(&g).err(),
+        Some(NotEulerian::Unbalanced(vec![
+            NodeIndex::new(0),
+            NodeIndex::new(3)
+        ]))
+    );
+
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 3), (1, 2)]);
+    assert!(has_eulerian_path(&g));
+    // Two starts and one end.
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 3), (1, 3)]);
+    assert_eq!(
+        eulerian_path(&g).err(),
+        Some(NotEulerian::Unbalanced(vec![
+            NodeIndex::new(0),
+            NodeIndex::new(2),
+            NodeIndex::new(3)
+        ]))
+    );
+}
+
+#[test]
+fn disconnected() {
+    // Two triangles, and an isolated node.
+    let mut g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+    g.add_node(());
+    assert!(!is_eulerian(&g));
+    assert!(!has_eulerian_path(&g));
+    assert_eq!(eulerian_circuit(&g).err(), Some(NotEulerian::Disconnected));
+    assert_eq!(eulerian_path(&g).err(), Some(NotEulerian::Disconnected));
+
+    // Isolated nodes are ignored.
+    g.add_edge(NodeIndex::new(2), NodeIndex::new(3), ());
+    g.add_edge(NodeIndex::new(2), NodeIndex::new(3), ());
+    assert!(is_eulerian(&g));
+
+    let edgeless = DiGraph::<(), ()>::from_edges(&[(0, 0)]);
+    assert!(is_eulerian(&edgeless));
+    let mut edgeless = edgeless;
+    edgeless.clear_edges();
+    let empty = EulerianWalk {
+        nodes: vec![],
+        edges: vec![],
+    };
+    assert_eq!(eulerian_circuit(&edgeless).unwrap(), empty);
+    assert_eq!(eulerian_path(&edgeless).unwrap(), empty);
+}
+
+#[test]
+fn stable_graph_holes() {
+    let mut g = StableUnGraph::<(), u32>::with_capacity(0, 0);
+    let n: Vec<_> = (0..6).map(|_| g.add_node(())).collect();
+    g.extend_with_edges(&[
+        (n[0], n[1], 0),
+        (n[1], n[2], 1),
+        (n[2], n[0], 2),
+        (n[2], n[3], 3),
+        (n[2], n[3], 4),
+        (n[4], n[5], 5),
+    ]);
+    assert!(!has_eulerian_path(&g));
+    g.remove_node(n[4]);
+    g.remove_node(n[0]);
+    // 1 and 2 have an odd degree.
+    let path = eulerian_path(&g).unwrap();
+    assert_eq!(assert_walk(&g, &path), Some((n[1], n[2])));
+    let weights: Vec<u32> = path.edges.iter().map(|e| *e.weight()).collect();
+    assert_eq!(weights, vec![1, 3, 4]);
+    assert_eq!(path.nodes, vec![n[1], n[2], n[3], n[2]]);
+}
+
+#[test]
+fn graphmap() {
+    let g = DiGraphMap::<&str, ()>::from_edges(&[("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")]);
+    let path = eulerian_path(&g).unwrap();
+    let edges: Vec<_> = path.edges.iter().map(|&(a, b, _)| (a, b)).collect();
+    assert_eq!(edges, vec![("c", "a"), ("a", "b"), ("b", "c"), ("c", "d")]);
+    assert_eq!(path.nodes, vec!["c", "a", "b", "c", "d"]);
+}
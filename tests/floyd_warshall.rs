@@ -1,4 +1,5 @@
-use petgraph::algo::floyd_warshall;
+use petgraph::algo::{bellman_ford, floyd_warshall, floyd_warshall_matrix};
+use petgraph::matrix_graph::UnMatrix;
 use petgraph::{prelude::*, Directed, Graph, Undirected};
 use std::collections::HashMap;
 
@@ -282,3 +283,100 @@ fn floyd_warshall_negative_cycle() {
 
     assert!(res.is_err());
 }
+
+#[test]
+fn floyd_warshall_matrix_paths() {
+    let mut graph: Graph<(), f64, Directed> = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    let e = graph.add_node(());
+
+    graph.extend_with_edges(&[
+        (a, b, 1.0),
+        (a, c, 4.0),
+        (b, c, -2.0),
+        (c, d, 1.0),
+        (b, d, 3.0),
+        // parallel edge, cheaper than the first one
+        (c, d, 0.5),
+        (d, a, 2.0),
+    ]);
+    // e is isolated
+
+    let res = floyd_warshall_matrix(&graph, |edge| *edge.weight()).unwrap();
+    assert_eq!(res.node_bound(), 5);
+
+    let (a, b, c, d, e) = (a.index(), b.index(), c.index(), d.index(), e.index());
+    assert_eq!(res.distance(a, d), Some(-0.5));
+    assert_eq!(res.path(a, d), Some(vec![a, b, c, d]));
+    assert_eq!(res.distance(c, b), Some(3.5));
+    assert_eq!(res.path(c, b), Some(vec![c, d, a, b]));
+    assert_eq!(res.next_hop(c, b), Some(d));
+    assert_eq!(res.next_hop(a, a), Some(a));
+    assert_eq!(res.path(e, e), Some(vec![e]));
+    assert_eq!(res.distance(a, e), None);
+    assert_eq!(res.next_hop(e, a), None);
+    assert_eq!(res.path(e, a), None);
+
+    // Same distances as bellman_ford.
+    for source in graph.node_indices() {
+        let paths = bellman_ford(&graph, source).unwrap();
+        for target in graph.node_indices() {
+            let expected = paths.distances[target.index()];
+            let expected = if expected.is_finite() {
+                Some(expected)
+            } else {
+                None
+            };
+            assert_eq!(res.distance(source.index(), target.index()), expected);
+        }
+    }
+}
+
+#[test]
+fn floyd_warshall_matrix_undirected_and_holes() {
+    let mut graph =
+        StableUnGraph::<(), u32>::from_edges(&[(0, 1, 5), (1, 2, 1), (2, 3, 1), (0, 3, 1)]);
+    graph.remove_node(NodeIndex::new(1));
+    // 0 --1-- 3 --1-- 2, node 1 removed
+    let res = floyd_warshall_matrix(&graph, |edge| *edge.weight()).unwrap();
+    assert_eq!(res.node_bound(), 4);
+    assert_eq!(res.path(2, 0), Some(vec![2, 3, 0]));
+    assert_eq!(res.path(0, 2), Some(vec![0, 3, 2]));
+    assert_eq!(res.distance(0, 2), Some(2));
+    assert_eq!(res.distance(1, 1), None);
+    assert_eq!(res.distance(0, 1), None);
+    assert_eq!(res.next_hop(1, 1), None);
+    assert_eq!(res.path(1, 1), None);
+    assert_eq!(res.path(0, 1), None);
+    assert_eq!(res.path(1, 0), None);
+    assert_eq!(res.path(3, 3), Some(vec![3]));
+
+    let mut graph: UnMatrix<(), u32> = UnMatrix::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.extend_with_edges(&[(a, b, 7), (b, c, 1), (a, c, 2)]);
+    let res = floyd_warshall_matrix(&graph, |edge| *edge.2).unwrap();
+    assert_eq!(res.path(a.index(), b.index()), Some(vec![0, 2, 1]));
+    assert_eq!(res.distance(b.index(), a.index()), Some(3));
+}
+
+#[test]
+fn floyd_warshall_matrix_negative_cycle() {
+    let mut graph: Graph<(), i32, Directed> = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.extend_with_edges(&[(a, b, 1), (b, c, -3), (c, a, 1)]);
+    assert!(floyd_warshall_matrix(&graph, |edge| *edge.weight()).is_err());
+
+    // A negative self-loop is a negative cycle too.
+    let mut graph: Graph<(), i32, Directed> = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.extend_with_edges(&[(a, b, 1), (b, b, -1)]);
+    assert!(floyd_warshall_matrix(&graph, |edge| *edge.weight()).is_err());
+}
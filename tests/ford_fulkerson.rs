@@ -1,5 +1,6 @@
-use petgraph::algo::ford_fulkerson;
-use petgraph::prelude::Graph;
+use petgraph::algo::{dinic, ford_fulkerson, min_cut, push_relabel};
+use petgraph::prelude::{Graph, StableGraph};
+use petgraph::visit::EdgeRef;
 
 #[test]
 fn test_ford_fulkerson() {
@@ -119,3 +120,91 @@ fn test_ford_fulkerson() {
     let (max_flow, _) = ford_fulkerson(&graph, source, destination);
     assert_eq!(19, max_flow);
 }
+
+// Example from CLRS book
+fn clrs_network() -> Graph<u8, u8> {
+    let mut graph = Graph::<u8, u8>::new();
+    for i in 0..6 {
+        graph.add_node(i);
+    }
+    graph.extend_with_edges(&[
+        (0, 1, 16),
+        (0, 2, 13),
+        (1, 2, 10),
+        (1, 3, 12),
+        (2, 1, 4),
+        (2, 4, 14),
+        (3, 2, 9),
+        (3, 5, 20),
+        (4, 3, 7),
+        (4, 5, 4),
+    ]);
+    graph
+}
+
+#[test]
+fn test_dinic_and_push_relabel() {
+    let graph = clrs_network();
+    let source = 0.into();
+    let destination = 5.into();
+    for &(max_flow, ref flows) in &[
+        dinic(&graph, source, destination),
+        push_relabel(&graph, source, destination),
+    ] {
+        assert_eq!(23, max_flow);
+        let (mut source_side, cut_edges) = min_cut(&graph, source, flows);
+        source_side.sort();
+        assert_eq!(source_side, vec![0.into(), 1.into(), 2.into(), 4.into()]);
+        let mut cut: Vec<_> = cut_edges
+            .iter()
+            .map(|e| (e.source().index(), e.target().index()))
+            .collect();
+        cut.sort();
+        assert_eq!(cut, vec![(1, 3), (4, 3), (4, 5)]);
+    }
+
+    // Float capacities.
+    let mut graph = Graph::<usize, f32>::new();
+    let source = graph.add_node(0);
+    let _ = graph.add_node(1);
+    let _ = graph.add_node(2);
+    let _ = graph.add_node(3);
+    let _ = graph.add_node(4);
+    let destination = graph.add_node(5);
+    graph.extend_with_edges(&[
+        (0, 1, 7.),
+        (0, 2, 4.),
+        (1, 3, 5.),
+        (1, 4, 3.),
+        (2, 1, 3.),
+        (2, 4, 2.),
+        (3, 5, 8.),
+        (4, 3, 3.),
+        (4, 5, 5.),
+    ]);
+    assert_eq!(10.0, dinic(&graph, source, destination).0);
+    assert_eq!(10.0, push_relabel(&graph, source, destination).0);
+}
+
+#[test]
+fn test_max_flow_degenerate() {
+    let graph = clrs_network();
+    // The source is the destination.
+    assert_eq!(dinic(&graph, 0.into(), 0.into()), (0, vec![0; 10]));
+    assert_eq!(push_relabel(&graph, 0.into(), 0.into()), (0, vec![0; 10]));
+    // The destination is not reachable.
+    assert_eq!(dinic(&graph, 5.into(), 0.into()).0, 0);
+    assert_eq!(push_relabel(&graph, 5.into(), 0.into()).0, 0);
+    let (source_side, cut_edges) = min_cut(&graph, 5.into(), &[0; 10]);
+    assert_eq!(source_side, vec![5.into()]);
+    assert!(cut_edges.is_empty());
+
+    // Removed nodes and edges leave holes in the indices.
+    let mut graph = StableGraph::from(clrs_network());
+    graph.remove_node(2.into());
+    let (max_flow, flows) = dinic(&graph, 0.into(), 5.into());
+    assert_eq!(max_flow, 12);
+    assert_eq!(push_relabel(&graph, 0.into(), 5.into()).0, max_flow);
+    let (_, cut_edges) = min_cut(&graph, 0.into(), &flows);
+    assert_eq!(cut_edges.iter().map(|e| *e.weight()).sum::<u8>(), max_flow);
+}
@@ -16,7 +16,10 @@ use petgraph::algo::{
 use petgraph::graph::node_index as n;
 use petgraph::graph::IndexType;
 
-use petgraph::algo::{astar, dijkstra, DfsSpace};
+use petgraph::algo::{
+    astar, bellman_ford, bidirectional_dijkstra, dijkstra, dijkstra_paths, multi_source_dijkstra,
+    multi_source_dijkstra_path, spfa, DfsSpace,
+};
 use petgraph::visit::{
     IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNodeIdentifiers, NodeFiltered, Reversed, Topo,
     VisitMap, Walker,
@@ -434,6 +437,214 @@ fn dijk() {
     assert_eq!(scores[&c], 9);
 }
 
+#[test]
+fn dijk_paths() {
+    let mut g = Graph::new_undirected();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    let f = g.add_node("F");
+    let z = g.add_node("Z");
+    g.add_edge(a, b, 7);
+    g.add_edge(c, a, 9);
+    g.add_edge(a, d, 14);
+    g.add_edge(b, c, 10);
+    let dc = g.add_edge(d, c, 2);
+    g.add_edge(d, e, 9);
+    g.add_edge(b, f, 15);
+    let cf = g.add_edge(c, f, 11);
+    g.add_edge(e, f, 6);
+    // A more expensive parallel edge is never part of a shortest path.
+    g.add_edge(c, d, 3);
+
+    let paths = dijkstra_paths(&g, a, None, |e| *e.weight());
+    assert_eq!(
+        paths.distances,
+        vec![
+            Some(0),
+            Some(7),
+            Some(9),
+            Some(11),
+            Some(20),
+            Some(20),
+            None
+        ]
+    );
+    assert_eq!(paths.path_to(&g, a, a), Some(vec![a]));
+    assert_eq!(paths.path_to(&g, a, d), Some(vec![a, c, d]));
+    assert_eq!(paths.path_to(&g, a, f), Some(vec![a, c, f]));
+    assert_eq!(paths.path_to(&g, a, z), None);
+    assert!(paths.edges_to(&g, a, z, |e| *e.weight()).is_none());
+    assert_eq!(paths.edges_to(&g, a, a, |e| *e.weight()), Some(vec![]));
+
+    let edges = paths.edges_to(&g, a, d, |e| *e.weight()).unwrap();
+    assert_eq!(edges[1].id(), dc);
+    let edges = paths.edges_to(&g, a, f, |e| *e.weight()).unwrap();
+    assert_eq!(edges[1].id(), cf);
+    let cost: i32 = edges.iter().map(|e| *e.weight()).sum();
+    assert_eq!(Some(cost), paths.distances[f.index()]);
+
+    // The search stops once the goal is reached.
+    let paths = dijkstra_paths(&g, a, Some(c), |e| *e.weight());
+    assert_eq!(paths.path_to(&g, a, c), Some(vec![a, c]));
+
+    // Paths from bellman_ford are reconstructed the same way.
+    let mut g = Graph::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    g.extend_with_edges(&[(a, b, 1.0), (a, c, 4.0), (b, c, -2.0)]);
+    let paths = bellman_ford(&g, a).unwrap();
+    assert_eq!(paths.path_to(&g, a, c), Some(vec![a, b, c]));
+    let paths = bellman_ford(&g, c).unwrap();
+    assert_eq!(paths.path_to(&g, c, a), None);
+}
+
+#[test]
+fn paths_to_unreachable_target() {
+    // c is not reachable from a, and b is reached at no cost.
+    let g = Graph::<(), f64>::from_edges(&[(0, 1, 0.0), (2, 0, 1.0)]);
+    let (a, b, c) = (n(0), n(1), n(2));
+    let edge_cost = |e: pg::graph::EdgeReference<f64>| *e.weight();
+
+    let paths = bellman_ford(&g, a).unwrap();
+    assert_eq!(paths.path_to(&g, a, a), Some(vec![a]));
+    assert_eq!(paths.path_to(&g, a, b), Some(vec![a, b]));
+    assert_eq!(paths.path_to(&g, a, c), None);
+    assert!(paths.edges_to(&g, a, c, edge_cost).is_none());
+
+    let paths = dijkstra_paths(&g, a, None, edge_cost);
+    assert_eq!(paths.path_to(&g, a, a), Some(vec![a]));
+    assert_eq!(paths.path_to(&g, a, b), Some(vec![a, b]));
+    assert_eq!(paths.path_to(&g, a, c), None);
+    assert!(paths.edges_to(&g, a, c, edge_cost).is_none());
+
+    let paths = spfa(&g, a, edge_cost).unwrap();
+    assert_eq!(paths.path_to(&g, a, a), Some(vec![a]));
+    assert_eq!(paths.path_to(&g, a, b), Some(vec![a, b]));
+    assert_eq!(paths.path_to(&g, a, c), None);
+    assert!(paths.edges_to(&g, a, c, edge_cost).is_none());
+}
+
+#[test]
+fn dijk_multi_source() {
+    let mut g = Graph::new_undirected();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    let f = g.add_node("F");
+    let z = g.add_node("Z");
+    g.add_edge(a, b, 7);
+    g.add_edge(c, a, 9);
+    g.add_edge(a, d, 14);
+    g.add_edge(b, c, 10);
+    g.add_edge(d, c, 2);
+    g.add_edge(d, e, 9);
+    g.add_edge(b, f, 15);
+    g.add_edge(c, f, 11);
+    g.add_edge(e, f, 6);
+
+    // A single start is the same as dijkstra.
+    assert_eq!(
+        multi_source_dijkstra(&g, vec![(a, 0)], None, |e| *e.weight()),
+        dijkstra(&g, a, None, |e| *e.weight())
+    );
+
+    let scores = multi_source_dijkstra(&g, vec![(a, 0), (e, 0), (e, 5)], None, |e| *e.weight());
+    let mut scores: Vec<_> = scores.into_iter().map(|(n, s)| (g[n], s)).collect();
+    scores.sort();
+    assert_eq!(
+        scores,
+        vec![("A", 0), ("B", 7), ("C", 9), ("D", 9), ("E", 0), ("F", 6)]
+    );
+
+    assert_eq!(
+        multi_source_dijkstra_path(&g, vec![(a, 0), (e, 0)], d, |e| *e.weight()),
+        Some((9, vec![e, d]))
+    );
+    assert_eq!(
+        multi_source_dijkstra_path(&g, vec![(a, 0), (e, 1)], d, |e| *e.weight()),
+        Some((10, vec![e, d]))
+    );
+    assert_eq!(
+        multi_source_dijkstra_path(&g, vec![(a, 0), (e, 3)], d, |e| *e.weight()),
+        Some((11, vec![a, c, d]))
+    );
+    assert_eq!(
+        multi_source_dijkstra_path(&g, vec![(a, 0)], a, |e| *e.weight()),
+        Some((0, vec![a]))
+    );
+    assert_eq!(
+        multi_source_dijkstra_path(&g, vec![(a, 0), (e, 0)], z, |e| *e.weight()),
+        None
+    );
+    assert_eq!(
+        multi_source_dijkstra_path(&g, vec![], a, |e| *e.weight()),
+        None
+    );
+}
+
+#[test]
+fn dijk_bidirectional() {
+    let mut g = Graph::new_undirected();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    let f = g.add_node("F");
+    let z = g.add_node("Z");
+    g.add_edge(a, b, 7);
+    g.add_edge(c, a, 9);
+    g.add_edge(a, d, 14);
+    g.add_edge(b, c, 10);
+    g.add_edge(d, c, 2);
+    g.add_edge(d, e, 9);
+    g.add_edge(b, f, 15);
+    g.add_edge(c, f, 11);
+    g.add_edge(e, f, 6);
+
+    let (cost, meeting, path) = bidirectional_dijkstra(&g, a, e, |e| *e.weight()).unwrap();
+    assert_eq!(cost, 20);
+    assert_eq!(path, vec![a, c, d, e]);
+    assert!(path.contains(&meeting));
+    let (cost, _, path) = bidirectional_dijkstra(&g, e, a, |e| *e.weight()).unwrap();
+    assert_eq!(cost, 20);
+    assert_eq!(path, vec![e, d, c, a]);
+
+    assert_eq!(
+        bidirectional_dijkstra(&g, a, a, |e| *e.weight()),
+        Some((0, a, vec![a]))
+    );
+    assert_eq!(bidirectional_dijkstra(&g, a, z, |e| *e.weight()), None);
+
+    // The backward search follows edges against their direction.
+    let mut g = Graph::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    g.extend_with_edges(&[(a, b, 1), (b, c, 1), (c, a, 1)]);
+    assert_eq!(
+        bidirectional_dijkstra(&g, a, c, |e| *e.weight()).map(|(c, _, p)| (c, p)),
+        Some((2, vec![a, b, c]))
+    );
+    assert_eq!(
+        bidirectional_dijkstra(&g, c, b, |e| *e.weight()).map(|(c, _, p)| (c, p)),
+        Some((2, vec![c, a, b]))
+    );
+
+    let g = DiGraphMap::<u32, u32>::from_edges(&[(0, 1, 4), (1, 2, 4), (0, 2, 9), (2, 3, 1)]);
+    assert_eq!(
+        bidirectional_dijkstra(&g, 0, 3, |e| *e.2).map(|(c, _, p)| (c, p)),
+        Some((9, vec![0, 1, 2, 3]))
+    );
+    assert_eq!(bidirectional_dijkstra(&g, 3, 0, |e| *e.2), None);
+}
+
 #[test]
 fn test_astar_null_heuristic() {
     let mut g = Graph::new();
@@ -5,7 +5,7 @@ use std::collections::HashSet;
 use std::fmt;
 
 use petgraph::prelude::*;
-use petgraph::visit::Walker;
+use petgraph::visit::{EdgeIndexable, Walker};
 
 use petgraph::algo::dijkstra;
 
@@ -386,6 +386,18 @@ fn undirected_neighbors_includes_self_loops() {
     assert_eq!(neighbors.next(), None);
 }
 
+#[test]
+fn undirected_edge_index_ignores_orientation() {
+    let graph = UnGraphMap::<u32, ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+
+    for (a, b, _) in graph.all_edges() {
+        let ix = EdgeIndexable::to_index(&graph, (a, b));
+        assert_eq!(EdgeIndexable::to_index(&graph, (b, a)), ix);
+        let (c, d) = EdgeIndexable::from_index(&graph, ix);
+        assert!((c, d) == (a, b) || (c, d) == (b, a));
+    }
+}
+
 #[test]
 fn self_loops_can_be_removed() {
     let mut graph = DiGraphMap::new();
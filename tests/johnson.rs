@@ -0,0 +1,109 @@
+use petgraph::algo::{bellman_ford, johnson};
+use petgraph::prelude::*;
+use std::collections::HashMap;
+
+#[cfg(feature = "rayon")]
+use petgraph::algo::parallel_johnson;
+
+fn graph_example() -> Graph<(), f64> {
+    // a ----> b ----> e ----> f
+    // ^       |       ^       |
+    // |       v       |       v
+    // d <---- c       h <---- g
+    //
+    // with the negative edges b -> c and f -> g.
+    let mut graph = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    let e = graph.add_node(());
+    let f = graph.add_node(());
+    let g = graph.add_node(());
+    let h = graph.add_node(());
+    graph.extend_with_edges(&[
+        (a, b, 3.),
+        (b, c, -2.),
+        (c, d, 4.),
+        (d, a, 1.),
+        (e, f, 2.),
+        (b, e, 5.),
+        (f, g, -1.),
+        (g, h, 3.),
+        (h, e, 1.),
+    ]);
+    graph
+}
+
+// Compare with bellman_ford from every node.
+fn assert_same_as_bellman_ford(graph: &Graph<(), f64>, res: &HashMap<(NodeIndex, NodeIndex), f64>) {
+    let mut n_reachable = 0;
+    for source in graph.node_indices() {
+        let paths = bellman_ford(graph, source).unwrap();
+        for target in graph.node_indices() {
+            let distance = paths.distances[target.index()];
+            if distance.is_infinite() {
+                assert_eq!(res.get(&(source, target)), None);
+            } else {
+                assert_eq!(res.get(&(source, target)), Some(&distance));
+                n_reachable += 1;
+            }
+        }
+    }
+    assert_eq!(res.len(), n_reachable);
+}
+
+#[test]
+fn johnson_negative_edges() {
+    let graph = graph_example();
+    let res = johnson(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(res[&(0.into(), 2.into())], 1.);
+    assert_eq!(res[&(2.into(), 7.into())], 17.);
+    assert_same_as_bellman_ford(&graph, &res);
+}
+
+#[test]
+fn johnson_unreachable() {
+    let mut graph = graph_example();
+    let z = graph.add_node(());
+    let res = johnson(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(res[&(z, z)], 0.);
+    assert!(graph
+        .node_indices()
+        .all(|n| n == z || !res.contains_key(&(n, z))));
+    assert!(graph
+        .node_indices()
+        .all(|n| n == z || !res.contains_key(&(z, n))));
+}
+
+#[test]
+fn johnson_negative_cycle() {
+    let mut graph = graph_example();
+    graph.add_edge(7.into(), 5.into(), -3.);
+    assert!(johnson(&graph, |e| *e.weight()).is_err());
+
+    // An undirected negative edge is a negative cycle.
+    let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, -1.)]);
+    assert!(johnson(&graph, |e| *e.weight()).is_err());
+}
+
+#[test]
+fn johnson_undirected() {
+    let graph = UnGraph::<(), f32>::from_edges(&[(0, 1, 1.), (1, 2, 2.5), (0, 2, 4.)]);
+    let res = johnson(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(res.len(), 9);
+    assert_eq!(res[&(0.into(), 2.into())], 3.5);
+    assert_eq!(res[&(2.into(), 0.into())], 3.5);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn parallel_johnson_negative_edges() {
+    let graph = graph_example();
+    let res = parallel_johnson(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(res, johnson(&graph, |e| *e.weight()).unwrap());
+
+    let mut graph = graph;
+    graph.add_edge(7.into(), 5.into(), -3.);
+    assert!(parallel_johnson(&graph, |e| *e.weight()).is_err());
+}
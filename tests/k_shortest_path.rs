@@ -1,4 +1,4 @@
-use petgraph::algo::k_shortest_path;
+use petgraph::algo::{k_shortest_path, yen_k_shortest_paths};
 use petgraph::prelude::*;
 use petgraph::Graph;
 use std::collections::HashMap;
@@ -61,3 +61,74 @@ fn second_shortest_path() {
 
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn yen_loopless_paths() {
+    // a -> b -> a is a cheap loop that k_shortest_path would take, but a
+    // simple path never does.
+    let mut graph: Graph<(), u32, Directed> = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.extend_with_edges(&[(a, b, 1), (b, a, 1), (b, d, 1), (a, c, 5), (c, d, 5)]);
+
+    let res = k_shortest_path(&graph, a, Some(d), 2, |e| *e.weight());
+    assert_eq!(res[&d], 4);
+
+    let paths = yen_k_shortest_paths(&graph, a, d, 5, |e| *e.weight());
+    assert_eq!(paths, vec![(2, vec![a, b, d]), (10, vec![a, c, d])]);
+
+    assert!(yen_k_shortest_paths(&graph, a, d, 0, |e| *e.weight()).is_empty());
+    assert!(yen_k_shortest_paths(&graph, d, a, 3, |e| *e.weight()).is_empty());
+    assert_eq!(
+        yen_k_shortest_paths(&graph, a, a, 3, |e| *e.weight()),
+        vec![(0, vec![a])]
+    );
+}
+
+#[test]
+fn yen_parallel_edges() {
+    // Parallel edges do not make distinct paths; the cheapest one counts.
+    let mut graph: Graph<(), u32, Directed> = Graph::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.extend_with_edges(&[(a, b, 3), (a, b, 1), (b, c, 1), (a, c, 4), (b, c, 2)]);
+
+    let paths = yen_k_shortest_paths(&graph, a, c, 3, |e| *e.weight());
+    assert_eq!(paths, vec![(2, vec![a, b, c]), (4, vec![a, c])]);
+}
+
+#[test]
+fn yen_undirected_grid() {
+    // 0 - 1 - 2
+    // |   |   |
+    // 3 - 4 - 5
+    let graph = UnGraph::<(), u32>::from_edges(&[
+        (0, 1, 1),
+        (1, 2, 1),
+        (0, 3, 1),
+        (1, 4, 1),
+        (2, 5, 1),
+        (3, 4, 1),
+        (4, 5, 1),
+    ]);
+    let start = NodeIndex::new(0);
+    let goal = NodeIndex::new(5);
+    let paths = yen_k_shortest_paths(&graph, start, goal, 10, |e| *e.weight());
+    let costs: Vec<_> = paths.iter().map(|p| p.0).collect();
+    assert_eq!(costs, vec![3, 3, 3, 5]);
+
+    let mut nodes: Vec<_> = paths.into_iter().map(|p| p.1).collect();
+    nodes.sort();
+    nodes.dedup();
+    assert_eq!(nodes.len(), 4);
+}
+
+#[test]
+fn yen_graphmap() {
+    let graph = DiGraphMap::<u32, u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (0, 2, 3), (2, 3, 1)]);
+    let paths = yen_k_shortest_paths(&graph, 0, 3, 3, |e| *e.2);
+    assert_eq!(paths, vec![(3, vec![0, 1, 2, 3]), (4, vec![0, 2, 3])]);
+}
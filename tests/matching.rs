@@ -1,7 +1,10 @@
 use std::collections::HashSet;
 use std::hash::Hash;
 
-use petgraph::algo::{greedy_matching, maximum_matching};
+use petgraph::algo::{
+    bipartite_maximum_matching, bipartite_minimum_vertex_cover, bipartition, greedy_matching,
+    max_weight_assignment, maximum_matching, maximum_weight_matching, min_cost_assignment,
+};
 use petgraph::prelude::*;
 
 macro_rules! assert_one_of {
@@ -139,3 +142,255 @@ fn is_perfect_in_stable_graph() {
     assert_eq!(m.len(), 1);
     assert!(m.is_perfect());
 }
+
+#[test]
+fn assignment_empty() {
+    let g: UnGraph<(), u32> = UnGraph::default();
+    let (m, cost) = min_cost_assignment(&g, None, None, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![]);
+    assert_eq!(cost, 0);
+}
+
+#[test]
+fn assignment_square() {
+    // Rows 0, 1, 2 and columns 3, 4, 5 of the cost matrix
+    //     4 1 3
+    //     2 0 5
+    //     3 2 2
+    let costs = [[4, 1, 3], [2, 0, 5], [3, 2, 2]];
+    let mut g: UnGraph<(), u32> = UnGraph::default();
+    let rows: Vec<_> = (0..3).map(|_| g.add_node(())).collect();
+    let columns: Vec<_> = (0..3).map(|_| g.add_node(())).collect();
+    for (i, row) in costs.iter().enumerate() {
+        for (j, &cost) in row.iter().enumerate() {
+            g.add_edge(rows[i], columns[j], cost);
+        }
+    }
+
+    let (m, cost) = min_cost_assignment(&g, rows.clone(), columns.clone(), |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(0, 4), (1, 3), (2, 5)]);
+    assert_eq!(cost, 5);
+    assert!(m.is_perfect());
+
+    let (m, weight) = max_weight_assignment(&g, rows, columns, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(0, 3), (1, 5), (2, 4)]);
+    assert_eq!(weight, 11);
+}
+
+#[test]
+fn assignment_is_maximum() {
+    // The cheapest edge (1, 2) would leave node 0 unassigned.
+    let g: UnGraph<(), i32> = UnGraph::from_edges(&[(0, 2, 5), (1, 2, 1), (1, 3, 7)]);
+    let (m, cost) = min_cost_assignment(
+        &g,
+        vec![0.into(), 1.into()],
+        vec![2.into(), 3.into()],
+        |e| *e.weight(),
+    );
+    assert_eq!(collect(m.edges()), set![(0, 2), (1, 3)]);
+    assert_eq!(cost, 12);
+
+    // Node 1 can only be matched instead of node 0.
+    let g: UnGraph<(), i32> = UnGraph::from_edges(&[(0, 2, 5), (1, 2, 1)]);
+    let (m, cost) = min_cost_assignment(&g, vec![0.into(), 1.into()], vec![2.into()], |e| {
+        *e.weight()
+    });
+    assert_eq!(collect(m.edges()), set![(1, 2)]);
+    assert_eq!(cost, 1);
+    let (m, weight) = max_weight_assignment(&g, vec![0.into(), 1.into()], vec![2.into()], |e| {
+        *e.weight()
+    });
+    assert_eq!(collect(m.edges()), set![(0, 2)]);
+    assert_eq!(weight, 5);
+}
+
+#[test]
+fn assignment_ignores_other_edges() {
+    // Edges within a side are ignored, edges are taken in both directions,
+    // and the cheapest of parallel edges is used.
+    let g: Graph<(), f64> =
+        Graph::from_edges(&[(0, 1, 1.), (2, 0, 4.), (1, 3, 2.), (0, 2, 3.), (2, 3, 0.5)]);
+    let (m, cost) = min_cost_assignment(
+        &g,
+        vec![0.into(), 1.into()],
+        vec![2.into(), 3.into()],
+        |e| *e.weight(),
+    );
+    assert_eq!(collect(m.edges()), set![(0, 2), (1, 3)]);
+    assert_eq!(cost, 5.);
+    assert!(!m.contains_edge(0.into(), 1.into()));
+}
+
+#[test]
+fn maximum_weight_empty() {
+    let g: UnGraph<(), i32> = UnGraph::default();
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![]);
+}
+
+#[test]
+fn maximum_weight_triangle() {
+    let g: UnGraph<(), u32> = UnGraph::from_edges(&[(0, 1, 5), (1, 2, 7), (2, 0, 6)]);
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(1, 2)]);
+}
+
+#[test]
+fn maximum_weight_negative() {
+    let g: UnGraph<(), i32> = UnGraph::from_edges(&[(0, 1, 2), (0, 2, -2), (1, 3, -3), (2, 3, 1)]);
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(0, 1), (2, 3)]);
+
+    let g: UnGraph<(), i32> = UnGraph::from_edges(&[(0, 1, 2), (0, 2, -2), (1, 3, -6)]);
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(0, 1)]);
+    let m = maximum_weight_matching(&g, true, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(0, 2), (1, 3)]);
+}
+
+#[test]
+fn maximum_weight_blossom() {
+    // The odd cycle 0, 1, 2 is shrunk to a blossom and expanded.
+    let g: UnGraph<(), f64> =
+        UnGraph::from_edges(&[(0, 1, 8.), (0, 2, 9.), (1, 2, 10.), (2, 3, 7.)]);
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(0, 1), (2, 3)]);
+
+    let g: UnGraph<(), i32> = UnGraph::from_edges(&[
+        (0, 1, 8),
+        (0, 2, 9),
+        (1, 2, 10),
+        (2, 3, 7),
+        (0, 5, 5),
+        (3, 4, 6),
+    ]);
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(0, 5), (1, 2), (3, 4)]);
+}
+
+#[test]
+fn maximum_weight_nested_blossoms() {
+    // A nested outer blossom, relabeled as inner and expanded.
+    let g: UnGraph<(), i32> = UnGraph::from_edges(&[
+        (0, 1, 19),
+        (0, 2, 20),
+        (0, 7, 8),
+        (1, 2, 25),
+        (1, 3, 18),
+        (2, 4, 18),
+        (3, 4, 13),
+        (3, 6, 7),
+        (4, 5, 7),
+    ]);
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(0, 7), (1, 2), (3, 6), (4, 5)]);
+
+    // A nested outer blossom, augmented and expanded recursively.
+    let g: UnGraph<(), i32> = UnGraph::from_edges(&[
+        (0, 1, 40),
+        (0, 2, 40),
+        (1, 2, 60),
+        (1, 3, 55),
+        (2, 4, 55),
+        (3, 4, 50),
+        (0, 7, 15),
+        (4, 6, 30),
+        (6, 5, 10),
+        (7, 9, 10),
+        (3, 8, 30),
+    ]);
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(
+        collect(m.edges()),
+        set![(0, 1), (2, 4), (3, 8), (5, 6), (7, 9)]
+    );
+
+    // A blossom relabeled as inner in more than one way.
+    let g: UnGraph<(), i32> = UnGraph::from_edges(&[
+        (0, 1, 45),
+        (0, 4, 45),
+        (1, 2, 50),
+        (2, 3, 45),
+        (3, 4, 50),
+        (0, 5, 30),
+        (2, 8, 35),
+        (3, 7, 35),
+        (4, 6, 26),
+        (8, 9, 5),
+    ]);
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(
+        collect(m.edges()),
+        set![(0, 5), (1, 2), (3, 7), (4, 6), (8, 9)]
+    );
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn maximum_weight_in_stable_graph() {
+    let mut g: StableUnGraph<(), u8> =
+        StableUnGraph::from_edges(&[(0, 1, 1), (1, 2, 3), (2, 3, 2), (3, 4, 1)]);
+    g.remove_node(NodeIndex::new(1));
+
+    let m = maximum_weight_matching(&g, false, |e| *e.weight());
+    assert_eq!(collect(m.edges()), set![(2, 3)]);
+    assert!(!m.contains_node(NodeIndex::new(0)));
+}
+
+#[test]
+fn bipartite_maximum_empty() {
+    let g: UnGraph<(), ()> = UnGraph::default();
+    let m = bipartite_maximum_matching(&g, None, None);
+    assert_eq!(collect(m.edges()), set![]);
+    assert!(bipartite_minimum_vertex_cover(&g, None, None, &m).is_empty());
+}
+
+#[test]
+fn bipartite_maximum_augmenting_path() {
+    // The first edge found for node 1 has to be given up for node 0.
+    let g: UnGraph<(), ()> = UnGraph::from_edges(&[(1, 2), (0, 2), (1, 3), (4, 3), (4, 5)]);
+    let (left, right) = bipartition(&g).unwrap();
+    let m = bipartite_maximum_matching(&g, left.clone(), right.clone());
+    assert_eq!(collect(m.edges()), set![(0, 2), (1, 3), (4, 5)]);
+    assert!(m.is_perfect());
+
+    let cover = bipartite_minimum_vertex_cover(&g, left, right, &m);
+    assert_eq!(cover.len(), 3);
+}
+
+#[test]
+fn bipartite_maximum_ignores_other_edges() {
+    // Edges within a side are ignored, and edges are taken in both directions.
+    let g: Graph<(), ()> = Graph::from_edges(&[(0, 1), (3, 0), (2, 3), (1, 3)]);
+    let m = bipartite_maximum_matching(&g, vec![0.into(), 1.into()], vec![2.into(), 3.into()]);
+    assert_eq!(m.len(), 1);
+    assert!(!m.contains_edge(0.into(), 1.into()));
+    assert!(!m.contains_edge(2.into(), 3.into()));
+
+    let cover =
+        bipartite_minimum_vertex_cover(&g, vec![0.into(), 1.into()], vec![2.into(), 3.into()], &m);
+    assert_eq!(collect(cover.into_iter()), set![3]);
+}
+
+#[test]
+fn bipartite_minimum_vertex_cover_star() {
+    let g: UnGraph<(), ()> = UnGraph::from_edges(&[(0, 1), (0, 2), (0, 3), (4, 3)]);
+    let (left, right) = bipartition(&g).unwrap();
+    assert_eq!(left, vec![0.into(), 4.into()]);
+    let m = bipartite_maximum_matching(&g, left.clone(), right.clone());
+    assert_eq!(m.len(), 2);
+    let cover = bipartite_minimum_vertex_cover(&g, left, right, &m);
+    assert_eq!(collect(cover.into_iter()), set![0, 4]);
+}
+
+#[test]
+fn bipartition_odd_cycle() {
+    let g: UnGraph<(), ()> = UnGraph::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    assert_eq!(bipartition(&g), None);
+
+    let g: DiGraph<(), ()> = DiGraph::from_edges(&[(0, 1), (2, 1), (2, 3), (0, 3)]);
+    assert_eq!(
+        bipartition(&g),
+        Some((vec![0.into(), 2.into()], vec![1.into(), 3.into()]))
+    );
+}
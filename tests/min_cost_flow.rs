@@ -0,0 +1,169 @@
+use petgraph::algo::{ford_fulkerson, min_cost_flow};
+use petgraph::prelude::*;
+
+#[test]
+fn min_cost_flow_transport() {
+    // Two warehouses supply three shops; the super source and sink edges
+    // have the supplies and demands as capacities.
+    let mut graph = Graph::<(), (f64, f64)>::new();
+    let source = graph.add_node(());
+    let warehouses = [graph.add_node(()), graph.add_node(())];
+    let shops = [graph.add_node(()), graph.add_node(()), graph.add_node(())];
+    let sink = graph.add_node(());
+    graph.add_edge(source, warehouses[0], (20., 0.));
+    graph.add_edge(source, warehouses[1], (30., 0.));
+    graph.add_edge(shops[0], sink, (10., 0.));
+    graph.add_edge(shops[1], sink, (25., 0.));
+    graph.add_edge(shops[2], sink, (15., 0.));
+    let unit_costs = [[2., 3., 1.], [5., 4., 8.]];
+    for (w, costs) in warehouses.iter().zip(&unit_costs) {
+        for (s, &cost) in shops.iter().zip(costs) {
+            graph.add_edge(*w, *s, (50., cost));
+        }
+    }
+
+    let (flow, cost, flows) =
+        min_cost_flow(&graph, source, sink, |e| e.weight().0, |e| e.weight().1).unwrap();
+    assert_eq!(flow, 50.);
+    // Warehouse 0 sends 5 to shop 0 and 15 to shop 2, warehouse 1 sends 5 to
+    // shop 0 and 25 to shop 1.
+    assert_eq!(cost, 5. * 2. + 15. * 1. + 5. * 5. + 25. * 4.);
+    let shipped: Vec<_> = flows[5..].to_vec();
+    assert_eq!(shipped, vec![5., 0., 15., 5., 25., 0.]);
+}
+
+#[test]
+fn min_cost_flow_negative_costs() {
+    // A negative cost edge makes the longer path cheaper.
+    let mut graph = Graph::<(), (f32, f32)>::new();
+    let s = graph.add_node(());
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let t = graph.add_node(());
+    graph.extend_with_edges(&[
+        (s, a, (1., 1.)),
+        (a, t, (1., 1.)),
+        (s, b, (1., 3.)),
+        (b, a, (1., -4.)),
+        (b, t, (1., 1.)),
+    ]);
+    let (flow, cost, flows) =
+        min_cost_flow(&graph, s, t, |e| e.weight().0, |e| e.weight().1).unwrap();
+    assert_eq!(flow, 2.);
+    assert_eq!(cost, 6.);
+    assert_eq!(flows, vec![1., 1., 1., 0., 1.]);
+
+    // A negative cycle is reported as an error.
+    graph.add_edge(a, b, (1., 3.));
+    assert!(min_cost_flow(&graph, s, t, |e| e.weight().0, |e| e.weight().1).is_err());
+}
+
+#[test]
+fn min_cost_flow_parallel_edges_and_unreachable() {
+    let mut graph = Graph::<(), (f64, f64)>::new();
+    let s = graph.add_node(());
+    let t = graph.add_node(());
+    let z = graph.add_node(());
+    graph.extend_with_edges(&[
+        (s, t, (2., 5.)),
+        (s, t, (1., 1.)),
+        (s, t, (0., -1.)),
+        (t, z, (4., 2.)),
+    ]);
+    let (flow, cost, flows) =
+        min_cost_flow(&graph, s, t, |e| e.weight().0, |e| e.weight().1).unwrap();
+    assert_eq!((flow, cost), (3., 11.));
+    assert_eq!(flows, vec![2., 1., 0., 0.]);
+
+    assert_eq!(
+        min_cost_flow(&graph, z, s, |e| e.weight().0, |e| e.weight().1),
+        Ok((0., 0., vec![0.; 4]))
+    );
+    assert_eq!(
+        min_cost_flow(&graph, s, s, |e| e.weight().0, |e| e.weight().1),
+        Ok((0., 0., vec![0.; 4]))
+    );
+}
+
+#[test]
+fn min_cost_flow_is_max_flow() {
+    // Example from CLRS book, with unit costs.
+    let mut graph = StableGraph::<(), f64>::new();
+    for _ in 0..7 {
+        graph.add_node(());
+    }
+    graph.extend_with_edges(&[
+        (0, 1, 16.),
+        (0, 2, 13.),
+        (1, 2, 10.),
+        (1, 3, 12.),
+        (2, 1, 4.),
+        (2, 4, 14.),
+        (3, 2, 9.),
+        (3, 5, 20.),
+        (4, 3, 7.),
+        (4, 5, 4.),
+    ]);
+    // The removed node leaves a hole in the indices.
+    graph.remove_node(6.into());
+    let (max_flow, _) = ford_fulkerson(&graph, 0.into(), 5.into());
+    let (flow, cost, _) =
+        min_cost_flow(&graph, 0.into(), 5.into(), |e| *e.weight(), |_| 1.).unwrap();
+    assert_eq!(flow, max_flow);
+    // 12 units along 0 -> 1 -> 3 -> 5, 4 along 0 -> 2 -> 4 -> 5 and
+    // 7 along 0 -> 2 -> 4 -> 3 -> 5.
+    assert_eq!(cost, 12. * 3. + 4. * 3. + 7. * 4.);
+}
+
+#[test]
+fn min_cost_flow_integer_costs() {
+    // The same network as in `min_cost_flow_negative_costs`, with integral
+    // capacities and costs.
+    let mut graph = Graph::<(), (u32, i64)>::new();
+    let s = graph.add_node(());
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let t = graph.add_node(());
+    // Not reachable from the source.
+    let u = graph.add_node(());
+    graph.extend_with_edges(&[
+        (s, a, (1, 1)),
+        (a, t, (1, 1)),
+        (s, b, (1, 3)),
+        (b, a, (1, -4)),
+        (b, t, (1, 1)),
+        (u, t, (5, -10)),
+    ]);
+    let (flow, cost, flows) =
+        min_cost_flow(&graph, s, t, |e| i64::from(e.weight().0), |e| e.weight().1).unwrap();
+    assert_eq!(flow, 2);
+    assert_eq!(cost, 6);
+    assert_eq!(flows, vec![1, 1, 1, 0, 1, 0]);
+
+    graph.add_edge(a, b, (1, 3));
+    assert!(min_cost_flow(&graph, s, t, |e| i64::from(e.weight().0), |e| e.weight().1).is_err());
+}
+
+#[test]
+fn min_cost_flow_unsigned_costs() {
+    // The second path cancels the flow on 1 -> 2, which must not negate its
+    // unsigned cost.
+    let graph = Graph::<(), (u32, u32)>::from_edges(&[
+        (0, 1, (1, 1)),
+        (1, 2, (1, 1)),
+        (2, 3, (1, 1)),
+        (0, 2, (1, 10)),
+        (1, 3, (1, 10)),
+    ]);
+    let (flow, cost, flows) = min_cost_flow(
+        &graph,
+        0.into(),
+        3.into(),
+        |e| e.weight().0,
+        |e| e.weight().1,
+    )
+    .unwrap();
+    assert_eq!(flow, 2);
+    assert_eq!(cost, 22);
+    assert_eq!(flows, vec![1, 0, 1, 1, 1]);
+}
@@ -1,9 +1,43 @@
-use petgraph::{algo::min_spanning_tree, dot::Dot, graph::UnGraph, Graph};
+use petgraph::algo::{
+    min_spanning_arborescence, min_spanning_tree, min_spanning_tree_boruvka, min_spanning_tree_prim,
+};
+use petgraph::data::FromElements;
+use petgraph::matrix_graph::UnMatrix;
+use petgraph::{dot::Dot, graph::NodeIndex, graph::UnGraph, Graph};
+
+/// The example graph of `mst`, with its two components.
+fn two_components() -> Graph<&'static str, f64> {
+    let mut gr = Graph::<_, _>::new();
+    let a = gr.add_node("A");
+    let b = gr.add_node("B");
+    let c = gr.add_node("C");
+    let d = gr.add_node("D");
+    let e = gr.add_node("E");
+    let f = gr.add_node("F");
+    let g = gr.add_node("G");
+    gr.add_edge(a, b, 7.);
+    gr.add_edge(a, d, 5.);
+    gr.add_edge(d, b, 9.);
+    gr.add_edge(b, c, 8.);
+    gr.add_edge(b, e, 7.);
+    gr.add_edge(c, e, 5.);
+    gr.add_edge(d, e, 15.);
+    gr.add_edge(d, f, 6.);
+    gr.add_edge(f, e, 8.);
+    gr.add_edge(f, g, 11.);
+    gr.add_edge(e, g, 9.);
+
+    let h = gr.add_node("H");
+    let i = gr.add_node("I");
+    let j = gr.add_node("J");
+    gr.add_edge(h, i, 1.);
+    gr.add_edge(h, j, 3.);
+    gr.add_edge(i, j, 1.);
+    gr
+}
 
 #[test]
 fn mst() {
-    use petgraph::data::FromElements;
-
     let mut gr = Graph::<_, _>::new();
     let a = gr.add_node("A");
     let b = gr.add_node("B");
@@ -57,3 +91,197 @@ fn mst() {
     assert!(mst.find_edge(d, b).is_none());
     assert!(mst.find_edge(b, c).is_none());
 }
+
+#[test]
+fn mst_prim() {
+    let gr = two_components().into_edge_type::<petgraph::Undirected>();
+    let n = NodeIndex::new;
+
+    let mst: UnGraph<_, _> = UnGraph::from_elements(min_spanning_tree_prim(&gr, n(3)));
+    assert_eq!(mst.node_count(), gr.node_count());
+    assert_eq!(mst.edge_count(), 6);
+    for &(a, b) in &[(0, 1), (0, 3), (1, 4), (4, 2), (4, 6), (3, 5)] {
+        assert!(mst.find_edge(n(a), n(b)).is_some());
+    }
+    assert_eq!(mst[n(7)], "H");
+    assert_eq!(mst.neighbors(n(7)).count(), 0);
+
+    let mst: UnGraph<_, _> = UnGraph::from_elements(min_spanning_tree_prim(&gr, n(9)));
+    assert_eq!(mst.edge_count(), 2);
+    assert!(mst.find_edge(n(7), n(8)).is_some());
+    assert!(mst.find_edge(n(8), n(9)).is_some());
+}
+
+#[test]
+fn mst_prim_matrix() {
+    let mut gr = UnMatrix::<(), u32>::with_capacity(4);
+    let n: Vec<_> = (0..4).map(|_| gr.add_node(())).collect();
+    gr.extend_with_edges(&[
+        (n[0], n[1], 3),
+        (n[0], n[2], 1),
+        (n[0], n[3], 4),
+        (n[1], n[2], 1),
+        (n[1], n[3], 5),
+        (n[2], n[3], 9),
+    ]);
+    let mst = UnGraph::<(), u32>::from_elements(min_spanning_tree_prim(&gr, n[1]));
+    assert_eq!(mst.edge_count(), 3);
+    assert_eq!(mst.edge_weights().sum::<u32>(), 6);
+}
+
+#[test]
+fn mst_boruvka() {
+    let gr = two_components();
+    let kruskal: UnGraph<_, _> = UnGraph::from_elements(min_spanning_tree(&gr));
+    let boruvka: UnGraph<_, _> = UnGraph::from_elements(min_spanning_tree_boruvka(&gr));
+    assert_eq!(boruvka.node_count(), gr.node_count());
+    assert_eq!(boruvka.edge_count(), gr.node_count() - 2);
+    for edge in kruskal.edge_indices() {
+        let (a, b) = kruskal.edge_endpoints(edge).unwrap();
+        assert!(boruvka.find_edge(a, b).is_some());
+    }
+}
+
+#[test]
+fn mst_boruvka_equal_weights() {
+    // With equal weights, only the ties order avoids a cycle.
+    let gr =
+        UnGraph::<(), u8>::from_edges(&[(0, 1, 1), (1, 2, 1), (2, 0, 1), (2, 3, 1), (3, 0, 1)]);
+    let mst = UnGraph::<(), u8>::from_elements(min_spanning_tree_boruvka(&gr));
+    assert_eq!(mst.edge_count(), 3);
+    assert!(!petgraph::algo::is_cyclic_undirected(&mst));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn mst_parallel_boruvka() {
+    use petgraph::algo::parallel_min_spanning_tree_boruvka;
+
+    let gr = two_components();
+    let sequential: Vec<_> = min_spanning_tree_boruvka(&gr).collect();
+    let parallel: Vec<_> = parallel_min_spanning_tree_boruvka(&gr).collect();
+    assert_eq!(sequential, parallel);
+}
+
+#[test]
+fn arborescence_follows_directions() {
+    // The cheapest undirected tree uses 2 -> 1 backwards.
+    let gr = Graph::<(), u32>::from_edges(&[(0, 1, 10), (0, 2, 1), (1, 2, 1)]);
+    let n = NodeIndex::new;
+    let tree: Graph<(), u32> =
+        Graph::from_elements(min_spanning_arborescence(&gr, Some(n(0))).unwrap());
+    assert_eq!(tree.edge_count(), 2);
+    assert!(tree.find_edge(n(0), n(1)).is_some());
+    assert!(tree.find_edge(n(0), n(2)).is_some());
+    assert!(tree.find_edge(n(1), n(2)).is_none());
+}
+
+#[test]
+fn arborescence_nested_cycles() {
+    // The cheapest incoming edges form the cycle 1 -> 2 -> 3 -> 1, and after
+    // its contraction, a cycle with node 4.
+    let gr = Graph::<(), i32>::from_edges(&[
+        (0, 1, 10),
+        (0, 4, 20),
+        (1, 2, 1),
+        (2, 3, 1),
+        (3, 1, 1),
+        (3, 4, 2),
+        (4, 2, 0),
+        (4, 3, 5),
+    ]);
+    let n = NodeIndex::new;
+    let tree: Graph<(), i32> =
+        Graph::from_elements(min_spanning_arborescence(&gr, Some(n(0))).unwrap());
+    assert_eq!(tree.edge_count(), 4);
+    assert_eq!(tree.edge_weights().sum::<i32>(), 14);
+    for &(a, b) in &[(0, 1), (1, 2), (2, 3), (3, 4)] {
+        assert!(tree.find_edge(n(a), n(b)).is_some());
+    }
+}
+
+#[test]
+fn arborescence_deeply_nested_cycles() {
+    // The cheapest incoming edges form the cycle 1 -> 2 -> 1, which forms a
+    // cycle with node 3 once contracted, then with node 4, and so on: each
+    // contraction nests the previous one.
+    let node_count = 2000;
+    let mut edges = vec![(0, 1, 10 * node_count)];
+    for i in 1..node_count - 1 {
+        edges.push((i, i + 1, 1));
+        edges.push((i + 1, i, 2));
+    }
+    let gr = Graph::<(), u32>::from_edges(&edges);
+    let n = NodeIndex::new;
+    let tree: Graph<(), u32> =
+        Graph::from_elements(min_spanning_arborescence(&gr, Some(n(0))).unwrap());
+    assert_eq!(tree.edge_count(), node_count as usize - 1);
+    for i in 0..node_count as usize - 1 {
+        assert!(tree.find_edge(n(i), n(i + 1)).is_some());
+    }
+}
+
+#[test]
+fn arborescence_unreachable() {
+    let gr = Graph::<(), f64>::from_edges(&[(0, 1, 1.), (2, 1, 1.), (3, 3, 1.)]);
+    let n = NodeIndex::new;
+    let error = min_spanning_arborescence(&gr, Some(n(0))).unwrap_err();
+    assert_eq!(error.nodes(), &[n(2), n(3)]);
+    assert!(min_spanning_arborescence(&gr, Some(n(2))).is_err());
+}
+
+#[test]
+fn arborescence_branching() {
+    let n = NodeIndex::new;
+    // With only positive weights, the minimum branching is empty.
+    let gr = Graph::<(), u32>::from_edges(&[(0, 1, 4), (2, 1, 3), (2, 3, 7), (3, 2, 2), (3, 4, 1)]);
+    let branching: Graph<(), u32> =
+        Graph::from_elements(min_spanning_arborescence(&gr, None).unwrap());
+    assert_eq!(branching.node_count(), 5);
+    assert_eq!(branching.edge_count(), 0);
+
+    // Node 1 keeps the cheapest of its three incoming edges, so the cycle
+    // 0 -> 1 -> 0 is broken, and the positive edge 2 -> 4 is left out.
+    let gr = Graph::<(), i32>::from_edges(&[
+        (0, 1, -1),
+        (1, 0, -1),
+        (2, 1, -3),
+        (3, 1, -2),
+        (3, 2, -1),
+        (2, 4, 1),
+    ]);
+    let branching: Graph<(), i32> =
+        Graph::from_elements(min_spanning_arborescence(&gr, None).unwrap());
+    assert_eq!(branching.edge_weights().sum::<i32>(), -5);
+    for &(a, b) in &[(1, 0), (2, 1), (3, 2)] {
+        assert!(branching.find_edge(n(a), n(b)).is_some());
+    }
+    assert_eq!(branching.edge_count(), 3);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn arborescence_in_stable_graph() {
+    use petgraph::stable_graph::StableDiGraph;
+
+    let mut gr = StableDiGraph::<u8, u8>::new();
+    let a = gr.add_node(0);
+    let b = gr.add_node(1);
+    let c = gr.add_node(2);
+    let d = gr.add_node(3);
+    gr.extend_with_edges(&[(a, b, 1), (a, d, 9), (b, c, 1), (c, d, 1), (d, b, 0)]);
+    gr.remove_node(c);
+
+    let tree =
+        StableDiGraph::<u8, u8>::from_elements(min_spanning_arborescence(&gr, Some(a)).unwrap());
+    assert_eq!(tree.node_count(), 3);
+    let mut edges: Vec<_> = tree
+        .edge_indices()
+        .map(|e| {
+            let (x, y) = tree.edge_endpoints(e).unwrap();
+            (tree[x], tree[y], tree[e])
+        })
+        .collect();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 3, 9), (3, 1, 0)]);
+}
@@ -1,7 +1,16 @@
-use petgraph::{algo::page_rank, Graph};
+mod common;
+
+use common::assert_close;
+use petgraph::algo::{
+    page_rank, page_rank_with_options, weighted_page_rank_with_options, PageRankOptions,
+};
+use petgraph::prelude::*;
+use petgraph::Graph;
 
 #[cfg(feature = "rayon")]
 use petgraph::algo::page_rank::parallel_page_rank;
+#[cfg(feature = "rayon")]
+use petgraph::algo::{parallel_page_rank_with_options, parallel_weighted_page_rank_with_options};
 
 fn graph_example() -> Graph<String, f32> {
     // Taken and adapted from https://github.com/neo4j-labs/graph?tab=readme-ov-file#how-to-run-algorithms
@@ -81,3 +90,116 @@ fn test_par_page_rank() {
             || computed.is_nan()
             || expected.is_nan()));
 }
+
+#[test]
+fn test_page_rank_with_options() {
+    let graph = graph_example();
+    let options = PageRankOptions::new().tolerance(1e-15).max_iter(1000);
+    let scores = page_rank_with_options(&graph, &options);
+    assert!(scores.converged);
+    assert!(scores.iterations > 3);
+    assert_close(
+        &scores.ranks,
+        &[
+            0.030146, 0.378964, 0.336574, 0.014455, 0.036921, 0.079292, 0.036921, 0.014455,
+            0.014455, 0.014455, 0.014455, 0.014455, 0.014455,
+        ],
+    );
+
+    // Not enough iterations.
+    let scores = page_rank_with_options(&graph, &options.clone().max_iter(3));
+    assert!(!scores.converged);
+    assert_eq!(scores.iterations, 3);
+    let scores = page_rank_with_options(&graph, &options.max_iter(0));
+    assert!(!scores.converged);
+    assert_eq!(scores.ranks, vec![1. / 13.; 13]);
+}
+
+#[test]
+fn test_personalized_page_rank() {
+    let graph = graph_example();
+    let mut personalization = vec![0.; 13];
+    personalization[0] = 1.;
+    personalization[12] = 3.;
+    let options = PageRankOptions::new()
+        .tolerance(1e-15)
+        .max_iter(1000)
+        .personalization(personalization);
+    let scores = page_rank_with_options(&graph, &options);
+    assert!(scores.converged);
+    assert_close(
+        &scores.ranks,
+        &[
+            0.070924, 0.287885, 0.244702, 0., 0.043183, 0.15241, 0.043183, 0., 0., 0., 0., 0.,
+            0.157714,
+        ],
+    );
+}
+
+#[test]
+fn test_weighted_page_rank() {
+    let graph = graph_example();
+    let options = PageRankOptions::new().tolerance(1e-15).max_iter(1000);
+    let weight = |e: petgraph::graph::EdgeReference<f32>| (e.id().index() % 3 + 1) as f64;
+    let scores = weighted_page_rank_with_options(&graph, &options, weight);
+    assert!(scores.converged);
+    assert_close(
+        &scores.ranks,
+        &[
+            0.039675, 0.373759, 0.332816, 0.015121, 0.038515, 0.082567, 0.026818, 0.015121,
+            0.015121, 0.015121, 0.015121, 0.015121, 0.015121,
+        ],
+    );
+
+    // Edges of weight zero are not followed.
+    let g = Graph::<(), f64>::from_edges(&[(0, 1, 1.), (0, 2, 0.), (1, 0, 0.)]);
+    let scores = weighted_page_rank_with_options(&g, &options, |e| *e.weight());
+    let mut unweighted = Graph::<(), ()>::from_edges(&[(0, 1)]);
+    unweighted.add_node(());
+    let unweighted = page_rank_with_options(&unweighted, &options);
+    assert_close(&scores.ranks, &unweighted.ranks);
+}
+
+#[test]
+fn test_page_rank_with_options_stable_graph() {
+    let mut g = StableGraph::<(), ()>::new();
+    let n: Vec<_> = (0..4).map(|_| g.add_node(())).collect();
+    g.extend_with_edges(&[(0, 1), (1, 2), (2, 3), (3, 1)]);
+    g.remove_node(n[0]);
+
+    let options = PageRankOptions::new().tolerance(1e-15);
+    let scores = page_rank_with_options(&g, &options);
+    assert_close(&scores.ranks, &[0., 1. / 3., 1. / 3., 1. / 3.]);
+
+    let empty = Graph::<(), ()>::new();
+    assert!(page_rank_with_options(&empty, &options).ranks.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_page_rank_with_options_zero_personalization() {
+    let graph = graph_example();
+    let options = PageRankOptions::new().personalization(vec![0.; 13]);
+    page_rank_with_options(&graph, &options);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_par_page_rank_with_options() {
+    let graph = graph_example();
+    let mut personalization = vec![1.; 13];
+    personalization[1] = 5.;
+    let options = PageRankOptions::new()
+        .tolerance(1e-15)
+        .max_iter(1000)
+        .personalization(personalization);
+    let sequential = page_rank_with_options(&graph, &options);
+    let parallel = parallel_page_rank_with_options(&graph, &options);
+    assert_eq!(parallel.iterations, sequential.iterations);
+    assert_close(&parallel.ranks, &sequential.ranks);
+
+    let weight = |e: petgraph::graph::EdgeReference<f32>| (e.id().index() % 3 + 1) as f64;
+    let sequential = weighted_page_rank_with_options(&graph, &options, weight);
+    let parallel = parallel_weighted_page_rank_with_options(&graph, &options, weight);
+    assert_close(&parallel.ranks, &sequential.ranks);
+}
@@ -14,7 +14,7 @@ mod utils;
 use utils::{Small, Tournament};
 
 use odds::prelude::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
 use itertools::assert_equal;
@@ -23,20 +23,34 @@ use quickcheck::{Arbitrary, Gen};
 use rand::Rng;
 
 use petgraph::algo::{
-    bellman_ford, condensation, dijkstra, find_negative_cycle, floyd_warshall, ford_fulkerson,
-    greedy_feedback_arc_set, greedy_matching, is_cyclic_directed, is_cyclic_undirected,
-    is_isomorphic, is_isomorphic_matching, k_shortest_path, kosaraju_scc, maximum_matching,
-    min_spanning_tree, page_rank, tarjan_scc, toposort, Matching,
+    all_simple_paths, approx_vertex_cover, articulation_points, bellman_ford,
+    betweenness_centrality, bidirectional_dijkstra, bipartite_edge_coloring,
+    bipartite_maximum_matching, bipartite_minimum_vertex_cover, bipartition, block_cut_tree,
+    bridges, christofides, chromatic_number, condensation, connected_components, dijkstra,
+    dijkstra_paths, dinic, edge_betweenness_centrality, edge_coloring, eulerian_circuit,
+    eulerian_path, find_negative_cycle, floyd_warshall, floyd_warshall_matrix, ford_fulkerson,
+    greedy_coloring, greedy_dominating_set, greedy_feedback_arc_set, greedy_independent_set,
+    greedy_matching, hamiltonian_path, has_eulerian_path, held_karp, is_cyclic_directed,
+    is_cyclic_undirected, is_dominating_set, is_eulerian, is_independent_set, is_isomorphic,
+    is_isomorphic_matching, is_vertex_cover, johnson, k_shortest_path, kosaraju_scc,
+    label_propagation, louvain, max_weight_assignment, maximal_cliques, maximum_clique,
+    maximum_independent_set, maximum_matching, maximum_weight_matching, min_cost_assignment,
+    min_cost_flow, min_cut, min_spanning_arborescence, min_spanning_tree,
+    min_spanning_tree_boruvka, min_spanning_tree_prim, minimum_vertex_cover, modularity,
+    multi_source_dijkstra, multi_source_dijkstra_path, nearest_neighbor_tour, or_opt, page_rank,
+    push_relabel, quotient_graph, spfa, steiner_tree, tarjan_scc, toposort, two_opt,
+    weighted_betweenness_centrality, weighted_page_rank_with_options, yen_k_shortest_paths,
+    ColoringOrder, EulerianWalk, Matching, NotEulerian, PageRankOptions,
 };
 use petgraph::data::FromElements;
 use petgraph::dot::{Config, Dot};
-use petgraph::graph::{edge_index, node_index, IndexType};
+use petgraph::graph::{edge_index, node_index, EdgeReference, IndexType};
 use petgraph::graphmap::NodeTrait;
 use petgraph::operator::complement;
 use petgraph::prelude::*;
 use petgraph::visit::{
     EdgeFiltered, EdgeIndexable, IntoEdgeReferences, IntoEdges, IntoNeighbors, IntoNodeIdentifiers,
-    IntoNodeReferences, NodeCount, NodeIndexable, Reversed, Topo, VisitMap, Visitable,
+    IntoNodeReferences, NodeCount, NodeIndexable, Reversed, Topo, VisitMap, Visitable, Walker,
 };
 use petgraph::EdgeType;
 
@@ -68,6 +82,615 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // Prim's and Borůvka's algorithms find trees as light as Kruskal's
+    fn mst_variants(g: Graph<(), u32, Undirected>) -> bool {
+        if g.node_count() == 0 {
+            return true;
+        }
+        // keep the weights small to avoid overflows
+        let g = g.map(|_, _| (), |_, &w| w % 1000);
+        let weight = |mst: &Graph<(), u32, Undirected>| mst.edge_weights().sum::<u32>();
+        let kruskal = mst_graph(&g);
+
+        let boruvka = Graph::from_elements(min_spanning_tree_boruvka(&g));
+        assert_eq!(boruvka.node_count(), g.node_count());
+        assert!(!is_cyclic_undirected(&boruvka));
+        assert_eq!(boruvka.edge_count(), kruskal.edge_count());
+        assert_eq!(weight(&boruvka), weight(&kruskal));
+        #[cfg(feature = "rayon")]
+        {
+            use petgraph::algo::parallel_min_spanning_tree_boruvka;
+            let parallel: Vec<_> = parallel_min_spanning_tree_boruvka(&g).collect();
+            let sequential: Vec<_> = min_spanning_tree_boruvka(&g).collect();
+            assert_eq!(parallel, sequential);
+        }
+
+        // the tree of the first node's component
+        let root = NodeIndex::new(0);
+        let component: HashSet<_> = Dfs::new(&g, root).iter(&g).collect();
+        let kruskal_component: u32 = kruskal
+            .edge_references()
+            .filter(|e| component.contains(&e.source()))
+            .map(|e| *e.weight())
+            .sum();
+        let prim = Graph::from_elements(min_spanning_tree_prim(&g, root));
+        assert_eq!(prim.node_count(), g.node_count());
+        assert!(!is_cyclic_undirected(&prim));
+        assert_eq!(prim.edge_count(), component.len() - 1);
+        assert!(prim.edge_references().all(|e| component.contains(&e.source())));
+        weight(&prim) == kruskal_component
+    }
+}
+
+/// The weight of the cheapest choice of parent edges that forms a branching,
+/// where only `root` may lack a parent if it is given.
+fn best_branching(g: &Graph<(), i8>, root: Option<NodeIndex>) -> Option<i64> {
+    fn choose(
+        g: &Graph<(), i8>,
+        root: Option<NodeIndex>,
+        parents: &mut Vec<Option<EdgeIndex>>,
+        best: &mut Option<i64>,
+    ) {
+        let v = parents.len();
+        if v == g.node_count() {
+            // every node must reach a root by following its parents
+            for start in 0..v {
+                let mut node = start;
+                let mut steps = 0;
+                while let Some(e) = parents[node] {
+                    node = g.edge_endpoints(e).unwrap().0.index();
+                    steps += 1;
+                    if steps > v {
+                        return;
+                    }
+                }
+            }
+            let weight = parents.iter().flatten().map(|&e| g[e] as i64).sum();
+            if best.map_or(true, |b| weight < b) {
+                *best = Some(weight);
+            }
+            return;
+        }
+        let v = NodeIndex::new(v);
+        if root.map_or(true, |r| r == v) {
+            parents.push(None);
+            choose(g, root, parents, best);
+            parents.pop();
+        }
+        if root != Some(v) {
+            for edge in g.edges_directed(v, Incoming) {
+                if edge.source() != v {
+                    parents.push(Some(edge.id()));
+                    choose(g, root, parents, best);
+                    parents.pop();
+                }
+            }
+        }
+    }
+    let mut best = None;
+    choose(g, root, &mut Vec::new(), &mut best);
+    best
+}
+
+quickcheck! {
+    fn arborescence(g: Small<Graph<(), i8>>) -> bool {
+        // keep the brute force small
+        let g = g.filter_map(|n, _| if n.index() < 6 { Some(()) } else { None }, |_, &w| Some(w));
+        let check = |root: Option<NodeIndex>| {
+            let best = best_branching(&g, root);
+            let tree = match min_spanning_arborescence(&g, root) {
+                Ok(elements) => Graph::<(), i8>::from_elements(elements),
+                Err(error) => {
+                    let reachable: HashSet<_> = Dfs::new(&g, root.unwrap()).iter(&g).collect();
+                    let unreachable: Vec<_> =
+                        g.node_indices().filter(|n| !reachable.contains(n)).collect();
+                    return best.is_none() && error.nodes() == &unreachable[..];
+                }
+            };
+            assert_eq!(tree.node_count(), g.node_count());
+            assert!(!is_cyclic_undirected(&tree));
+            assert!(tree.node_indices().all(|n| tree.edges_directed(n, Incoming).count() <= 1));
+            if root.is_some() && tree.edge_count() + 1 != g.node_count() {
+                return false;
+            }
+            let weight = tree.edge_weights().map(|&w| w as i64).sum();
+            best == Some(weight)
+        };
+        let rooted = g.node_count() == 0 || check(Some(NodeIndex::new(0)));
+        rooted && check(None)
+    }
+}
+
+quickcheck! {
+    fn steiner(g: Small<UnGraph<(), u8>>, terminal_mask: u8) -> bool {
+        // keep the brute force small
+        let g = g.filter_map(|n, _| if n.index() < 8 { Some(()) } else { None }, |_, &w| Some(w));
+        let terminals: Vec<_> = g
+            .node_indices()
+            .filter(|n| terminal_mask & (1 << n.index()) != 0)
+            .collect();
+        let (nodes, edges) = steiner_tree(&g, terminals.iter().cloned(), |e| *e.weight());
+
+        // a forest of the nodes, where the terminals are connected like in g
+        // and all leaves are terminals
+        assert!(terminals.iter().all(|t| nodes.contains(t)));
+        assert!(edges.iter().all(|&e| {
+            let (a, b) = g.edge_endpoints(e).unwrap();
+            nodes.contains(&a) && nodes.contains(&b)
+        }));
+        let forest = g.filter_map(
+            |n, _| if nodes.contains(&n) { Some(()) } else { None },
+            |e, &w| if edges.contains(&e) { Some(w) } else { None },
+        );
+        assert!(!is_cyclic_undirected(&forest));
+        let tree = EdgeFiltered::from_fn(&g, |e| edges.contains(&e.id()));
+        for &n in &nodes {
+            assert!(tree.edges(n).count() > 1 || terminals.contains(&n));
+        }
+        for &a in &terminals {
+            let in_g: HashSet<_> = Dfs::new(&g, a).iter(&g).collect();
+            let in_tree: HashSet<_> = Dfs::new(&tree, a).iter(&tree).collect();
+            assert!(terminals.iter().all(|b| in_g.contains(b) == in_tree.contains(b)));
+        }
+
+        // at most twice the cost of a minimum Steiner forest: the cheapest
+        // spanning forest of the terminals and some other nodes that connects
+        // the terminals like in g
+        let others: Vec<_> = g.node_indices().filter(|n| !terminals.contains(n)).collect();
+        let mut best = u32::max_value();
+        for subset in 0..1u32 << others.len() {
+            let mut included: HashSet<_> = terminals.iter().cloned().collect();
+            for (i, &n) in others.iter().enumerate() {
+                if subset & (1 << i) != 0 {
+                    included.insert(n);
+                }
+            }
+            let induced = g.filter_map(
+                |n, _| if included.contains(&n) { Some(n) } else { None },
+                |e, _| Some(e),
+            );
+            let forest =
+                Graph::<NodeIndex, EdgeIndex, Undirected>::from_elements(min_spanning_tree(&induced));
+            let connects = terminals.iter().all(|&a| {
+                let in_g: HashSet<_> = Dfs::new(&g, a).iter(&g).collect();
+                let start = forest.node_indices().find(|&n| forest[n] == a).unwrap();
+                let reached: HashSet<_> =
+                    Dfs::new(&forest, start).iter(&forest).map(|n| forest[n]).collect();
+                terminals.iter().all(|b| !in_g.contains(b) || reached.contains(b))
+            });
+            if connects {
+                best = best.min(forest.edge_weights().map(|&e| g[e] as u32).sum());
+            }
+        }
+        edges.iter().map(|&e| g[e] as u32).sum::<u32>() <= 2 * best
+    }
+}
+
+fn is_proper_coloring<G>(g: G, coloring: &HashMap<G::NodeId, usize>) -> bool
+where
+    G: IntoEdgeReferences,
+    G::NodeId: Eq + Hash,
+{
+    g.edge_references()
+        .all(|e| e.source() == e.target() || coloring[&e.source()] != coloring[&e.target()])
+}
+
+quickcheck! {
+    fn coloring(g: Graph<(), ()>) -> bool {
+        let mut counts = vec![];
+        for &order in &[ColoringOrder::LargestFirst, ColoringOrder::SmallestLast, ColoringOrder::DSatur] {
+            let coloring = greedy_coloring(&g, order);
+            assert_eq!(coloring.len(), g.node_count());
+            assert!(is_proper_coloring(&g, &coloring));
+            counts.push(coloring.values().max().map_or(0, |&c| c + 1));
+        }
+
+        // the least number of colors, by brute force on a few nodes
+        let g = g.filter_map(|n, _| if n.index() < 6 { Some(()) } else { None }, |_, _| Some(()));
+        let (count, coloring) = chromatic_number(&g);
+        assert!(is_proper_coloring(&g, &coloring));
+        assert_eq!(coloring.values().max().map_or(0, |&c| c + 1), count);
+        assert!(counts.iter().all(|&greedy| count <= greedy));
+        let fewer = count.saturating_sub(1);
+        let colorable = (0..fewer.pow(g.node_count() as u32)).any(|mut code| {
+            let mut colors = vec![];
+            for _ in 0..g.node_count() {
+                colors.push(code % fewer);
+                code /= fewer;
+            }
+            g.edge_references().all(|e| {
+                e.source() == e.target() || colors[e.source().index()] != colors[e.target().index()]
+            })
+        });
+        count == 0 || !colorable
+    }
+}
+
+quickcheck! {
+    fn edge_colorings(g: Small<UnGraph<(), ()>>, parallel: bool) -> bool {
+        let mut g = (*g).clone();
+        if parallel {
+            for e in g.edge_indices().step_by(3) {
+                let (a, b) = g.edge_endpoints(e).unwrap();
+                g.add_edge(a, b, ());
+            }
+        }
+        let mut degrees = vec![0; g.node_count()];
+        let mut pairs = HashSet::new();
+        let mut simple = true;
+        for e in g.edge_references() {
+            let (a, b) = (e.source().index(), e.target().index());
+            if a != b {
+                degrees[a] += 1;
+                degrees[b] += 1;
+                simple &= pairs.insert((a.min(b), a.max(b)));
+            }
+        }
+        let max_degree = degrees.iter().cloned().max().unwrap_or(0);
+
+        let proper = |coloring: &HashMap<EdgeIndex, usize>| {
+            g.edge_references().filter(|e| e.source() != e.target()).all(|a| {
+                g.edges(a.source())
+                    .chain(g.edges(a.target()))
+                    .filter(|b| b.id() != a.id() && b.source() != b.target())
+                    .all(|b| coloring[&a.id()] != coloring[&b.id()])
+            })
+        };
+        let count = |coloring: &HashMap<EdgeIndex, usize>| coloring.values().max().map_or(0, |&c| c + 1);
+
+        let coloring = edge_coloring(&g);
+        assert_eq!(coloring.len(), g.edge_references().filter(|e| e.source() != e.target()).count());
+        assert!(proper(&coloring));
+        let bound = if simple { max_degree + 1 } else { (max_degree + 1).max(2 * max_degree - 1) };
+        assert!(count(&coloring) <= bound);
+
+        match bipartite_edge_coloring(&g) {
+            Some(coloring) => {
+                assert!(bipartition(&g).is_some());
+                proper(&coloring) && count(&coloring) == max_degree
+            }
+            None => bipartition(&g).is_none(),
+        }
+    }
+}
+
+quickcheck! {
+    fn cliques(g: Graph<(), ()>) -> bool {
+        let largest = maximal_cliques(&g).map(|clique| clique.len()).max().unwrap_or(0);
+        assert_eq!(maximum_clique(&g).len(), largest);
+
+        // keep the brute force small
+        let g = g.filter_map(|n, _| if n.index() < 10 { Some(()) } else { None }, |_, _| Some(()));
+        let n = g.node_count();
+        let adjacent = |a: usize, b: usize| {
+            a == b || g.find_edge_undirected(node_index(a), node_index(b)).is_some()
+        };
+        let is_clique = |set: u32| {
+            (0..n).all(|a| (0..n).all(|b| set & (1 << a) == 0 || set & (1 << b) == 0 || adjacent(a, b)))
+        };
+        let mut expected = vec![];
+        for set in 1..1u32 << n {
+            if is_clique(set) && (0..n).all(|a| set & (1 << a) != 0 || !is_clique(set | 1 << a)) {
+                expected.push(set);
+            }
+        }
+
+        let as_set = |clique: Vec<NodeIndex>| clique.iter().fold(0u32, |set, a| set | 1 << a.index());
+        let mut cliques: Vec<_> = maximal_cliques(&g).map(as_set).collect();
+        cliques.sort();
+        assert_eq!(cliques, expected);
+        let maximum = maximum_clique(&g);
+        let size = maximum.len() as u32;
+        let maximum = as_set(maximum);
+        is_clique(maximum) && expected.iter().all(|set| set.count_ones() <= size)
+    }
+}
+
+quickcheck! {
+    fn covering(g: Graph<(), ()>) -> bool {
+        let cover = approx_vertex_cover(&g);
+        assert!(is_vertex_cover(&g, &cover));
+        assert!(is_independent_set(&g, &greedy_independent_set(&g)));
+        assert!(is_dominating_set(&g, &greedy_dominating_set(&g)));
+
+        // keep the brute force small
+        let g = g.filter_map(|n, _| if n.index() < 12 { Some(()) } else { None }, |_, _| Some(()));
+        let n = g.node_count();
+        let edges: Vec<_> = g.edge_references().map(|e| (e.source().index(), e.target().index())).collect();
+        let count = |set: u32| set.count_ones() as usize;
+        let is_cover = |set: u32| edges.iter().all(|&(a, b)| set & (1 << a | 1 << b) != 0);
+        let is_dominating = |set: u32| {
+            let dominated = edges.iter().fold(set, |dominated, &(a, b)| {
+                dominated | if set & 1 << a != 0 { 1 << b } else { 0 } | if set & 1 << b != 0 { 1 << a } else { 0 }
+            });
+            dominated == (1 << n) - 1
+        };
+        let min_cover = (0..1u32 << n).filter(|&set| is_cover(set)).map(count).min().unwrap();
+        let min_dominating = (0..1u32 << n).filter(|&set| is_dominating(set)).map(count).min().unwrap();
+        let as_set = |nodes: &HashSet<NodeIndex>| nodes.iter().fold(0u32, |set, a| set | 1 << a.index());
+
+        let cover = minimum_vertex_cover(&g);
+        assert!(is_vertex_cover(&g, &cover));
+        assert_eq!(cover.len(), min_cover);
+        assert!(is_cover(as_set(&cover)));
+        let set = maximum_independent_set(&g);
+        assert!(is_independent_set(&g, &set));
+        assert_eq!(set.len(), n - min_cover);
+        let approx = approx_vertex_cover(&g);
+        assert!(approx.len() <= 2 * min_cover);
+        let dominating = greedy_dominating_set(&g);
+        assert!(is_dominating(as_set(&dominating)));
+        dominating.len() >= min_dominating
+    }
+}
+
+quickcheck! {
+    fn betweenness(g: Graph<(), u8>) -> bool {
+        // every shortest path from s to t has d(s, t) edges, with
+        // d(s, t) - 1 nodes between s and t
+        let (mut path_lengths, mut pair_count) = (0, 0);
+        for s in g.node_indices() {
+            let distances = dijkstra(&g, s, None, |_| 1);
+            path_lengths += distances.values().sum::<usize>();
+            pair_count += distances.len() - 1;
+        }
+        let close = |a: f64, b: usize| (a - b as f64).abs() <= 1e-6 * (b as f64 + 1.);
+
+        let nodes: Vec<f64> = betweenness_centrality(&g, false);
+        assert!(close(nodes.iter().sum(), path_lengths - pair_count));
+        let edges: Vec<f64> = edge_betweenness_centrality(&g, false);
+        assert!(close(edges.iter().sum(), path_lengths));
+
+        // the same paths with all costs equal
+        let weighted: Vec<f64> = weighted_betweenness_centrality(&g, false, |_| 2.5);
+        assert!(nodes.iter().zip(&weighted).all(|(&a, &b)| (a - b).abs() <= 1e-6 * (a + 1.)));
+        #[cfg(feature = "rayon")]
+        {
+            use petgraph::algo::parallel_betweenness_centrality;
+            let parallel: Vec<f64> = parallel_betweenness_centrality(&g, false);
+            assert!(nodes.iter().zip(&parallel).all(|(&a, &b)| (a - b).abs() <= 1e-6 * (a + 1.)));
+        }
+
+        // with costs, the nodes on no shortest path score zero
+        let weighted: Vec<f64> = weighted_betweenness_centrality(&g, false, |e| *e.weight() as u32 + 1);
+        let distances: Vec<_> = g.node_indices().map(|s| dijkstra(&g, s, None, |e| *e.weight() as u32 + 1)).collect();
+        g.node_indices().all(|v| {
+            let between = g.node_indices().any(|s| {
+                s != v && distances[s.index()].get(&v).map_or(false, |&sv| {
+                    distances[v.index()].iter().any(|(&t, &vt)| t != v && t != s && distances[s.index()][&t] == sv + vt)
+                })
+            });
+            between || weighted[v.index()] == 0.
+        })
+    }
+}
+
+fn check_eulerian<Ty: EdgeType>(g: &Graph<(), (), Ty>) -> bool {
+    let mut balance = vec![0i32; g.node_count()];
+    for e in g.edge_references() {
+        balance[e.source().index()] += 1;
+        balance[e.target().index()] += if g.is_directed() { -1 } else { 1 };
+    }
+    let unbalanced: Vec<NodeIndex> = g
+        .node_indices()
+        .filter(|n| {
+            if g.is_directed() {
+                balance[n.index()] != 0
+            } else {
+                balance[n.index()] % 2 != 0
+            }
+        })
+        .collect();
+    let with_edges = g.filter_map(
+        |n, _| {
+            if g.neighbors_undirected(n).next().is_some() {
+                Some(())
+            } else {
+                None
+            }
+        },
+        |_, _| Some(()),
+    );
+    let connected = connected_components(&with_edges) <= 1;
+
+    let has_path = connected
+        && if g.is_directed() {
+            unbalanced.is_empty()
+                || (unbalanced.len() == 2
+                    && unbalanced.iter().all(|n| balance[n.index()].abs() == 1))
+        } else {
+            unbalanced.len() <= 2
+        };
+    assert_eq!(is_eulerian(g), connected && unbalanced.is_empty());
+    assert_eq!(has_eulerian_path(g), has_path);
+    match eulerian_circuit(g) {
+        Err(NotEulerian::Unbalanced(nodes)) => assert_eq!(nodes, unbalanced),
+        Err(NotEulerian::Disconnected) => assert!(unbalanced.is_empty() && !connected),
+        Ok(circuit) => assert!(eulerian_walk(g, &circuit, true)),
+    }
+    match eulerian_path(g) {
+        Err(NotEulerian::Unbalanced(nodes)) => nodes == unbalanced && !has_path,
+        Err(NotEulerian::Disconnected) => !connected,
+        Ok(path) => eulerian_walk(g, &path, false),
+    }
+}
+
+/// Returns true if the walk goes through each edge once, each from the node
+/// before it to the node after it.
+fn eulerian_walk<Ty: EdgeType>(
+    g: &Graph<(), (), Ty>,
+    walk: &EulerianWalk<NodeIndex, EdgeReference<()>>,
+    closed: bool,
+) -> bool {
+    let mut seen = vec![false; g.edge_count()];
+    if walk.edges.is_empty() {
+        return walk.nodes.is_empty() && g.edge_count() == 0;
+    }
+    if walk.nodes.len() != walk.edges.len() + 1 {
+        return false;
+    }
+    for (e, ends) in walk.edges.iter().zip(walk.nodes.windows(2)) {
+        if std::mem::replace(&mut seen[e.id().index()], true) {
+            return false;
+        }
+        let forward = (e.source(), e.target()) == (ends[0], ends[1]);
+        let backward = (e.target(), e.source()) == (ends[0], ends[1]);
+        if !forward && (g.is_directed() || !backward) {
+            return false;
+        }
+    }
+    seen.iter().all(|&s| s) && (!closed || walk.nodes.first() == walk.nodes.last())
+}
+
+quickcheck! {
+    fn eulerian_directed(g: Graph<(), ()>) -> bool {
+        // with each edge walked back, the degrees are balanced
+        let mut doubled = g.clone();
+        for e in g.edge_references() {
+            doubled.add_edge(e.target(), e.source(), ());
+        }
+        check_eulerian(&g) && check_eulerian(&doubled)
+    }
+
+    fn eulerian_undirected(g: Graph<(), (), Undirected>) -> bool {
+        let mut doubled = g.clone();
+        for e in g.edge_references() {
+            doubled.add_edge(e.target(), e.source(), ());
+        }
+        check_eulerian(&g) && check_eulerian(&doubled)
+    }
+}
+
+/// Calls `f` with every order of `items[k..]`.
+fn for_each_permutation(items: &mut [usize], k: usize, f: &mut dyn FnMut(&[usize])) {
+    if k == items.len() {
+        f(items);
+    }
+    for i in k..items.len() {
+        items.swap(k, i);
+        for_each_permutation(items, k + 1, f);
+        items.swap(k, i);
+    }
+}
+
+quickcheck! {
+    fn tsp(g: Graph<(), u8, Undirected>) -> bool {
+        // keep the brute force small
+        let g = g.filter_map(|n, _| if n.index() < 8 { Some(()) } else { None }, |_, &w| Some(w as i32));
+        let n = g.node_count();
+        let mut costs = vec![vec![None; n]; n];
+        for e in g.edge_references() {
+            let (a, b) = (e.source().index(), e.target().index());
+            for &(a, b) in &[(a, b), (b, a)] {
+                if costs[a][b].map_or(true, |c| *e.weight() < c) {
+                    costs[a][b] = Some(*e.weight());
+                }
+            }
+        }
+        let tour_cost = |tour: &[NodeIndex]| -> Option<i32> {
+            let mut sorted = tour.to_vec();
+            sorted.sort();
+            assert_eq!(sorted, g.node_indices().collect::<Vec<_>>());
+            if n < 2 {
+                return Some(0);
+            }
+            (0..n).map(|i| costs[tour[i].index()][tour[(i + 1) % n].index()]).sum()
+        };
+        let mut optimum = None;
+        let mut has_path = n == 0;
+        let mut order: Vec<usize> = (0..n).collect();
+        for_each_permutation(&mut order, 0, &mut |order| {
+            has_path |= order.windows(2).all(|w| costs[w[0]][w[1]].is_some());
+            let tour: Vec<NodeIndex> = order.iter().map(|&i| node_index(i)).collect();
+            if let (Some(0), Some(cost)) = (order.first(), tour_cost(&tour)) {
+                if optimum.map_or(true, |o| cost < o) {
+                    optimum = Some(cost);
+                }
+            }
+        });
+        if n == 0 {
+            optimum = Some(0);
+        }
+
+        let exact = held_karp(&g, |e| *e.weight());
+        assert_eq!(exact.as_ref().map(|&(cost, _)| cost), optimum);
+        if let Some((cost, tour)) = exact {
+            assert_eq!(tour_cost(&tour), Some(cost));
+        }
+        match hamiltonian_path(&g) {
+            Some(path) => assert!(path.len() == n && path.windows(2).all(|w| g.contains_edge(w[0], w[1]))),
+            None => assert!(!has_path),
+        }
+        if let Some(start) = g.node_indices().next() {
+            if let Some((cost, mut tour)) = nearest_neighbor_tour(&g, start, |e| *e.weight()) {
+                assert_eq!(tour_cost(&tour), Some(cost));
+                let improved = two_opt(&g, &mut tour, |e| *e.weight()).unwrap();
+                assert!(improved <= cost && Some(improved) >= optimum);
+                assert_eq!(tour_cost(&tour), Some(improved));
+                let improved_again = or_opt(&g, &mut tour, |e| *e.weight()).unwrap();
+                assert!(improved_again <= improved && Some(improved_again) >= optimum);
+                assert_eq!(tour_cost(&tour), Some(improved_again));
+            }
+        }
+
+        // on the metric closure, Christofides is within 3/2 of the optimum
+        let distances = floyd_warshall(&g, |e| *e.weight()).unwrap();
+        let mut metric = Graph::<(), i32, Undirected>::with_capacity(n, 0);
+        for _ in 0..n {
+            metric.add_node(());
+        }
+        for a in metric.node_indices() {
+            for b in metric.node_indices().filter(|&b| a < b) {
+                if let Some(&d) = distances.get(&(a, b)).filter(|&&d| d != i32::max_value()) {
+                    metric.add_edge(a, b, d);
+                }
+            }
+        }
+        let complete = metric.edge_count() == n * n.saturating_sub(1) / 2;
+        match christofides(&metric, |e| *e.weight()) {
+            Some((cost, tour)) => {
+                let optimum = held_karp(&metric, |e| *e.weight()).unwrap().0;
+                let mut sorted = tour.clone();
+                sorted.sort();
+                complete && sorted == metric.node_indices().collect::<Vec<_>>() && 2 * cost <= 3 * optimum
+            }
+            None => !complete,
+        }
+    }
+}
+
+quickcheck! {
+    fn community(g: Graph<(), u8, Undirected>) -> bool {
+        let weight = |e: EdgeReference<u8>| *e.weight() as f64;
+        let singletons: Vec<usize> = (0..g.node_count()).collect();
+        let communities = louvain(&g, 1., weight);
+        let q = modularity(&g, &communities, 1., weight);
+        assert!(q >= modularity(&g, &singletons, 1., weight) - 1e-9);
+
+        // the communities are connected, and keep their modularity in the
+        // quotient graph
+        let quotient = quotient_graph(&g, &communities, weight);
+        for (c, nodes) in quotient.node_weights().enumerate() {
+            let sub = g.filter_map(|n, _| if communities[n.index()] == c { Some(()) } else { None }, |_, &w| Some(w));
+            assert_eq!(sub.node_count(), nodes.len());
+            assert_eq!(connected_components(&sub), 1);
+        }
+        let identity: Vec<usize> = (0..quotient.node_count()).collect();
+        assert!((modularity(&quotient, &identity, 1., |e| *e.weight()) - q).abs() < 1e-9);
+
+        // every node has one of the heaviest labels of its neighborhood
+        let labels = label_propagation(&g, |e| *e.weight() as u32);
+        g.node_indices().all(|n| {
+            let mut weights = vec![0; g.node_count()];
+            for e in g.edges(n).filter(|e| e.target() != n) {
+                weights[labels[e.target().index()]] += *e.weight() as u32;
+            }
+            weights[labels[n.index()]] == *weights.iter().max().unwrap()
+        })
+    }
+}
+
 quickcheck! {
     fn mst_undirected(g: Graph<(), u32, Undirected>) -> bool {
         // filter out isolated nodes
@@ -761,6 +1384,158 @@ fn full_topo_generic() {
     quickcheck::quickcheck(prop_generic as fn(_) -> bool);
 }
 
+quickcheck! {
+    // checks dijkstra_paths against dijkstra, and that the reconstructed
+    // paths have the computed lengths
+    fn dijkstra_paths_reconstruct(g: Graph<(), u32>, node: usize) -> bool {
+        if g.node_count() == 0 {
+            return true;
+        }
+        let g = g.map(|_, _| (), |_, &w| w % 1000);
+        let v = node_index(node % g.node_count());
+        let distances = dijkstra(&g, v, None, |e| *e.weight());
+        let paths = dijkstra_paths(&g, v, None, |e| *e.weight());
+        for u in g.node_indices() {
+            match (distances.get(&u), paths.edges_to(&g, v, u, |e| *e.weight())) {
+                (Some(&d), Some(edges)) => {
+                    let cost: u32 = edges.iter().map(|e| *e.weight()).sum();
+                    if cost != d || paths.distances[u.index()] != Some(d) {
+                        return false;
+                    }
+                    let path = paths.path_to(&g, v, u).unwrap();
+                    if path[0] != v || path.len() != edges.len() + 1 {
+                        return false;
+                    }
+                }
+                (None, None) => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+fn bidirectional_dijkstra_agrees<Ty: EdgeType>(g: &Graph<(), u32, Ty>, a: usize, b: usize) -> bool {
+    if g.node_count() == 0 {
+        return true;
+    }
+    let g = g.map(|_, _| (), |_, &w| w % 1000);
+    let a = node_index(a % g.node_count());
+    let b = node_index(b % g.node_count());
+    let distances = dijkstra(&g, a, None, |e| *e.weight());
+    match (
+        distances.get(&b),
+        bidirectional_dijkstra(&g, a, b, |e| *e.weight()),
+    ) {
+        (Some(&d), Some((cost, meeting, path))) => {
+            // the path must be made of edges and have the reported cost
+            let mut path_cost = 0;
+            for pair in path.windows(2) {
+                match g
+                    .edges_connecting(pair[0], pair[1])
+                    .map(|e| *e.weight())
+                    .min()
+                {
+                    Some(w) => path_cost += w,
+                    None => return false,
+                }
+            }
+            cost == d
+                && path_cost == d
+                && path.first() == Some(&a)
+                && path.last() == Some(&b)
+                && path.contains(&meeting)
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+quickcheck! {
+    // checks bidirectional_dijkstra against dijkstra
+    fn bidirectional_dijkstra_directed(g: Graph<(), u32>, a: usize, b: usize) -> bool {
+        bidirectional_dijkstra_agrees(&g, a, b)
+    }
+
+    fn bidirectional_dijkstra_undirected(g: Graph<(), u32, Undirected>, a: usize, b: usize) -> bool {
+        bidirectional_dijkstra_agrees(&g, a, b)
+    }
+
+    // checks multi_source_dijkstra against the best of dijkstra from each start
+    fn multi_source_dijkstra_agrees(g: Graph<(), u32>, starts: Vec<(usize, u8)>, goal: usize) -> bool {
+        if g.node_count() == 0 {
+            return true;
+        }
+        let g = g.map(|_, _| (), |_, &w| w % 1000);
+        let starts: Vec<_> = starts
+            .into_iter()
+            .map(|(n, s)| (node_index(n % g.node_count()), u32::from(s)))
+            .collect();
+        let mut expected = HashMap::new();
+        for &(start, start_score) in &starts {
+            for (n, d) in dijkstra(&g, start, None, |e| *e.weight()) {
+                let d = d + start_score;
+                let best = expected.entry(n).or_insert(d);
+                *best = (*best).min(d);
+            }
+        }
+        if multi_source_dijkstra(&g, starts.clone(), None, |e| *e.weight()) != expected {
+            return false;
+        }
+        let goal = node_index(goal % g.node_count());
+        match multi_source_dijkstra_path(&g, starts.clone(), goal, |e| *e.weight()) {
+            Some((cost, path)) => {
+                let start_score = starts.iter().filter(|s| s.0 == path[0]).map(|s| s.1).min();
+                let edges_cost: Option<u32> = path
+                    .windows(2)
+                    .map(|pair| g.edges_connecting(pair[0], pair[1]).map(|e| *e.weight()).min())
+                    .sum();
+                expected.get(&goal) == Some(&cost)
+                    && path.last() == Some(&goal)
+                    && start_score.zip(edges_cost).map(|(s, e)| s + e) == Some(cost)
+            }
+            None => !expected.contains_key(&goal),
+        }
+    }
+}
+
+quickcheck! {
+    // checks yen_k_shortest_paths against all simple paths
+    fn yen_k_shortest_paths_simple(g: Small<Graph<(), u8>>, a: usize, b: usize, k: u8) -> bool {
+        // keep the number of simple paths small
+        let g = g.filter_map(
+            |n, _| if n.index() < 7 { Some(()) } else { None },
+            |_, &w| Some(u32::from(w)),
+        );
+        if g.node_count() == 0 {
+            return true;
+        }
+        let a = node_index(a % g.node_count());
+        let b = node_index(b % g.node_count());
+        let k = usize::from(k % 8);
+        let path_cost = |path: &[NodeIndex]| -> u32 {
+            path.windows(2)
+                .map(|pair| g.edges_connecting(pair[0], pair[1]).map(|e| *e.weight()).min().unwrap())
+                .sum()
+        };
+
+        let mut all: Vec<Vec<NodeIndex>> = all_simple_paths(&g, a, b, 0, None).collect();
+        if a == b {
+            all = vec![vec![a]];
+        }
+        let mut expected: Vec<_> = all.iter().map(|p| path_cost(p)).collect();
+        expected.sort();
+        expected.truncate(k);
+
+        let paths = yen_k_shortest_paths(&g, a, b, k, |e| *e.weight());
+        let costs: Vec<_> = paths.iter().map(|p| p.0).collect();
+        let distinct: HashSet<_> = paths.iter().map(|p| p.1.clone()).collect();
+        costs == expected
+            && distinct.len() == paths.len()
+            && paths.iter().all(|(cost, path)| all.contains(path) && path_cost(path) == *cost)
+    }
+}
+
 quickcheck! {
     // checks that the distances computed by dijkstra satisfy the triangle
     // inequality.
@@ -804,6 +1579,55 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // checks floyd_warshall_matrix against bellman_ford, and that the
+    // reconstructed paths have the computed lengths
+    fn floyd_warshall_matrix_(g: Small<Graph<(), i8>>) -> bool {
+        let g = g.map(|_, _| (), |_, &w| f64::from(w));
+        let res = floyd_warshall_matrix(&g, |e| *e.weight());
+        let mut negative_cycle = false;
+        for source in g.node_indices() {
+            let paths = match bellman_ford(&g, source) {
+                Ok(paths) => paths,
+                Err(_) => {
+                    negative_cycle = true;
+                    break;
+                }
+            };
+            let res = match &res {
+                Ok(res) => res,
+                Err(_) => return false,
+            };
+            for target in g.node_indices() {
+                let (s, t) = (source.index(), target.index());
+                let distance = paths.distances[t];
+                match (res.distance(s, t), res.path(s, t)) {
+                    (Some(d), Some(path)) => {
+                        let cost: f64 = path
+                            .windows(2)
+                            .map(|pair| {
+                                g.edges_connecting(node_index(pair[0]), node_index(pair[1]))
+                                    .map(|e| *e.weight())
+                                    .fold(f64::INFINITY, f64::min)
+                            })
+                            .sum();
+                        if d != distance || cost != d || path[0] != s || path[path.len() - 1] != t {
+                            return false;
+                        }
+                    }
+                    (None, None) => {
+                        if distance.is_finite() {
+                            return false;
+                        }
+                    }
+                    _ => return false,
+                }
+            }
+        }
+        negative_cycle == res.is_err()
+    }
+}
+
 quickcheck! {
     // checks floyd_warshall against dijkstra results
     fn floyd_warshall_(g: Graph<u32, u32>) -> bool {
@@ -835,6 +1659,35 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // checks johnson against floyd_warshall results, with negative edges
+    fn johnson_(g: Small<Graph<(), i8>>) -> bool {
+        let mut g = g.map(|_, _| (), |_, &w| i64::from(w));
+        // floyd_warshall does not see negative self-loops as negative cycles
+        g.retain_edges(|g, e| {
+            let (a, b) = g.edge_endpoints(e).unwrap();
+            a != b
+        });
+        let fw_res = floyd_warshall(&g, |e| *e.weight());
+        let johnson_res = johnson(&g, |e| *e.weight());
+        let (fw_res, johnson_res) = match (fw_res, johnson_res) {
+            (Ok(fw_res), Ok(johnson_res)) => (fw_res, johnson_res),
+            (Err(_), Err(_)) => return true,
+            _ => return false,
+        };
+
+        // floyd_warshall reports unreachable pairs with a distance close to the maximum value
+        let unreachable = i64::MAX / 2;
+        fw_res.iter().all(|(pair, &distance)| {
+            if distance > unreachable {
+                !johnson_res.contains_key(pair)
+            } else {
+                johnson_res.get(pair) == Some(&distance)
+            }
+        })
+    }
+}
+
 quickcheck! {
     // checks that the complement of the complement is the same as the input if the input does not contain self-loops
     fn complement_(g: Graph<u32, u32>, _node: usize) -> bool {
@@ -950,6 +1803,28 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // checks spfa against bellman_ford results, with negative edges
+    fn test_spfa(gr: Small<Graph<(), i8>>) -> bool {
+        if gr.node_count() == 0 {
+            return true;
+        }
+        let gr = gr.map(|_, _| (), |_, &w| f64::from(w));
+        let start = node_index(0);
+        match (bellman_ford(&gr, start), spfa(&gr, start, |e| *e.weight())) {
+            (Ok(expected), Ok(paths)) => {
+                expected
+                    .distances
+                    .iter()
+                    .zip(&paths.distances)
+                    .all(|(&bf, &d)| d.unwrap_or(f64::INFINITY) == bf)
+            }
+            (Err(_), Err(_)) => true,
+            _ => false,
+        }
+    }
+}
+
 quickcheck! {
     fn test_bellman_ford_undir(gr: Graph<(), f32, Undirected>) -> bool {
         let mut gr = gr;
@@ -1313,6 +2188,148 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn bipartite_matching(g: Graph<(), (), Undirected>) -> bool {
+        // Keep the edges between even and odd nodes.
+        let mut g = g;
+        g.retain_edges(|g, e| {
+            let (a, b) = g.edge_endpoints(e).unwrap();
+            a.index() % 2 != b.index() % 2
+        });
+        let (left, right) = bipartition(&g).unwrap();
+        assert!(g.edge_references().all(|e| left.contains(&e.source()) != left.contains(&e.target())));
+
+        let m = bipartite_maximum_matching(&g, left.clone(), right.clone());
+        assert!(is_valid_matching(&m));
+        assert!(m.edges().all(|(a, b)| g.contains_edge(a, b)));
+        assert_eq!(m.len(), maximum_matching(&g).len());
+
+        let cover: HashSet<_> = bipartite_minimum_vertex_cover(&g, left, right, &m)
+            .into_iter()
+            .collect();
+        assert_eq!(cover.len(), m.len());
+        g.edge_references().all(|e| cover.contains(&e.source()) || cover.contains(&e.target()))
+    }
+}
+
+// The greatest (cardinality, weight) of a matching, by trying all matchings
+// of the nodes from `v` on, when the nodes in `matched` are already taken.
+fn best_matching(
+    weights: &HashMap<(usize, usize), i32>,
+    n: usize,
+    v: usize,
+    matched: &mut Vec<bool>,
+) -> ((usize, i32), i32) {
+    if v == n {
+        return ((0, 0), 0);
+    }
+    if matched[v] {
+        return best_matching(weights, n, v + 1, matched);
+    }
+    let (mut best_cardinality, mut best_weight) = best_matching(weights, n, v + 1, matched);
+    matched[v] = true;
+    for u in v + 1..n {
+        if let (false, Some(&weight)) = (matched[u], weights.get(&(v, u))) {
+            matched[u] = true;
+            let ((cardinality, cardinality_weight), max_weight) =
+                best_matching(weights, n, v + 1, matched);
+            matched[u] = false;
+            best_cardinality = best_cardinality.max((cardinality + 1, cardinality_weight + weight));
+            best_weight = best_weight.max(max_weight + weight);
+        }
+    }
+    matched[v] = false;
+    (best_cardinality, best_weight)
+}
+
+quickcheck! {
+    fn weighted_matching(g: Graph<(), i8, Undirected>) -> bool {
+        if g.node_count() > 10 {
+            return true;
+        }
+        let mut weights = HashMap::new();
+        for e in g.edge_references() {
+            let (a, b) = (e.source().index(), e.target().index());
+            if a != b {
+                let weight = weights.entry((a.min(b), a.max(b))).or_insert(i32::min_value());
+                *weight = (*weight).max(i32::from(*e.weight()));
+            }
+        }
+        let ((cardinality, cardinality_weight), max_weight) =
+            best_matching(&weights, g.node_count(), 0, &mut vec![false; g.node_count()]);
+        let total = |m: &Matching<&Graph<(), i8, Undirected>>| -> i32 {
+            m.edges()
+                .map(|(a, b)| weights[&(a.index().min(b.index()), a.index().max(b.index()))])
+                .sum()
+        };
+
+        let m = maximum_weight_matching(&g, false, |e| i32::from(*e.weight()));
+        assert!(is_valid_matching(&m));
+        assert_eq!(total(&m), max_weight);
+
+        let m = maximum_weight_matching(&g, true, |e| i32::from(*e.weight()));
+        assert!(is_valid_matching(&m));
+        assert_eq!(m.len(), cardinality);
+        assert_eq!(total(&m), cardinality_weight);
+        true
+    }
+}
+
+// The best assignment from even to odd nodes, as a minimum cost flow through
+// a source connected to the even nodes and a sink connected to the odd ones.
+fn assignment_by_flow(g: &Graph<(), u8, Undirected>, maximize: bool) -> (f64, f64) {
+    let mut network = Graph::<(), (f64, f64)>::new();
+    for _ in g.node_indices() {
+        network.add_node(());
+    }
+    let source = network.add_node(());
+    let sink = network.add_node(());
+    for n in g.node_indices() {
+        if n.index() % 2 == 0 {
+            network.add_edge(source, n, (1., 0.));
+        } else {
+            network.add_edge(n, sink, (1., 0.));
+        }
+    }
+    for e in g.edge_references() {
+        let (a, b) = (e.source(), e.target());
+        let (a, b) = match (a.index() % 2, b.index() % 2) {
+            (0, 1) => (a, b),
+            (1, 0) => (b, a),
+            _ => continue,
+        };
+        let weight = f64::from(*e.weight());
+        network.add_edge(a, b, (1., if maximize { -weight } else { weight }));
+    }
+    let (flow, cost, _) =
+        min_cost_flow(&network, source, sink, |e| e.weight().0, |e| e.weight().1).unwrap();
+    (flow, if maximize { -cost } else { cost })
+}
+
+quickcheck! {
+    fn assignment(g: Graph<(), u8, Undirected>) -> bool {
+        let left: Vec<_> = g.node_indices().filter(|n| n.index() % 2 == 0).collect();
+        let right: Vec<_> = g.node_indices().filter(|n| n.index() % 2 == 1).collect();
+        for &maximize in &[false, true] {
+            let (m, total) = if maximize {
+                max_weight_assignment(&g, left.iter().cloned(), right.iter().cloned(), |e| {
+                    u32::from(*e.weight())
+                })
+            } else {
+                min_cost_assignment(&g, left.iter().cloned(), right.iter().cloned(), |e| {
+                    u32::from(*e.weight())
+                })
+            };
+            assert!(is_valid_matching(&m));
+            assert!(m.edges().all(|(a, b)| a.index() % 2 != b.index() % 2));
+            let (size, best) = assignment_by_flow(&g, maximize);
+            assert_eq!(m.len() as f64, size);
+            assert_eq!(f64::from(total), best);
+        }
+        true
+    }
+}
+
 quickcheck! {
     // The ranks are probabilities,
     // as such they are positive and they should sum up to 1.
@@ -1331,6 +2348,67 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // The same holds with options, personalization and edge weights.
+    fn test_page_rank_with_options_proba(gr: Graph<(), f32>, personalized: bool) -> bool {
+        if gr.node_count() == 0 {
+            return true;
+        }
+        let mut options = PageRankOptions::new().tolerance(1e-12_f64).max_iter(1000);
+        // the jumps never go to the first node
+        if personalized && gr.node_count() > 1 {
+            options = options.personalization(gr.node_indices().map(|n| (n.index() % 3) as f64).collect());
+        }
+        let scores = weighted_page_rank_with_options(&gr, &options, |e| e.weight().abs() as f64);
+        assert!(scores.converged);
+        assert!(scores.ranks.iter().all(|&rank| rank >= 0.));
+        (scores.ranks.iter().sum::<f64>() - 1.).abs() < 1e-9
+    }
+}
+
+quickcheck! {
+    // An edge is a bridge, and a node an articulation point, exactly when
+    // removing it increases the number of connected components.
+    fn bridges_and_articulation_points(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        let gr = gr.0;
+        let n_components = connected_components(&gr);
+
+        let found: HashSet<_> = bridges(&gr).into_iter().map(|e| e.id()).collect();
+        let expected: HashSet<_> = gr
+            .edge_indices()
+            .filter(|&e| {
+                let mut g = gr.clone();
+                g.remove_edge(e);
+                connected_components(&g) > n_components
+            })
+            .collect();
+        assert_eq!(found, expected);
+
+        let cuts = articulation_points(&gr);
+        let expected: Vec<_> = gr
+            .node_indices()
+            .filter(|&n| {
+                let mut g = gr.clone();
+                g.remove_node(n);
+                connected_components(&g) > n_components
+            })
+            .collect();
+        assert_eq!(cuts, expected);
+
+        // The articulation points are the nodes that are in several blocks.
+        let tree = block_cut_tree(&gr);
+        let mut tree_cuts: Vec<_> = tree
+            .node_weights()
+            .filter_map(|w| match *w {
+                petgraph::algo::BlockCutNode::Cut(n) => Some(n),
+                _ => None,
+            })
+            .collect();
+        tree_cuts.sort();
+        tree_cuts == cuts
+    }
+}
+
 fn sum_flows<N, F: std::iter::Sum + Copy>(
     gr: &Graph<N, F>,
     flows: &[F],
@@ -1376,3 +2454,93 @@ quickcheck! {
         return capacity_constraint && flow_conservation_constraint && max_flow_constaint;
     }
 }
+
+fn check_max_flow<F>(gr: &Graph<usize, u32>, max_flow_algorithm: F) -> bool
+where
+    F: Fn(&Graph<usize, u32>, NodeIndex, NodeIndex) -> (u32, Vec<u32>),
+{
+    if gr.node_count() <= 1 {
+        return true;
+    }
+    // keep the sums of capacities from overflowing
+    let gr = gr.map(|_, &w| w, |_, &w| w % 1000);
+    let source = NodeIndex::from(0);
+    let destination = NodeIndex::from(gr.node_count() as u32 / 2);
+    let (max_flow, flows) = max_flow_algorithm(&gr, source, destination);
+    let capacity_constraint = gr.edge_indices().all(|e| flows[e.index()] <= gr[e]);
+    let flow_conservation_constraint = gr.node_indices().all(|node| {
+        node == source
+            || node == destination
+            || sum_flows(&gr, &flows, node, Direction::Outgoing)
+                == sum_flows(&gr, &flows, node, Direction::Incoming)
+    });
+    let max_flow_constraint = sum_flows(&gr, &flows, source, Direction::Outgoing)
+        == max_flow + sum_flows(&gr, &flows, source, Direction::Incoming)
+        && sum_flows(&gr, &flows, destination, Direction::Incoming)
+            == max_flow + sum_flows(&gr, &flows, destination, Direction::Outgoing);
+    // the flow is maximum iff there is a cut with the same capacity
+    let (source_side, cut_edges) = min_cut(&gr, source, &flows);
+    let min_cut_constraint = !source_side.contains(&destination)
+        && cut_edges.iter().map(|e| *e.weight()).sum::<u32>() == max_flow
+        && cut_edges
+            .iter()
+            .all(|e| flows[e.id().index()] == *e.weight());
+    capacity_constraint && flow_conservation_constraint && max_flow_constraint && min_cut_constraint
+}
+
+quickcheck! {
+    // checks that the flows are valid and maximum, see test_ford_fulkerson_flows
+    fn test_dinic_flows(gr: Graph<usize, u32>) -> bool {
+        check_max_flow(&gr, |gr, s, t| dinic(gr, s, t))
+    }
+
+    fn test_push_relabel_flows(gr: Graph<usize, u32>) -> bool {
+        check_max_flow(&gr, |gr, s, t| push_relabel(gr, s, t))
+    }
+
+    fn test_ford_fulkerson_min_cut(gr: Graph<usize, u32>) -> bool {
+        check_max_flow(&gr, |gr, s, t| ford_fulkerson(gr, s, t))
+    }
+}
+
+quickcheck! {
+    // checks that min_cost_flow is a maximum flow, and that it is of minimum
+    // cost: there is no negative cost cycle in the residual network
+    fn test_min_cost_flow(gr: Small<Graph<(), (u8, i8)>>) -> bool {
+        if gr.node_count() <= 1 {
+            return true;
+        }
+        // no negative cycles
+        let gr = gr.map(|_, _| (), |_, &(c, w)| (f64::from(c), f64::from(w).abs()));
+        let source = NodeIndex::from(0);
+        let destination = NodeIndex::from(gr.node_count() as u32 / 2);
+        let (flow, cost, flows) =
+            min_cost_flow(&gr, source, destination, |e| e.weight().0, |e| e.weight().1).unwrap();
+        let (max_flow, _) = dinic(
+            &gr.map(|_, _| (), |_, w| w.0),
+            source,
+            destination,
+        );
+        let edge_cost: f64 = gr.edge_indices().map(|e| flows[e.index()] * gr[e].1).sum();
+
+        let mut residual = Graph::<(), f64>::new();
+        for _ in gr.node_indices() {
+            residual.add_node(());
+        }
+        for e in gr.edge_references() {
+            let (capacity, cost) = *e.weight();
+            let flow = flows[e.id().index()];
+            if flow < capacity {
+                residual.add_edge(e.source(), e.target(), cost);
+            }
+            if flow > 0. {
+                residual.add_edge(e.target(), e.source(), -cost);
+            }
+        }
+        flow == max_flow
+            && cost == edge_cost
+            && residual
+                .node_indices()
+                .all(|n| find_negative_cycle(&residual, n).is_none())
+    }
+}
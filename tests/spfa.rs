@@ -0,0 +1,75 @@
+use petgraph::algo::{bellman_ford, spfa};
+use petgraph::prelude::*;
+
+#[test]
+fn spfa_same_as_bellman_ford() {
+    let mut g = Graph::<(), f64>::new();
+    let nodes: Vec<_> = (0..7).map(|_| g.add_node(())).collect();
+    g.extend_with_edges(&[
+        (0, 1, 6.),
+        (0, 2, 7.),
+        (1, 2, 8.),
+        (1, 3, 5.),
+        (1, 4, -4.),
+        (2, 3, -3.),
+        (2, 4, 9.),
+        (3, 1, -2.),
+        (4, 0, 2.),
+        (4, 3, 7.),
+    ]);
+
+    for &source in &nodes {
+        let expected = bellman_ford(&g, source).unwrap();
+        let paths = spfa(&g, source, |e| *e.weight()).unwrap();
+        let distances: Vec<_> = paths
+            .distances
+            .iter()
+            .map(|&d| d.unwrap_or(f64::INFINITY))
+            .collect();
+        assert_eq!(distances, expected.distances);
+        assert_eq!(paths.predecessors, expected.predecessors);
+    }
+}
+
+#[test]
+fn spfa_unreachable() {
+    let g = Graph::<(), i32>::from_edges(&[(0, 1, 2), (1, 2, -1), (3, 0, 1)]);
+    let paths = spfa(&g, 0.into(), |e| *e.weight()).unwrap();
+    assert_eq!(paths.distances, vec![Some(0), Some(2), Some(1), None]);
+    assert_eq!(
+        paths.predecessors,
+        vec![None, Some(0.into()), Some(1.into()), None]
+    );
+}
+
+#[test]
+fn spfa_negative_cycle() {
+    let mut g = Graph::<(), i32>::from_edges(&[(0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 1, -3)]);
+    assert!(spfa(&g, 0.into(), |e| *e.weight()).is_err());
+
+    // Starting on the cycle.
+    assert!(spfa(&g, 1.into(), |e| *e.weight()).is_err());
+
+    // The cycle is not reachable from the source.
+    g.add_node(());
+    assert!(spfa(&g, 4.into(), |e| *e.weight()).is_ok());
+
+    // Negative self-loop.
+    let g = Graph::<(), i32>::from_edges(&[(0, 1, 1), (1, 1, -1)]);
+    assert!(spfa(&g, 0.into(), |e| *e.weight()).is_err());
+
+    // An undirected negative edge is a negative cycle.
+    let g = UnGraph::<(), i32>::from_edges(&[(0, 1, 3), (1, 2, -1)]);
+    assert!(spfa(&g, 0.into(), |e| *e.weight()).is_err());
+}
+
+#[test]
+fn spfa_undirected() {
+    let g = UnGraph::<(), u32>::from_edges(&[(0, 1, 4), (1, 2, 1), (0, 2, 2), (2, 3, 7)]);
+    let paths = spfa(&g, 3.into(), |e| *e.weight()).unwrap();
+    assert_eq!(paths.distances, vec![Some(9), Some(8), Some(7), Some(0)]);
+    assert_eq!(
+        paths.predecessors,
+        vec![Some(2.into()), Some(2.into()), Some(3.into()), None]
+    );
+}
@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+
+use petgraph::algo::steiner_tree;
+use petgraph::prelude::*;
+
+#[test]
+fn steiner_tree_through_other_nodes() {
+    // Terminals 0, 1, 2 and 3 around the nodes 4 and 5.
+    //
+    //  0         2
+    //   \1     1/
+    //    4 -1- 5
+    //   /1     1\
+    //  1         3
+    let mut g = UnGraph::<(), u32>::new_undirected();
+    let n: Vec<_> = (0..6).map(|_| g.add_node(())).collect();
+    g.extend_with_edges(&[
+        (0, 4, 1),
+        (1, 4, 1),
+        (4, 5, 1),
+        (2, 5, 1),
+        (3, 5, 1),
+        (0, 1, 3),
+        (1, 3, 4),
+        (0, 2, 4),
+        (2, 3, 3),
+    ]);
+
+    let (nodes, edges) = steiner_tree(&g, n[..4].iter().cloned(), |e| *e.weight());
+    assert_eq!(nodes, n.iter().cloned().collect());
+    assert_eq!(edges.len(), 5);
+    assert_eq!(edges.iter().map(|&e| g[e]).sum::<u32>(), 5);
+}
+
+#[test]
+fn steiner_tree_prunes_other_nodes() {
+    // The path 0 - 1 - 2 - 3 with terminals 1 and 2, and a branch to 4.
+    let g = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 5.), (2, 3, 1.), (1, 4, 1.)]);
+    let n = NodeIndex::new;
+
+    let (nodes, edges) = steiner_tree(&g, vec![n(1), n(2)], |e| *e.weight());
+    assert_eq!(nodes, vec![n(1), n(2)].into_iter().collect());
+    assert_eq!(
+        edges,
+        Some(g.find_edge(n(1), n(2)).unwrap()).into_iter().collect()
+    );
+}
+
+#[test]
+fn steiner_tree_directed() {
+    // Edge directions are ignored.
+    let g = Graph::<(), u8>::from_edges(&[(0, 2, 1), (1, 2, 1), (0, 1, 5)]);
+    let n = NodeIndex::new;
+
+    let (nodes, edges) = steiner_tree(&g, vec![n(0), n(1)], |e| *e.weight());
+    assert_eq!(nodes.len(), 3);
+    assert_eq!(edges.iter().map(|&e| g[e]).sum::<u8>(), 2);
+}
+
+#[test]
+fn steiner_tree_trivial() {
+    let g = UnGraph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1)]);
+    let n = NodeIndex::new;
+
+    let (nodes, edges) = steiner_tree(&g, vec![], |e| *e.weight());
+    assert!(nodes.is_empty());
+    assert!(edges.is_empty());
+
+    // A repeated terminal is just one node.
+    let (nodes, edges) = steiner_tree(&g, vec![n(1), n(1)], |e| *e.weight());
+    assert_eq!(nodes, Some(n(1)).into_iter().collect());
+    assert!(edges.is_empty());
+}
+
+#[test]
+fn steiner_tree_zero_costs() {
+    // Terminal 1 is as close to terminal 0 as to itself.
+    let g = UnGraph::<(), u32>::from_edges(&[(0, 1, 0), (1, 2, 0), (2, 3, 2), (0, 3, 3)]);
+    let n = NodeIndex::new;
+
+    let (nodes, edges) = steiner_tree(&g, vec![n(0), n(1), n(3)], |e| *e.weight());
+    assert_eq!(nodes.len() - 1, edges.len());
+    assert!(nodes.contains(&n(1)));
+    assert!(edges.contains(&g.find_edge(n(0), n(1)).unwrap()));
+    assert_eq!(edges.iter().map(|&e| g[e]).sum::<u32>(), 2);
+}
+
+#[test]
+fn steiner_forest() {
+    // Two components, with two terminals in each.
+    let g = UnGraph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (3, 4, 1), (4, 5, 1)]);
+    let n = NodeIndex::new;
+
+    let (nodes, edges) = steiner_tree(&g, vec![n(0), n(2), n(3), n(4)], |e| *e.weight());
+    let expected: HashSet<_> = [0, 1, 2, 3, 4].iter().map(|&i| n(i)).collect();
+    assert_eq!(nodes, expected);
+    assert_eq!(edges.len(), 3);
+    assert!(!edges.contains(&g.find_edge(n(4), n(5)).unwrap()));
+}
+
+#[test]
+fn steiner_tree_graphmap() {
+    let g = UnGraphMap::<&str, u32>::from_edges(&[
+        ("berlin", "hamburg", 290),
+        ("berlin", "leipzig", 190),
+        ("leipzig", "munich", 430),
+        ("hamburg", "cologne", 430),
+        ("leipzig", "frankfurt", 390),
+        ("cologne", "frankfurt", 190),
+        ("frankfurt", "munich", 390),
+    ]);
+
+    let (nodes, edges) = steiner_tree(&g, vec!["hamburg", "munich", "cologne"], |e| *e.2);
+    let expected: HashSet<_> = vec!["hamburg", "munich", "cologne", "frankfurt"]
+        .into_iter()
+        .collect();
+    assert_eq!(nodes, expected);
+    let expected: HashSet<_> = vec![
+        ("cologne", "hamburg"),
+        ("cologne", "frankfurt"),
+        ("frankfurt", "munich"),
+    ]
+    .into_iter()
+    .collect();
+    let edges: HashSet<_> = edges
+        .into_iter()
+        .map(|(a, b)| if a < b { (a, b) } else { (b, a) })
+        .collect();
+    assert_eq!(edges, expected);
+}
+
+#[test]
+fn steiner_tree_stable_graph() {
+    let mut g = StableUnGraph::<(), u32>::default();
+    let n: Vec<_> = (0..5).map(|_| g.add_node(())).collect();
+    g.extend_with_edges(&[(0, 1, 1), (1, 4, 1), (0, 2, 1), (2, 3, 1), (3, 4, 1)]);
+    g.remove_node(n[1]);
+
+    let (nodes, edges) = steiner_tree(&g, vec![n[0], n[4]], |e| *e.weight());
+    let expected: HashSet<_> = vec![n[0], n[2], n[3], n[4]].into_iter().collect();
+    assert_eq!(nodes, expected);
+    assert_eq!(edges.len(), 3);
+}
@@ -0,0 +1,218 @@
+use petgraph::algo::{
+    christofides, hamiltonian_path, held_karp, nearest_neighbor_tour, or_opt, two_opt,
+};
+use petgraph::prelude::*;
+
+/// The complete graph on points of the plane, weighted by their distance.
+fn euclidean(points: &[(f64, f64)]) -> UnGraph<(), f64> {
+    let mut g = UnGraph::default();
+    let nodes: Vec<_> = points.iter().map(|_| g.add_node(())).collect();
+    for (i, &(xa, ya)) in points.iter().enumerate() {
+        for (j, &(xb, yb)) in points.iter().enumerate().skip(i + 1) {
+            g.add_edge(
+                nodes[i],
+                nodes[j],
+                ((xa - xb).powi(2) + (ya - yb).powi(2)).sqrt(),
+            );
+        }
+    }
+    g
+}
+
+fn points() -> Vec<(f64, f64)> {
+    vec![
+        (0., 0.),
+        (4., 1.),
+        (1., 3.),
+        (5., 5.),
+        (2., 6.),
+        (7., 2.),
+        (6., 7.),
+        (3., 2.),
+        (8., 6.),
+        (1., 8.),
+    ]
+}
+
+/// Checks that the tour goes through every node once and costs `cost`.
+fn assert_tour(g: &UnGraph<(), f64>, tour: &[NodeIndex], cost: f64) {
+    let mut sorted = tour.to_vec();
+    sorted.sort();
+    assert_eq!(sorted, g.node_indices().collect::<Vec<_>>());
+    let total: f64 = (0..tour.len())
+        .map(|i| {
+            let e = g.find_edge(tour[i], tour[(i + 1) % tour.len()]).unwrap();
+            g[e]
+        })
+        .sum();
+    assert!((total - cost).abs() < 1e-9, "{} != {}", total, cost);
+}
+
+#[test]
+fn euclidean_tours() {
+    let g = euclidean(&points());
+    let (optimum, tour) = held_karp(&g, |e| *e.weight()).unwrap();
+    assert_tour(&g, &tour, optimum);
+    assert_eq!(tour[0], NodeIndex::new(0));
+
+    let (cost, tour) = christofides(&g, |e| *e.weight()).unwrap();
+    assert_tour(&g, &tour, cost);
+    assert!(cost <= 1.5 * optimum + 1e-9);
+
+    let (cost, mut tour) = nearest_neighbor_tour(&g, NodeIndex::new(3), |e| *e.weight()).unwrap();
+    assert_tour(&g, &tour, cost);
+    assert_eq!(tour[0], NodeIndex::new(3));
+    let improved = two_opt(&g, &mut tour, |e| *e.weight()).unwrap();
+    assert_tour(&g, &tour, improved);
+    assert!(improved <= cost && improved >= optimum - 1e-9);
+    let improved_again = or_opt(&g, &mut tour, |e| *e.weight()).unwrap();
+    assert_tour(&g, &tour, improved_again);
+    assert!(improved_again <= improved && improved_again >= optimum - 1e-9);
+    assert_eq!(tour[0], NodeIndex::new(3));
+}
+
+#[test]
+fn held_karp_directed() {
+    // The cheap edges go around 0 -> 2 -> 1 -> 3 -> 0, the other edges cost 5.
+    let mut g = DiGraph::<(), u32>::new();
+    for _ in 0..4 {
+        g.add_node(());
+    }
+    let cheap = [(0, 2), (2, 1), (1, 3), (3, 0)];
+    for a in 0..4 {
+        for b in 0..4 {
+            if a != b {
+                let cost = if cheap.contains(&(a, b)) { 1 } else { 5 };
+                g.add_edge(NodeIndex::new(a), NodeIndex::new(b), cost);
+            }
+        }
+    }
+    let (cost, tour) = held_karp(&g, |e| *e.weight()).unwrap();
+    assert_eq!(cost, 4);
+    assert_eq!(tour, vec![0.into(), 2.into(), 1.into(), 3.into()]);
+
+    let (cost, tour) = nearest_neighbor_tour(&g, NodeIndex::new(1), |e| *e.weight()).unwrap();
+    assert_eq!(cost, 4);
+    assert_eq!(tour, vec![1.into(), 3.into(), 0.into(), 2.into()]);
+
+    // Without the edge 3 -> 0, a tour uses at most one cheap edge.
+    let e = g.find_edge(NodeIndex::new(3), NodeIndex::new(0)).unwrap();
+    g.remove_edge(e);
+    let (cost, _) = held_karp(&g, |e| *e.weight()).unwrap();
+    assert_eq!(cost, 16);
+}
+
+#[test]
+#[should_panic(expected = "more than the limit")]
+fn held_karp_too_many_nodes() {
+    let mut g = UnGraph::<(), u32>::default();
+    let nodes: Vec<_> = (0..25).map(|_| g.add_node(())).collect();
+    for (i, &a) in nodes.iter().enumerate() {
+        for &b in &nodes[i + 1..] {
+            g.add_edge(a, b, 1);
+        }
+    }
+    held_karp(&g, |e| *e.weight());
+}
+
+#[test]
+fn missing_edges() {
+    // A path has no tour.
+    let path = UnGraph::<(), i32>::from_edges(&[(0, 1, 1), (1, 2, 1), (2, 3, 1)]);
+    assert_eq!(
+        nearest_neighbor_tour(&path, NodeIndex::new(0), |e| *e.weight()),
+        None
+    );
+    assert_eq!(held_karp(&path, |e| *e.weight()), None);
+    assert_eq!(christofides(&path, |e| *e.weight()), None);
+    let mut tour = vec![
+        NodeIndex::new(0),
+        NodeIndex::new(1),
+        NodeIndex::new(2),
+        NodeIndex::new(3),
+    ];
+    assert_eq!(two_opt(&path, &mut tour, |e| *e.weight()), None);
+    assert_eq!(or_opt(&path, &mut tour, |e| *e.weight()), None);
+
+    // The nearest neighbor can get stuck, even if there is a tour.
+    let square =
+        UnGraph::<(), i32>::from_edges(&[(0, 1, 2), (1, 2, 1), (2, 3, 1), (3, 0, 1), (0, 2, 1)]);
+    assert_eq!(
+        nearest_neighbor_tour(&square, NodeIndex::new(0), |e| *e.weight()),
+        None
+    );
+    let (cost, tour) = held_karp(&square, |e| *e.weight()).unwrap();
+    assert_eq!(cost, 5);
+    assert_eq!(tour, vec![0.into(), 3.into(), 2.into(), 1.into()]);
+}
+
+#[test]
+fn small_tours() {
+    let mut g = UnGraph::<(), i32>::default();
+    assert_eq!(held_karp(&g, |e| *e.weight()), Some((0, vec![])));
+    assert_eq!(christofides(&g, |e| *e.weight()), Some((0, vec![])));
+    let a = g.add_node(());
+    assert_eq!(held_karp(&g, |e| *e.weight()), Some((0, vec![a])));
+    assert_eq!(christofides(&g, |e| *e.weight()), Some((0, vec![a])));
+    assert_eq!(
+        nearest_neighbor_tour(&g, a, |e| *e.weight()),
+        Some((0, vec![a]))
+    );
+    let b = g.add_node(());
+    g.add_edge(a, b, 3);
+    assert_eq!(held_karp(&g, |e| *e.weight()), Some((6, vec![a, b])));
+    assert_eq!(christofides(&g, |e| *e.weight()), Some((6, vec![a, b])));
+}
+
+#[test]
+fn hamiltonian_paths() {
+    // The Petersen graph has a Hamiltonian path, but no Hamiltonian cycle.
+    let petersen = UnGraph::<(), ()>::from_edges(&[
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 0),
+        (0, 5),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (4, 9),
+        (5, 7),
+        (7, 9),
+        (9, 6),
+        (6, 8),
+        (8, 5),
+    ]);
+    let path = hamiltonian_path(&petersen).unwrap();
+    assert_eq!(path.len(), 10);
+    for pair in path.windows(2) {
+        assert!(petersen.contains_edge(pair[0], pair[1]));
+    }
+    assert_eq!(held_karp(&petersen, |_| 1), None);
+
+    // Directions matter: only 2 -> 0 -> 1 goes through all the nodes.
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 0), (2, 1)]);
+    assert_eq!(
+        hamiltonian_path(&g),
+        Some(vec![2.into(), 0.into(), 1.into()])
+    );
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 1)]);
+    assert_eq!(hamiltonian_path(&g), None);
+
+    let mut g = StableUnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (0, 3), (3, 4)]);
+    g.remove_node(NodeIndex::new(2));
+    assert_eq!(
+        hamiltonian_path(&g),
+        Some(vec![1.into(), 0.into(), 3.into(), 4.into()])
+    );
+    g.add_node(());
+    assert_eq!(hamiltonian_path(&g), None);
+
+    let g = UnGraphMap::<char, ()>::from_edges(&[('a', 'b'), ('b', 'c'), ('c', 'a')]);
+    assert_eq!(hamiltonian_path(&g), Some(vec!['a', 'b', 'c']));
+    assert_eq!(
+        hamiltonian_path(&UnGraph::<(), ()>::default()),
+        Some(vec![])
+    );
+}